@@ -0,0 +1,87 @@
+use serde::{Serialize, Serializer};
+use std::fmt::{Display, Formatter};
+
+/// Answer to one part of a puzzle
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum Answer {
+    Number(i128),
+    Text(String),
+    /// The puzzle has no such part (e.g. the second part of the last day)
+    Empty,
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Answer::Number(value) => write!(f, "{}", value),
+            Answer::Text(text) => write!(f, "{}", text),
+            Answer::Empty => write!(f, "-"),
+        }
+    }
+}
+
+/// Numbers are serialized as numbers, texts as strings and empty answers as `null`
+impl Serialize for Answer {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Answer::Number(value) => serializer.serialize_i128(*value),
+            Answer::Text(text) => serializer.serialize_str(text),
+            Answer::Empty => serializer.serialize_unit(),
+        }
+    }
+}
+
+macro_rules! impl_from_integer {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(value: $t) -> Self {
+                    Answer::Number(value as i128)
+                }
+            }
+        )*
+    };
+}
+
+impl_from_integer!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+
+impl From<String> for Answer {
+    fn from(text: String) -> Self {
+        Answer::Text(text)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Answer;
+
+    #[test]
+    fn from_integers() {
+        assert_eq!(Answer::from(42u32), Answer::Number(42));
+        assert_eq!(Answer::from(-7i32), Answer::Number(-7));
+        assert_eq!(Answer::from(u64::MAX), Answer::Number(u64::MAX as i128));
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(
+            Answer::Number(2758514936282235).to_string(),
+            "2758514936282235"
+        );
+        assert_eq!(
+            Answer::Text("#..#\n####".to_string()).to_string(),
+            "#..#\n####"
+        );
+        assert_eq!(Answer::Empty.to_string(), "-");
+    }
+
+    #[test]
+    fn serialize() {
+        assert_eq!(serde_json::to_string(&Answer::Number(-42)).unwrap(), "-42");
+        assert_eq!(
+            serde_json::to_string(&Answer::Text("#.\n.#".to_string())).unwrap(),
+            r##""#.\n.#""##
+        );
+        assert_eq!(serde_json::to_string(&Answer::Empty).unwrap(), "null");
+    }
+}
@@ -0,0 +1,16 @@
+use crate::{Rng, Solution};
+
+/// Random input generator of a day, to stress test and benchmark the solution at scale
+pub trait Generator: Solution {
+    /// Size of the generated inputs when none is requested, close to the size of the real input
+    const DEFAULT_SIZE: usize;
+
+    /// Generates a valid input of the given size, whose meaning depends on the puzzle (number of
+    /// lines, side of a grid...)
+    fn generate(size: usize, rng: &mut Rng) -> String;
+}
+
+/// Generates the input of the given size, or of the default size, from the seed
+pub fn generate<G: Generator>(size: Option<usize>, seed: u64) -> String {
+    G::generate(size.unwrap_or(G::DEFAULT_SIZE), &mut Rng::new(seed))
+}
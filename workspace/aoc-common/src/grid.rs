@@ -0,0 +1,362 @@
+use crate::{InputLine, ParseError};
+use std::ops::{Index, IndexMut};
+
+/// Offsets of the 4 orthogonal neighbours of a cell
+const ORTHOGONAL: [(i64, i64); 4] = [(-1, 0), (1, 0), (0, -1), (0, 1)];
+
+/// Offsets of the 8 neighbours of a cell, diagonals included
+const SURROUNDING: [(i64, i64); 8] = [
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+    (-1, 0),
+    (1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+];
+
+/// Rectangular grid of cells indexed by `(x, y)` positions, stored row by row. A wrapping grid is
+/// toroidal: stepping off an edge comes back from the opposite one.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    width: usize,
+    height: usize,
+    wrapping: bool,
+}
+
+impl<T> Grid<T> {
+    /// Grid of the given cells, row by row
+    pub fn new(width: usize, height: usize, cells: Vec<T>) -> Self {
+        assert_eq!(
+            cells.len(),
+            width * height,
+            "not a {}x{} grid",
+            width,
+            height
+        );
+
+        Grid {
+            cells,
+            width,
+            height,
+            wrapping: false,
+        }
+    }
+
+    pub fn filled(width: usize, height: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Grid::new(width, height, vec![value; width * height])
+    }
+
+    /// Grid of the given rows, which must all have the same length
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Self {
+        let width = rows.first().map_or(0, Vec::len);
+        let height = rows.len();
+
+        Grid::new(width, height, rows.into_iter().flatten().collect())
+    }
+
+    /// The same grid, toroidal
+    pub fn wrapping(self) -> Self {
+        Grid {
+            wrapping: true,
+            ..self
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn get(&self, (x, y): (usize, usize)) -> Option<&T> {
+        if x < self.width && y < self.height {
+            Some(&self.cells[y * self.width + x])
+        } else {
+            None
+        }
+    }
+
+    /// Cell at a position which may be out of the grid, wrapped around if the grid is toroidal
+    pub fn get_signed(&self, x: i64, y: i64) -> Option<&T> {
+        self.step((0, 0), (x, y)).map(|position| &self[position])
+    }
+
+    /// Position `offset` away from `position`, if it is in the grid
+    pub fn step(&self, (x, y): (usize, usize), (dx, dy): (i64, i64)) -> Option<(usize, usize)> {
+        let (x, y) = (x as i64 + dx, y as i64 + dy);
+        let (width, height) = (self.width as i64, self.height as i64);
+
+        if self.wrapping && width > 0 && height > 0 {
+            Some((x.rem_euclid(width) as usize, y.rem_euclid(height) as usize))
+        } else if (0..width).contains(&x) && (0..height).contains(&y) {
+            Some((x as usize, y as usize))
+        } else {
+            None
+        }
+    }
+
+    /// Positions of the 4 orthogonal neighbours of `position` in the grid. They are computed
+    /// upfront, so the grid can be modified while iterating over them.
+    pub fn neighbours_4(&self, position: (usize, usize)) -> impl Iterator<Item = (usize, usize)> {
+        ORTHOGONAL
+            .map(|offset| self.step(position, offset))
+            .into_iter()
+            .flatten()
+    }
+
+    /// Calls `visit` with the grid and the position of each of the 4 orthogonal neighbours of
+    /// `position`, in the same order as `neighbours_4`. Each neighbour of a non wrapping grid gets
+    /// its own unsigned bounds check and call, which keeps the hot loops of path finding as fast as
+    /// hand written ones.
+    #[inline]
+    pub fn visit_neighbours_4(
+        &mut self,
+        (x, y): (usize, usize),
+        mut visit: impl FnMut(&mut Self, (usize, usize)),
+    ) {
+        if self.wrapping {
+            for neighbour in self.neighbours_4((x, y)) {
+                visit(self, neighbour);
+            }
+            return;
+        }
+
+        if x > 0 {
+            visit(self, (x - 1, y));
+        }
+        if x + 1 < self.width {
+            visit(self, (x + 1, y));
+        }
+        if y > 0 {
+            visit(self, (x, y - 1));
+        }
+        if y + 1 < self.height {
+            visit(self, (x, y + 1));
+        }
+    }
+
+    /// Positions of the 8 neighbours of `position` in the grid, diagonals included
+    pub fn neighbours_8(
+        &self,
+        position: (usize, usize),
+    ) -> impl Iterator<Item = (usize, usize)> + '_ {
+        SURROUNDING
+            .iter()
+            .filter_map(move |&offset| self.step(position, offset))
+    }
+
+    /// Positions of all the cells, row by row
+    pub fn positions(&self) -> impl Iterator<Item = (usize, usize)> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| (x, y)))
+    }
+
+    /// Cells along with their positions, row by row
+    pub fn iter(&self) -> impl Iterator<Item = ((usize, usize), &T)> {
+        self.positions().zip(&self.cells)
+    }
+
+    pub fn values(&self) -> impl Iterator<Item = &T> {
+        self.cells.iter()
+    }
+
+    pub fn values_mut(&mut self) -> impl Iterator<Item = &mut T> {
+        self.cells.iter_mut()
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            cells: self.cells.iter().map(f).collect(),
+            width: self.width,
+            height: self.height,
+            wrapping: self.wrapping,
+        }
+    }
+
+    /// Renders the grid as text, a line per row
+    pub fn render(&self, mut render_cell: impl FnMut(&T) -> char) -> String {
+        let mut text = String::with_capacity((self.width + 1) * self.height);
+        for row in self.cells.chunks(self.width.max(1)) {
+            text.extend(row.iter().map(&mut render_cell));
+            text.push('\n');
+        }
+        text
+    }
+
+    /// Parses the lines as rows of cells, one per character. All the rows must have the same
+    /// width, `cells` naming the cells in the error of a row of another width ("10 heights").
+    pub fn parse_chars<'a>(
+        lines: impl IntoIterator<Item = InputLine<'a>>,
+        cells: &str,
+        expected: &str,
+        mut parse_cell: impl FnMut(char) -> Option<T>,
+    ) -> Result<Self, ParseError> {
+        let mut grid_cells = Vec::new();
+        let mut width = None;
+        let mut height = 0;
+        for line in lines {
+            let row_start = grid_cells.len();
+            for (offset, c) in line.text.char_indices() {
+                match parse_cell(c) {
+                    Some(cell) => grid_cells.push(cell),
+                    None => return Err(line.error_at(offset, expected)),
+                }
+            }
+
+            let row_width = grid_cells.len() - row_start;
+            let width = *width.get_or_insert(row_width);
+            if row_width != width {
+                return Err(line.error_at(row_width.min(width), format!("{} {}", width, cells)));
+            }
+            height += 1;
+        }
+
+        Ok(Grid::new(width.unwrap_or(0), height, grid_cells))
+    }
+}
+
+impl Grid<u8> {
+    /// Parses the lines as rows of single digits
+    pub fn parse_digits<'a>(
+        lines: impl IntoIterator<Item = InputLine<'a>>,
+        cells: &str,
+    ) -> Result<Self, ParseError> {
+        Grid::parse_chars(lines, cells, "a digit", |c| {
+            c.to_digit(10).map(|digit| digit as u8)
+        })
+    }
+}
+
+// Out of line so that the bounds check of the indexing stays cheap in hot loops
+#[cold]
+#[inline(never)]
+fn out_of_the_grid(x: usize, y: usize) -> ! {
+    panic!("({}, {}) out of the grid", x, y)
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (x, y): (usize, usize)) -> &T {
+        if x >= self.width || y >= self.height {
+            out_of_the_grid(x, y);
+        }
+        &self.cells[y * self.width + x]
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, (x, y): (usize, usize)) -> &mut T {
+        if x >= self.width || y >= self.height {
+            out_of_the_grid(x, y);
+        }
+        &mut self.cells[y * self.width + x]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{input_lines, Grid, ParseError};
+
+    #[test]
+    fn parse_and_render() {
+        let grid = Grid::parse_digits(input_lines(9, "219\n398\n"), "heights").unwrap();
+
+        assert_eq!(grid, Grid::from_rows(vec![vec![2, 1, 9], vec![3, 9, 8]]));
+        assert_eq!(grid[(2, 1)], 8);
+        assert_eq!(grid.get((3, 1)), None);
+        assert_eq!(grid.render(|&digit| (b'0' + digit) as char), "219\n398\n");
+
+        let grid = Grid::parse_chars(input_lines(25, "v.>\n"), "locations", "`>`", |c| {
+            Some(c == '>')
+        })
+        .unwrap();
+        assert_eq!(grid.render(|&east| if east { '>' } else { '.' }), "..>\n");
+    }
+
+    #[test]
+    fn parse_invalid_grids() {
+        assert_eq!(
+            Grid::parse_digits(input_lines(9, "219\n3x8\n"), "heights"),
+            Err(ParseError::new(9, 2, 2, "a digit"))
+        );
+        assert_eq!(
+            Grid::parse_digits(input_lines(9, "219\n39\n"), "heights"),
+            Err(ParseError::new(9, 2, 3, "3 heights"))
+        );
+        assert_eq!(
+            Grid::parse_digits(input_lines(9, "219\n3981\n"), "heights"),
+            Err(ParseError::new(9, 2, 4, "3 heights"))
+        );
+        assert_eq!(
+            Grid::parse_digits(input_lines(9, ""), "heights").map(|grid| grid.height()),
+            Ok(0)
+        );
+    }
+
+    #[test]
+    fn neighbours() {
+        let grid = Grid::filled(3, 2, 0);
+
+        assert_eq!(
+            grid.neighbours_4((0, 0)).collect::<Vec<_>>(),
+            vec![(1, 0), (0, 1)]
+        );
+        assert_eq!(
+            grid.neighbours_8((1, 1)).collect::<Vec<_>>(),
+            vec![(0, 0), (1, 0), (2, 0), (0, 1), (2, 1)]
+        );
+        assert_eq!(grid.step((2, 1), (1, 0)), None);
+        assert_eq!(grid.get_signed(-1, 0), None);
+    }
+
+    #[test]
+    fn visit_neighbours() {
+        let mut grid = Grid::filled(3, 2, 0);
+        grid.visit_neighbours_4((1, 0), |grid, neighbour| grid[neighbour] += 1);
+        grid.visit_neighbours_4((0, 1), |grid, neighbour| grid[neighbour] += 1);
+
+        assert_eq!(grid, Grid::new(3, 2, vec![2, 0, 1, 0, 2, 0]));
+
+        let mut grid = grid.wrapping();
+        let mut visited = Vec::new();
+        grid.visit_neighbours_4((0, 0), |_, neighbour| visited.push(neighbour));
+
+        assert_eq!(visited, grid.neighbours_4((0, 0)).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn wrap_around_edges() {
+        let grid = Grid::new(3, 2, (0..6).collect()).wrapping();
+
+        assert_eq!(grid.step((2, 1), (1, 0)), Some((0, 1)));
+        assert_eq!(grid.step((0, 0), (0, -1)), Some((0, 1)));
+        assert_eq!(grid.get_signed(-1, 3), Some(&5));
+        assert_eq!(
+            grid.neighbours_4((0, 0)).collect::<Vec<_>>(),
+            vec![(2, 0), (1, 0), (0, 1), (0, 1)]
+        );
+        assert_eq!(grid.neighbours_8((1, 0)).count(), 8);
+    }
+
+    #[test]
+    fn iterate_cells() {
+        let mut grid = Grid::new(2, 2, vec![1, 2, 3, 4]);
+        grid[(0, 1)] = 5;
+        grid.values_mut().for_each(|value| *value *= 10);
+
+        assert_eq!(
+            grid.iter().collect::<Vec<_>>(),
+            vec![((0, 0), &10), ((1, 0), &20), ((0, 1), &50), ((1, 1), &40)]
+        );
+        assert_eq!(grid.map(|value| value / 10).values().sum::<i32>(), 12);
+    }
+}
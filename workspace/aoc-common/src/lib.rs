@@ -0,0 +1,21 @@
+mod answer;
+mod generator;
+mod grid;
+mod output;
+mod parse_error;
+mod report;
+mod rng;
+mod solution;
+mod visualize;
+
+pub use answer::Answer;
+pub use generator::{generate, Generator};
+pub use grid::Grid;
+pub use output::{render_json, OutputFormat};
+pub use parse_error::{input_lines, read_lines, InputError, InputLine, ParseError};
+pub use report::{parse_input, solve, DayReport, ParsedInput, PartReport};
+pub use rng::Rng;
+pub use solution::{input_reader, run, run_visualized, Solution};
+pub use visualize::{
+    frame_sink, render_terminal, write_pgm, FrameSink, PgmFrames, TerminalFrames, Visualize,
+};
@@ -0,0 +1,121 @@
+use crate::{Answer, DayReport};
+use serde::Serialize;
+use std::str::FromStr;
+use std::time::Duration;
+
+/// How the answers are printed: human readable text, or JSON for scripts
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum OutputFormat {
+    Text,
+    Json,
+}
+
+impl FromStr for OutputFormat {
+    type Err = String;
+
+    fn from_str(format: &str) -> Result<Self, Self::Err> {
+        match format {
+            "text" => Ok(OutputFormat::Text),
+            "json" => Ok(OutputFormat::Json),
+            _ => Err(format!(
+                "Invalid format `{}`, expected text or json",
+                format
+            )),
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct JsonReport<'a> {
+    day: u8,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    part1: Option<&'a Answer>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    part2: Option<&'a Answer>,
+    timings_us: JsonTimings,
+}
+
+#[derive(Serialize)]
+struct JsonTimings {
+    parse: u128,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    part1: Option<u128>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    part2: Option<u128>,
+}
+
+/// Renders the report as a single line JSON object: the answers of the solved parts, along with
+/// the duration of each phase in microseconds
+pub fn render_json(report: &DayReport) -> String {
+    let part = |part: u8| report.parts.iter().find(|report| report.part == part);
+    let micros = |duration: Duration| duration.as_micros();
+
+    serde_json::to_string(&JsonReport {
+        day: report.day,
+        part1: part(1).map(|part| &part.answer),
+        part2: part(2).map(|part| &part.answer),
+        timings_us: JsonTimings {
+            parse: micros(report.parse_duration),
+            part1: part(1).map(|part| micros(part.duration)),
+            part2: part(2).map(|part| micros(part.duration)),
+        },
+    })
+    .unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::output::{render_json, OutputFormat};
+    use crate::{Answer, DayReport, PartReport};
+    use std::time::Duration;
+
+    #[test]
+    fn parse_formats() {
+        assert_eq!("text".parse(), Ok(OutputFormat::Text));
+        assert_eq!("json".parse(), Ok(OutputFormat::Json));
+        assert!("yaml".parse::<OutputFormat>().is_err());
+    }
+
+    #[test]
+    fn render_answers_as_json() {
+        let report = DayReport {
+            day: 13,
+            parse_duration: Duration::from_micros(12),
+            parts: vec![
+                PartReport {
+                    part: 1,
+                    answer: Answer::Number(675),
+                    duration: Duration::from_nanos(44_900),
+                },
+                PartReport {
+                    part: 2,
+                    answer: Answer::Text("#..#\n####".to_string()),
+                    duration: Duration::from_micros(244),
+                },
+            ],
+        };
+
+        assert_eq!(
+            render_json(&report),
+            r##"{"day":13,"part1":675,"part2":"#..#\n####","timings_us":{"parse":12,"part1":44,"part2":244}}"##
+        );
+    }
+
+    #[test]
+    fn render_requested_parts_only() {
+        let report = DayReport {
+            day: 25,
+            parse_duration: Duration::from_micros(3),
+            parts: vec![PartReport {
+                part: 2,
+                answer: Answer::Empty,
+                duration: Duration::ZERO,
+            }],
+        };
+
+        assert_eq!(
+            render_json(&report),
+            r#"{"day":25,"part2":null,"timings_us":{"parse":3,"part2":0}}"#
+        );
+    }
+}
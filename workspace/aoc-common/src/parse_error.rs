@@ -0,0 +1,234 @@
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::io;
+use std::io::BufRead;
+use std::str::FromStr;
+
+/// Error raised when a puzzle input does not have the expected format, located in the input
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct ParseError {
+    pub day: u8,
+    /// Line number, starting at 1
+    pub line: usize,
+    /// Column number, starting at 1
+    pub column: usize,
+    /// Description of the expected token
+    pub expected: String,
+}
+
+impl ParseError {
+    pub fn new(day: u8, line: usize, column: usize, expected: impl Into<String>) -> Self {
+        ParseError {
+            day,
+            line,
+            column,
+            expected: expected.into(),
+        }
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "day {}, line {}, column {}: expected {}",
+            self.day, self.line, self.column, self.expected
+        )
+    }
+}
+
+impl Error for ParseError {}
+
+/// Error raised when reading a puzzle input from a stream: either the stream itself failed, or its
+/// content does not have the expected format
+#[derive(Debug)]
+pub enum InputError {
+    Io(io::Error),
+    Parse(ParseError),
+}
+
+impl Display for InputError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InputError::Io(error) => write!(f, "{}", error),
+            InputError::Parse(error) => write!(f, "{}", error),
+        }
+    }
+}
+
+impl Error for InputError {}
+
+impl From<io::Error> for InputError {
+    fn from(error: io::Error) -> Self {
+        InputError::Io(error)
+    }
+}
+
+impl From<ParseError> for InputError {
+    fn from(error: ParseError) -> Self {
+        InputError::Parse(error)
+    }
+}
+
+/// A line of a puzzle input, knowing its location in order to build located parse errors
+#[derive(Debug, Clone, Copy)]
+pub struct InputLine<'a> {
+    pub day: u8,
+    /// Index of the line, starting at 0
+    pub index: usize,
+    pub text: &'a str,
+}
+
+impl<'a> InputLine<'a> {
+    pub fn new(day: u8, index: usize, text: &'a str) -> Self {
+        InputLine { day, index, text }
+    }
+
+    /// Error located at the start of `token`, which must be a slice of the line
+    pub fn error(&self, token: &str, expected: impl Into<String>) -> ParseError {
+        let start = self.text.as_ptr() as usize;
+        let offset = (token.as_ptr() as usize)
+            .saturating_sub(start)
+            .min(self.text.len());
+        self.error_at(offset, expected)
+    }
+
+    /// Error located right after the end of the line, for missing tokens
+    pub fn error_at_end(&self, expected: impl Into<String>) -> ParseError {
+        self.error_at(self.text.len(), expected)
+    }
+
+    /// Error located at the given byte offset of the line
+    pub fn error_at(&self, offset: usize, expected: impl Into<String>) -> ParseError {
+        let column = self.text[..offset].chars().count() + 1;
+        ParseError::new(self.day, self.index + 1, column, expected)
+    }
+
+    /// Parses `token`, which must be a slice of the line
+    pub fn parse<T: FromStr>(&self, token: &str, expected: &str) -> Result<T, ParseError> {
+        token.parse().map_err(|_| self.error(token, expected))
+    }
+
+    /// Parses the line as a row of single digits
+    pub fn digits(&self) -> Result<Vec<u8>, ParseError> {
+        self.text
+            .char_indices()
+            .map(|(offset, c)| match c.to_digit(10) {
+                Some(digit) => Ok(digit as u8),
+                None => Err(self.error_at(offset, "a digit")),
+            })
+            .collect()
+    }
+
+    /// Strips the expected `prefix` from the start of the line, returning the remaining text
+    pub fn strip_prefix(&self, prefix: &str) -> Result<&'a str, ParseError> {
+        self.text
+            .strip_prefix(prefix)
+            .ok_or_else(|| self.error(self.text, format!("`{}`", prefix.trim_end())))
+    }
+}
+
+/// Iterates over the lines of a puzzle input
+pub fn input_lines(day: u8, content: &str) -> impl Iterator<Item = InputLine<'_>> {
+    content
+        .lines()
+        .enumerate()
+        .map(move |(index, text)| InputLine::new(day, index, text))
+}
+
+/// Hands each line of a puzzle input stream to `parse_line` as soon as it is read, so that only
+/// one line is held in memory at a time. Lines are split the same way as with `input_lines`.
+pub fn read_lines<R: BufRead>(
+    day: u8,
+    mut reader: R,
+    mut parse_line: impl FnMut(InputLine) -> Result<(), ParseError>,
+) -> Result<(), InputError> {
+    let mut buffer = String::new();
+    for index in 0.. {
+        buffer.clear();
+        if reader.read_line(&mut buffer)? == 0 {
+            break;
+        }
+        let text = match buffer.strip_suffix('\n') {
+            Some(text) => text.strip_suffix('\r').unwrap_or(text),
+            None => &buffer,
+        };
+        parse_line(InputLine::new(day, index, text))?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{input_lines, read_lines, InputError, InputLine, ParseError};
+
+    #[test]
+    fn locate_tokens() {
+        let lines = input_lines(22, "on x=1..2\noff y=é..3").collect::<Vec<_>>();
+
+        let token = &lines[1].text[6..8];
+        assert_eq!(
+            lines[1].parse::<i32>(token, "a number"),
+            Err(ParseError::new(22, 2, 7, "a number"))
+        );
+        assert_eq!(
+            lines[0].parse::<i32>(&lines[0].text[5..6], "a number"),
+            Ok(1)
+        );
+        assert_eq!(
+            lines[0].error_at_end("`,`"),
+            ParseError::new(22, 1, 10, "`,`")
+        );
+        assert_eq!(
+            InputLine::new(9, 4, "2199943210").digits(),
+            Ok(vec![2, 1, 9, 9, 9, 4, 3, 2, 1, 0])
+        );
+        assert_eq!(
+            InputLine::new(9, 4, "21a9").digits(),
+            Err(ParseError::new(9, 5, 3, "a digit"))
+        );
+        assert_eq!(
+            lines[1].strip_prefix("on "),
+            Err(ParseError::new(22, 2, 1, "`on`"))
+        );
+    }
+
+    #[test]
+    fn read_lines_of_stream() {
+        let mut lines = Vec::new();
+        read_lines(1, "199\r\n200\n\n208".as_bytes(), |line| {
+            lines.push((line.index, line.text.to_string()));
+            Ok(())
+        })
+        .unwrap();
+
+        assert_eq!(
+            lines,
+            input_lines(1, "199\r\n200\n\n208")
+                .map(|line| (line.index, line.text.to_string()))
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn read_invalid_stream() {
+        let result = read_lines(1, "199\n2OO\n".as_bytes(), |line| {
+            line.parse::<u32>(line.text, "a depth").map(|_| ())
+        });
+        assert!(
+            matches!(result, Err(InputError::Parse(error)) if error == ParseError::new(1, 2, 1, "a depth"))
+        );
+
+        let result = read_lines(1, &[b'1', 0xff, b'\n'][..], |_| Ok(()));
+        assert!(matches!(result, Err(InputError::Io(_))));
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(
+            ParseError::new(1, 3, 5, "a number").to_string(),
+            "day 1, line 3, column 5: expected a number"
+        );
+    }
+}
@@ -0,0 +1,165 @@
+use crate::{Answer, Solution};
+use std::error::Error;
+use std::io::BufRead;
+use std::marker::PhantomData;
+use std::time::{Duration, Instant};
+
+/// Answer of one part of a puzzle, along with the wall-clock time it took to compute it
+#[derive(Debug, Clone)]
+pub struct PartReport {
+    pub part: u8,
+    pub answer: Answer,
+    pub duration: Duration,
+}
+
+/// Results of the requested parts of one day
+#[derive(Debug, Clone)]
+pub struct DayReport {
+    pub day: u8,
+    pub parse_duration: Duration,
+    pub parts: Vec<PartReport>,
+}
+
+/// Parses the input read from `reader` and solves the requested parts (1 and/or 2), timing each
+/// phase. Reading the input counts as parsing, as streaming parsers do both at once.
+pub fn solve<S: Solution>(
+    reader: &mut dyn BufRead,
+    parts: &[u8],
+) -> Result<DayReport, Box<dyn Error>> {
+    let start = Instant::now();
+    let input = S::parse_reader(reader)?;
+    let parse_duration = start.elapsed();
+
+    let parts = parts
+        .iter()
+        .map(|&part| solve_part::<S>(&input, part))
+        .collect::<Result<Vec<_>, Box<dyn Error>>>()?;
+
+    Ok(DayReport {
+        day: S::DAY,
+        parse_duration,
+        parts,
+    })
+}
+
+fn solve_part<S: Solution>(input: &S::Input, part: u8) -> Result<PartReport, Box<dyn Error>> {
+    let start = Instant::now();
+    let answer = match part {
+        1 => S::part_1(input),
+        2 => S::part_2(input),
+        _ => return Err(format!("invalid part {}", part).into()),
+    };
+
+    Ok(PartReport {
+        part,
+        answer,
+        duration: start.elapsed(),
+    })
+}
+
+/// Input of a day parsed ahead of solving its parts, which can then be solved concurrently from
+/// several threads
+pub struct ParsedInput {
+    pub day: u8,
+    pub parse_duration: Duration,
+    input: Box<dyn PartSolver>,
+}
+
+impl ParsedInput {
+    pub fn solve(&self, part: u8) -> Result<PartReport, Box<dyn Error>> {
+        self.input.solve(part)
+    }
+}
+
+/// Type erased input of a solution
+trait PartSolver: Send + Sync {
+    fn solve(&self, part: u8) -> Result<PartReport, Box<dyn Error>>;
+}
+
+struct SolutionInput<S: Solution> {
+    input: S::Input,
+    solution: PhantomData<fn() -> S>,
+}
+
+impl<S: Solution> PartSolver for SolutionInput<S>
+where
+    S::Input: Send + Sync,
+{
+    fn solve(&self, part: u8) -> Result<PartReport, Box<dyn Error>> {
+        solve_part::<S>(&self.input, part)
+    }
+}
+
+/// Parses the input read from `reader`, timing it, so that the parts can be solved later on
+pub fn parse_input<S: Solution + 'static>(
+    reader: &mut dyn BufRead,
+) -> Result<ParsedInput, Box<dyn Error>>
+where
+    S::Input: Send + Sync,
+{
+    let start = Instant::now();
+    let input = S::parse_reader(reader)?;
+    let parse_duration = start.elapsed();
+
+    Ok(ParsedInput {
+        day: S::DAY,
+        parse_duration,
+        input: Box::new(SolutionInput::<S> {
+            input,
+            solution: PhantomData,
+        }),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{input_lines, parse_input, solve, Answer, ParseError, Solution};
+
+    struct Sum;
+
+    impl Solution for Sum {
+        const DAY: u8 = 1;
+        type Input = Vec<u32>;
+
+        fn parse(content: String) -> Result<Vec<u32>, ParseError> {
+            input_lines(Self::DAY, &content)
+                .map(|line| line.parse(line.text, "a number"))
+                .collect()
+        }
+
+        fn part_1(input: &Vec<u32>) -> Answer {
+            input.iter().sum::<u32>().into()
+        }
+
+        fn part_2(input: &Vec<u32>) -> Answer {
+            input.iter().product::<u32>().into()
+        }
+    }
+
+    #[test]
+    fn solve_requested_parts() {
+        let report = solve::<Sum>(&mut "2\n3\n4".as_bytes(), &[2]).unwrap();
+
+        assert_eq!(report.day, 1);
+        assert_eq!(report.parts.len(), 1);
+        assert_eq!(report.parts[0].part, 2);
+        assert_eq!(report.parts[0].answer, Answer::Number(24));
+    }
+
+    #[test]
+    fn solve_parsed_input() {
+        let input = parse_input::<Sum>(&mut "2\n3\n4".as_bytes()).unwrap();
+
+        assert_eq!(input.day, 1);
+        assert_eq!(input.solve(1).unwrap().answer, Answer::Number(9));
+        assert_eq!(input.solve(2).unwrap().answer, Answer::Number(24));
+        assert!(input.solve(3).is_err());
+        assert!(parse_input::<Sum>(&mut "2\na".as_bytes()).is_err());
+    }
+
+    #[test]
+    fn solve_invalid_input() {
+        assert!(solve::<Sum>(&mut "2\na".as_bytes(), &[1, 2]).is_err());
+        assert!(solve::<Sum>(&mut "2\n3".as_bytes(), &[3]).is_err());
+    }
+}
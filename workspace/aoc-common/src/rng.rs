@@ -0,0 +1,92 @@
+use std::ops::RangeInclusive;
+
+/// Seeded pseudo-random number generator (SplitMix64). Unlike external generators, its sequence
+/// is part of this repository, so a seed always generates the same input.
+#[derive(Debug, Clone)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Rng { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// Uniformly distributed number in `0..bound`, which must not be empty
+    pub fn below(&mut self, bound: usize) -> usize {
+        assert!(bound > 0, "empty range");
+        ((self.next_u64() as u128 * bound as u128) >> 64) as usize
+    }
+
+    /// Uniformly distributed number in the range, which must not be empty
+    pub fn range(&mut self, range: RangeInclusive<i64>) -> i64 {
+        let (start, end) = range.into_inner();
+        assert!(start <= end, "empty range");
+        let span = (end as i128 - start as i128 + 1) as u128;
+        (start as i128 + ((self.next_u64() as u128 * span) >> 64) as i128) as i64
+    }
+
+    /// True with the given probability
+    pub fn chance(&mut self, probability: f64) -> bool {
+        ((self.next_u64() >> 11) as f64 / (1u64 << 53) as f64) < probability
+    }
+
+    pub fn choose<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.below(items.len())]
+    }
+
+    /// Fisher-Yates shuffle
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.below(i + 1));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Rng;
+
+    #[test]
+    fn same_seed_same_sequence() {
+        let mut rng = Rng::new(42);
+        let sequence = (0..5).map(|_| rng.next_u64()).collect::<Vec<_>>();
+
+        let mut other = Rng::new(42);
+        assert_eq!((0..5).map(|_| other.next_u64()).collect::<Vec<_>>(), sequence);
+        assert_ne!(Rng::new(43).next_u64(), sequence[0]);
+        // Reference values of SplitMix64
+        assert_eq!(Rng::new(0).next_u64(), 0xe220_a839_7b1d_cdaf);
+    }
+
+    #[test]
+    fn stay_in_range() {
+        let mut rng = Rng::new(7);
+        for _ in 0..1000 {
+            assert!((-3..=5).contains(&rng.range(-3..=5)));
+            assert!(rng.below(10) < 10);
+        }
+        assert_eq!(rng.range(4..=4), 4);
+        assert_eq!(rng.range(i64::MIN..=i64::MIN), i64::MIN);
+        assert!(!rng.chance(0.0));
+        assert!(rng.chance(1.0));
+    }
+
+    #[test]
+    fn shuffle_permutes() {
+        let mut items = (0..20).collect::<Vec<_>>();
+        Rng::new(1).shuffle(&mut items);
+
+        assert_ne!(items, (0..20).collect::<Vec<_>>());
+        items.sort();
+        assert_eq!(items, (0..20).collect::<Vec<_>>());
+    }
+}
@@ -0,0 +1,219 @@
+use crate::{
+    frame_sink, render_json, solve, Answer, FrameSink, InputError, OutputFormat, ParseError,
+    Visualize,
+};
+use std::env;
+use std::error::Error;
+use std::fs::File;
+use std::io::{self, stdin, BufRead, BufReader, Cursor, IsTerminal};
+use std::process::exit;
+
+/// Interface shared by every day of the calendar, so that any puzzle can be parsed and solved the
+/// same way.
+pub trait Solution {
+    /// Day of the calendar this solution belongs to
+    const DAY: u8;
+
+    /// Parsed puzzle input
+    type Input;
+
+    fn parse(content: String) -> Result<Self::Input, ParseError>;
+
+    /// Parses the input read from a stream. By default the whole stream is read before parsing
+    /// it, days made of independent lines parse them as they are read instead.
+    fn parse_reader<R: BufRead>(mut reader: R) -> Result<Self::Input, InputError> {
+        let mut content = String::new();
+        reader.read_to_string(&mut content)?;
+        Ok(Self::parse(content)?)
+    }
+
+    fn part_1(input: &Self::Input) -> Answer;
+
+    fn part_2(input: &Self::Input) -> Answer;
+}
+
+/// Visualization of the parsed input of a day, see [`Visualize`]
+type Visualization<I> = fn(&I, &mut dyn FrameSink) -> io::Result<()>;
+
+/// Entry point of the day binaries: parses the data file given as argument, or the standard input
+/// when it is `-` or piped, and prints the answers of both parts, as text or as JSON with
+/// `--format json`.
+pub fn run<S: Solution>() -> Result<(), Box<dyn Error>> {
+    run_with_visualization::<S>(None)
+}
+
+/// Entry point of the days that can be visualized: same as [`run`], but the simulation can also
+/// be exported frame by frame with `--visualize <dir>`, or animated in the terminal with
+/// `--visualize -`, before the answers are printed.
+pub fn run_visualized<S: Visualize>() -> Result<(), Box<dyn Error>> {
+    run_with_visualization::<S>(Some(S::visualize))
+}
+
+fn run_with_visualization<S: Solution>(
+    visualization: Option<Visualization<S::Input>>,
+) -> Result<(), Box<dyn Error>> {
+    let args: Vec<String> = env::args().skip(1).collect();
+
+    let usage = |error: &str| -> ! {
+        let visualize = if visualization.is_some() {
+            " [--visualize <dir|->]"
+        } else {
+            ""
+        };
+        eprintln!(
+            "{}\n\nUsage: day-{} <input|-> [--format <text|json>]{}",
+            error,
+            S::DAY,
+            visualize
+        );
+        exit(1);
+    };
+    let args = match parse_args(&args) {
+        Ok(args) => args,
+        Err(error) => usage(&error),
+    };
+    let mut reader = match input_reader(args.path)? {
+        Some(reader) => reader,
+        None => usage("Missing input file"),
+    };
+
+    if let Some(target) = args.visualize {
+        let visualize = visualization.unwrap_or_else(|| usage("Unknown flag `--visualize`"));
+
+        // The whole input is kept in memory, as it is parsed a second time for the answers
+        let mut content = String::new();
+        reader.read_to_string(&mut content)?;
+        visualize(&S::parse(content.clone())?, &mut *frame_sink(target)?)?;
+        reader = Box::new(Cursor::new(content));
+    }
+
+    match args.format {
+        OutputFormat::Text => {
+            let input = S::parse_reader(reader)?;
+
+            print_answer(1, &S::part_1(&input));
+            print_answer(2, &S::part_2(&input));
+        }
+        OutputFormat::Json => println!("{}", render_json(&solve::<S>(&mut reader, &[1, 2])?)),
+    }
+
+    Ok(())
+}
+
+/// Opens the input of a day binary: the file at `path`, or the standard input when it is `-` or
+/// piped. `None` when there is no input to read, rather than waiting on the terminal.
+pub fn input_reader(path: Option<&str>) -> io::Result<Option<Box<dyn BufRead>>> {
+    Ok(match path {
+        Some("-") => Some(Box::new(stdin().lock())),
+        Some(path) => Some(Box::new(BufReader::new(File::open(path)?))),
+        None if !stdin().is_terminal() => Some(Box::new(stdin().lock())),
+        None => None,
+    })
+}
+
+/// Arguments of the day binaries
+#[derive(Debug, Eq, PartialEq)]
+struct Args<'a> {
+    path: Option<&'a str>,
+    format: OutputFormat,
+    /// Directory of the frames of the visualization, or `-` for the terminal
+    visualize: Option<&'a str>,
+}
+
+/// Reads the optional input path, output format and visualization target
+fn parse_args(args: &[String]) -> Result<Args<'_>, String> {
+    let mut path = None;
+    let mut format = OutputFormat::Text;
+    let mut visualize = None;
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--format" => format = args.next().ok_or("Missing value for `--format`")?.parse()?,
+            "--visualize" => {
+                visualize = Some(
+                    args.next()
+                        .ok_or("Missing value for `--visualize`")?
+                        .as_str(),
+                )
+            }
+            flag if flag.starts_with("--") => return Err(format!("Unknown flag `{}`", flag)),
+            extra if path.is_some() => return Err(format!("Unexpected argument `{}`", extra)),
+            input => path = Some(input),
+        }
+    }
+
+    Ok(Args {
+        path,
+        format,
+        visualize,
+    })
+}
+
+fn print_answer(part: u8, answer: &Answer) {
+    match answer {
+        // Multi-line answers (e.g. drawn letters) are printed below the label to keep them readable
+        Answer::Text(text) if text.contains('\n') => println!("part {}:\n{}", part, text),
+        _ => println!("part {}: {}", part, answer),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::solution::{parse_args, Args};
+    use crate::OutputFormat;
+
+    fn args(line: &str) -> Vec<String> {
+        line.split_whitespace().map(String::from).collect()
+    }
+
+    fn parsed(path: Option<&str>, format: OutputFormat) -> Result<Args<'_>, String> {
+        Ok(Args {
+            path,
+            format,
+            visualize: None,
+        })
+    }
+
+    #[test]
+    fn parse_input_and_format() {
+        assert_eq!(
+            parse_args(&args("data.txt")),
+            parsed(Some("data.txt"), OutputFormat::Text)
+        );
+        assert_eq!(
+            parse_args(&args("--format json data.txt")),
+            parsed(Some("data.txt"), OutputFormat::Json)
+        );
+        assert_eq!(
+            parse_args(&args("- --format json")),
+            parsed(Some("-"), OutputFormat::Json)
+        );
+        assert_eq!(parse_args(&args("")), parsed(None, OutputFormat::Text));
+    }
+
+    #[test]
+    fn parse_visualization_target() {
+        assert_eq!(
+            parse_args(&args("data.txt --visualize frames")),
+            Ok(Args {
+                path: Some("data.txt"),
+                format: OutputFormat::Text,
+                visualize: Some("frames"),
+            })
+        );
+        assert_eq!(
+            parse_args(&args("--visualize - data.txt")).map(|args| args.visualize),
+            Ok(Some("-"))
+        );
+    }
+
+    #[test]
+    fn parse_invalid_args() {
+        assert!(parse_args(&args("data.txt other.txt")).is_err());
+        assert!(parse_args(&args("data.txt --format")).is_err());
+        assert!(parse_args(&args("data.txt --format yaml")).is_err());
+        assert!(parse_args(&args("data.txt --verbose")).is_err());
+        assert!(parse_args(&args("data.txt --visualize")).is_err());
+    }
+}
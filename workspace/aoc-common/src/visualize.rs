@@ -0,0 +1,145 @@
+use crate::{Grid, Solution};
+use std::fs::{create_dir_all, File};
+use std::io::{self, stderr, BufWriter, Write};
+use std::path::PathBuf;
+use std::thread::sleep;
+use std::time::Duration;
+
+/// Characters of the terminal rendering, from black to white
+const SHADES: &[u8] = b" .:-=+*#%@";
+
+/// Delay between two frames of the terminal animation
+const FRAME_DELAY: Duration = Duration::from_millis(80);
+
+/// Simulation of a day whose intermediate states can be watched
+pub trait Visualize: Solution {
+    /// Sends the successive states of the simulation to the sink, as frames of gray levels from 0
+    /// (black) to 255 (white)
+    fn visualize(input: &Self::Input, frames: &mut dyn FrameSink) -> io::Result<()>;
+}
+
+/// Destination of the frames of a visualization
+pub trait FrameSink {
+    fn frame(&mut self, frame: &Grid<u8>) -> io::Result<()>;
+}
+
+/// Collects the frames in memory
+impl FrameSink for Vec<Grid<u8>> {
+    fn frame(&mut self, frame: &Grid<u8>) -> io::Result<()> {
+        self.push(frame.clone());
+        Ok(())
+    }
+}
+
+/// Writes each frame as a numbered PGM image (`frame-00000.pgm`, `frame-00001.pgm`...) in a
+/// directory, which is created if needed
+pub struct PgmFrames {
+    directory: PathBuf,
+    count: usize,
+}
+
+impl PgmFrames {
+    pub fn new(directory: impl Into<PathBuf>) -> io::Result<Self> {
+        let directory = directory.into();
+        create_dir_all(&directory)?;
+
+        Ok(PgmFrames {
+            directory,
+            count: 0,
+        })
+    }
+}
+
+impl FrameSink for PgmFrames {
+    fn frame(&mut self, frame: &Grid<u8>) -> io::Result<()> {
+        let path = self.directory.join(format!("frame-{:05}.pgm", self.count));
+        let mut writer = BufWriter::new(File::create(path)?);
+        write_pgm(frame, &mut writer)?;
+        writer.flush()?;
+
+        self.count += 1;
+        Ok(())
+    }
+}
+
+/// Animates the frames in the terminal, drawing each one over the previous one
+pub struct TerminalFrames<W: Write> {
+    output: W,
+    delay: Duration,
+}
+
+impl<W: Write> TerminalFrames<W> {
+    pub fn new(output: W, delay: Duration) -> Self {
+        TerminalFrames { output, delay }
+    }
+}
+
+impl<W: Write> FrameSink for TerminalFrames<W> {
+    fn frame(&mut self, frame: &Grid<u8>) -> io::Result<()> {
+        // Clear the screen and move the cursor back to its top left corner
+        write!(self.output, "\x1b[2J\x1b[H{}", render_terminal(frame))?;
+        self.output.flush()?;
+
+        sleep(self.delay);
+        Ok(())
+    }
+}
+
+/// Sink of the `--visualize` target: a terminal animation on the standard error for `-`, PGM
+/// images in the target directory otherwise
+pub fn frame_sink(target: &str) -> io::Result<Box<dyn FrameSink>> {
+    match target {
+        "-" => Ok(Box::new(TerminalFrames::new(stderr(), FRAME_DELAY))),
+        directory => Ok(Box::new(PgmFrames::new(directory)?)),
+    }
+}
+
+/// Writes the frame as a binary PGM image
+pub fn write_pgm(frame: &Grid<u8>, writer: &mut impl Write) -> io::Result<()> {
+    write!(writer, "P5\n{} {}\n255\n", frame.width(), frame.height())?;
+    writer.write_all(&frame.values().copied().collect::<Vec<_>>())
+}
+
+/// Renders the frame as text, a character per pixel
+pub fn render_terminal(frame: &Grid<u8>) -> String {
+    frame.render(|&level| SHADES[level as usize * (SHADES.len() - 1) / 255] as char)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::visualize::{render_terminal, write_pgm, FrameSink, PgmFrames};
+    use crate::Grid;
+    use std::env::temp_dir;
+    use std::fs::{read, remove_dir_all};
+
+    #[test]
+    fn write_pgm_image() {
+        let frame = Grid::new(3, 2, vec![0, 128, 255, 255, 0, 1]);
+        let mut image = Vec::new();
+        write_pgm(&frame, &mut image).unwrap();
+
+        assert_eq!(image, b"P5\n3 2\n255\n\x00\x80\xff\xff\x00\x01");
+    }
+
+    #[test]
+    fn render_shades() {
+        let frame = Grid::new(3, 2, vec![0, 128, 255, 255, 0, 30]);
+
+        assert_eq!(render_terminal(&frame), " =@\n@ .\n");
+    }
+
+    #[test]
+    fn write_numbered_images() {
+        let directory = temp_dir().join(format!("aoc-frames-{}", std::process::id()));
+        let mut frames = PgmFrames::new(&directory).unwrap();
+        frames.frame(&Grid::filled(1, 1, 0)).unwrap();
+        frames.frame(&Grid::filled(2, 1, 255)).unwrap();
+
+        assert_eq!(
+            read(directory.join("frame-00001.pgm")).unwrap(),
+            b"P5\n2 1\n255\n\xff\xff"
+        );
+        assert!(directory.join("frame-00000.pgm").exists());
+        remove_dir_all(directory).unwrap();
+    }
+}
@@ -0,0 +1,138 @@
+use std::fmt::Write;
+
+const MANIFEST_HEADER: &str = "\
+# Expected answers of the checked-in inputs, one `day part input-hash answer` entry per line.
+# Line breaks in multi-line answers are written as `\\n`, backslashes as `\\\\`.
+# Regenerate with `aoc check --all --update`.
+";
+
+/// Answer recorded for one part of a day, valid for the input with the given hash only
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct ExpectedAnswer {
+    pub day: u8,
+    pub part: u8,
+    pub input_hash: u64,
+    pub answer: String,
+}
+
+/// 64 bits FNV-1a hash of the input: unlike the std hashers it is stable across platforms and
+/// Rust releases, so it can be checked in
+pub fn input_hash(content: &str) -> u64 {
+    content.bytes().fold(0xcbf2_9ce4_8422_2325, |hash, byte| {
+        (hash ^ byte as u64).wrapping_mul(0x0100_0000_01b3)
+    })
+}
+
+/// Parses the manifest, ignoring empty lines and `#` comments
+pub fn parse_manifest(content: &str) -> Result<Vec<ExpectedAnswer>, String> {
+    content
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.is_empty() && !line.starts_with('#'))
+        .map(|(index, line)| {
+            let invalid = || format!("line {}: expected `day part input-hash answer`", index + 1);
+            let mut fields = line.splitn(4, ' ');
+            let mut field = || fields.next().ok_or_else(invalid);
+
+            Ok(ExpectedAnswer {
+                day: field()?.parse().map_err(|_| invalid())?,
+                part: field()?.parse().map_err(|_| invalid())?,
+                input_hash: u64::from_str_radix(field()?, 16).map_err(|_| invalid())?,
+                answer: unescape(field()?),
+            })
+        })
+        .collect()
+}
+
+/// Renders the manifest sorted by day and part
+pub fn render_manifest(answers: &[ExpectedAnswer]) -> String {
+    let mut answers = answers.to_vec();
+    answers.sort_by_key(|answer| (answer.day, answer.part));
+
+    let mut manifest = MANIFEST_HEADER.to_string();
+    for answer in answers {
+        writeln!(
+            manifest,
+            "{} {} {:016x} {}",
+            answer.day,
+            answer.part,
+            answer.input_hash,
+            escape(&answer.answer)
+        )
+        .unwrap();
+    }
+
+    manifest
+}
+
+fn escape(answer: &str) -> String {
+    answer.replace('\\', "\\\\").replace('\n', "\\n")
+}
+
+fn unescape(answer: &str) -> String {
+    let mut unescaped = String::with_capacity(answer.len());
+    let mut chars = answer.chars();
+    while let Some(c) = chars.next() {
+        match (c, chars.clone().next()) {
+            ('\\', Some('n')) => {
+                unescaped.push('\n');
+                chars.next();
+            }
+            ('\\', Some('\\')) => {
+                unescaped.push('\\');
+                chars.next();
+            }
+            _ => unescaped.push(c),
+        }
+    }
+    unescaped
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::answers::{input_hash, parse_manifest, render_manifest, ExpectedAnswer};
+
+    #[test]
+    fn hash_inputs() {
+        assert_eq!(input_hash(""), 0xcbf2_9ce4_8422_2325);
+        assert_eq!(input_hash("a"), 0xaf63_dc4c_8601_ec8c);
+        assert_ne!(input_hash("199\n200\n"), input_hash("199\n201\n"));
+    }
+
+    #[test]
+    fn manifest_round_trip() {
+        let answers = vec![
+            ExpectedAnswer {
+                day: 13,
+                part: 2,
+                input_hash: 0xff,
+                answer: "#..#\n#\\.#".to_string(),
+            },
+            ExpectedAnswer {
+                day: 1,
+                part: 1,
+                input_hash: 0x1234_5678_9abc_def0,
+                answer: "7".to_string(),
+            },
+        ];
+
+        let manifest = render_manifest(&answers);
+        assert!(
+            manifest.ends_with("1 1 123456789abcdef0 7\n13 2 00000000000000ff #..#\\n#\\\\.#\n")
+        );
+        assert_eq!(
+            parse_manifest(&manifest),
+            Ok(vec![answers[1].clone(), answers[0].clone()])
+        );
+    }
+
+    #[test]
+    fn parse_invalid_manifest() {
+        assert_eq!(
+            parse_manifest("# comment\n\n1 1 ff\n"),
+            Err("line 3: expected `day part input-hash answer`".to_string())
+        );
+        assert!(parse_manifest("1 one ff 7\n").is_err());
+        assert!(parse_manifest("1 1 fg 7\n").is_err());
+    }
+}
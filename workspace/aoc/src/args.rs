@@ -0,0 +1,477 @@
+use crate::days::DAYS;
+use crate::input::STDIN;
+use aoc_common::OutputFormat;
+use std::path::PathBuf;
+use std::time::Duration;
+
+pub const USAGE: &str = "Usage:
+    aoc run <day> [--part <1|2>] [--format <text|json>] [--threads <n>] [--timeout <seconds>] [input]
+    aoc run --all [--part <1|2>] [--format <text|json>] [--threads <n>] [--timeout <seconds>] [inputs]
+    aoc check <day> [--answers <file>] [--update] [input]
+    aoc check --all [--answers <file>] [--update] [inputs]
+    aoc bench <day> [--samples <n>] [--json <file>] [--baseline <file>] [--threshold <percents>] [input]
+    aoc bench --all [--samples <n>] [--json <file>] [--baseline <file>] [--threshold <percents>] [inputs]
+    aoc gen <day> [--size <n>] [--seed <n>] [--output <file>]
+
+The input can either be a file or a directory in which the file of each day is looked up as
+`day-N.txt` or `day-N/dataset/data.txt`. It defaults to the current directory. A single day can
+also read its input from the standard input with `-`.
+
+`run` prints a table of the answers, or one JSON object per line and day with `--format json`.
+The parts of all the days are solved concurrently on `--threads` threads (one per CPU by default),
+which the slowest days also use internally. Days still running `--timeout` seconds after they
+started are reported as failed.
+
+`check` compares the answers with the ones recorded in the answers manifest (`answers.txt` by
+default), `--update` records the current answers instead.
+
+`bench` times the parse, part 1 and part 2 phases of each day over up to `--samples` runs (10 by
+default) and optionally saves them as JSON. Given the JSON of a previous run as baseline, the
+phases whose median got slower by more than `--threshold` percents (10 by default) are reported.
+
+`gen` generates a random input of the day, of `--size` lines, grid side... depending on the puzzle
+(about the size of the real input by default). The same `--seed` (0 by default) always generates
+the same input. It is printed, or written to the `--output` file.";
+
+#[derive(Debug, PartialEq)]
+pub enum Command {
+    Run(RunOptions),
+    Check(CheckOptions),
+    Bench(BenchOptions),
+    Generate(GenerateOptions),
+}
+
+#[derive(Debug, Eq, PartialEq)]
+pub struct RunOptions {
+    pub days: Vec<u8>,
+    pub parts: Vec<u8>,
+    pub format: OutputFormat,
+    pub threads: Option<usize>,
+    pub timeout: Option<Duration>,
+    pub input: PathBuf,
+}
+
+#[derive(Debug, Eq, PartialEq)]
+pub struct CheckOptions {
+    pub days: Vec<u8>,
+    pub input: PathBuf,
+    pub answers: PathBuf,
+    pub update: bool,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct BenchOptions {
+    pub days: Vec<u8>,
+    pub input: PathBuf,
+    pub samples: usize,
+    pub json: Option<PathBuf>,
+    pub baseline: Option<PathBuf>,
+    pub threshold: f64,
+}
+
+#[derive(Debug, Eq, PartialEq)]
+pub struct GenerateOptions {
+    pub day: u8,
+    pub size: Option<usize>,
+    pub seed: u64,
+    pub output: Option<PathBuf>,
+}
+
+pub fn parse_args(args: &[String]) -> Result<Command, String> {
+    match args.first().map(String::as_str) {
+        Some("run") => Ok(Command::Run(parse_run_args(&args[1..])?)),
+        Some("check") => Ok(Command::Check(parse_check_args(&args[1..])?)),
+        Some("bench") => Ok(Command::Bench(parse_bench_args(&args[1..])?)),
+        Some("gen") => Ok(Command::Generate(parse_generate_args(&args[1..])?)),
+        Some(command) => Err(format!("Unknown command `{}`", command)),
+        None => Err("Missing command".to_string()),
+    }
+}
+
+fn parse_run_args(args: &[String]) -> Result<RunOptions, String> {
+    let mut all = false;
+    let mut parts = vec![1, 2];
+    let mut format = OutputFormat::Text;
+    let mut threads = None;
+    let mut timeout = None;
+    let mut positionals = Vec::new();
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--all" => all = true,
+            "--part" => {
+                let part = args.next().ok_or("Missing value for `--part`")?;
+                match part.as_str() {
+                    "1" => parts = vec![1],
+                    "2" => parts = vec![2],
+                    _ => return Err(format!("Invalid part `{}`, expected 1 or 2", part)),
+                }
+            }
+            "--format" => {
+                format = args.next().ok_or("Missing value for `--format`")?.parse()?;
+            }
+            "--threads" => {
+                let value = args.next().ok_or("Missing value for `--threads`")?;
+                threads = match value.parse::<usize>() {
+                    Ok(threads) if threads > 0 => Some(threads),
+                    _ => return Err(format!("Invalid number of threads `{}`", value)),
+                };
+            }
+            "--timeout" => {
+                let value = args.next().ok_or("Missing value for `--timeout`")?;
+                timeout = match value.parse::<f64>() {
+                    Ok(seconds) if seconds > 0.0 && seconds.is_finite() => {
+                        Some(Duration::from_secs_f64(seconds))
+                    }
+                    _ => return Err(format!("Invalid timeout `{}`", value)),
+                };
+            }
+            flag if flag.starts_with("--") => return Err(format!("Unknown flag `{}`", flag)),
+            positional => positionals.push(positional),
+        }
+    }
+
+    let (days, input) = parse_days_and_input(all, positionals)?;
+
+    Ok(RunOptions {
+        days,
+        parts,
+        format,
+        threads,
+        timeout,
+        input,
+    })
+}
+
+fn parse_check_args(args: &[String]) -> Result<CheckOptions, String> {
+    let mut all = false;
+    let mut answers = PathBuf::from("answers.txt");
+    let mut update = false;
+    let mut positionals = Vec::new();
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--all" => all = true,
+            "--answers" => {
+                answers = PathBuf::from(args.next().ok_or("Missing value for `--answers`")?);
+            }
+            "--update" => update = true,
+            flag if flag.starts_with("--") => return Err(format!("Unknown flag `{}`", flag)),
+            positional => positionals.push(positional),
+        }
+    }
+
+    let (days, input) = parse_days_and_input(all, positionals)?;
+
+    Ok(CheckOptions {
+        days,
+        input,
+        answers,
+        update,
+    })
+}
+
+fn parse_bench_args(args: &[String]) -> Result<BenchOptions, String> {
+    let mut all = false;
+    let mut samples = 10;
+    let mut json = None;
+    let mut baseline = None;
+    let mut threshold = 10.0;
+    let mut positionals = Vec::new();
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--all" => all = true,
+            "--samples" => {
+                let value = args.next().ok_or("Missing value for `--samples`")?;
+                samples = match value.parse::<usize>() {
+                    Ok(samples) if samples > 0 => samples,
+                    _ => return Err(format!("Invalid number of samples `{}`", value)),
+                };
+            }
+            "--json" => {
+                json = Some(PathBuf::from(
+                    args.next().ok_or("Missing value for `--json`")?,
+                ))
+            }
+            "--baseline" => {
+                baseline = Some(PathBuf::from(
+                    args.next().ok_or("Missing value for `--baseline`")?,
+                ));
+            }
+            "--threshold" => {
+                let value = args.next().ok_or("Missing value for `--threshold`")?;
+                threshold = match value.parse::<f64>() {
+                    Ok(threshold) if threshold >= 0.0 => threshold,
+                    _ => return Err(format!("Invalid threshold `{}`", value)),
+                };
+            }
+            flag if flag.starts_with("--") => return Err(format!("Unknown flag `{}`", flag)),
+            positional => positionals.push(positional),
+        }
+    }
+
+    let (days, input) = parse_days_and_input(all, positionals)?;
+
+    Ok(BenchOptions {
+        days,
+        input,
+        samples,
+        json,
+        baseline,
+        threshold,
+    })
+}
+
+fn parse_generate_args(args: &[String]) -> Result<GenerateOptions, String> {
+    let mut size = None;
+    let mut seed = 0;
+    let mut output = None;
+    let mut positionals = Vec::new();
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--size" => {
+                let value = args.next().ok_or("Missing value for `--size`")?;
+                size = match value.parse::<usize>() {
+                    Ok(size) if size > 0 => Some(size),
+                    _ => return Err(format!("Invalid size `{}`", value)),
+                };
+            }
+            "--seed" => {
+                let value = args.next().ok_or("Missing value for `--seed`")?;
+                seed = value
+                    .parse::<u64>()
+                    .map_err(|_| format!("Invalid seed `{}`", value))?;
+            }
+            "--output" => {
+                output = Some(PathBuf::from(
+                    args.next().ok_or("Missing value for `--output`")?,
+                ));
+            }
+            flag if flag.starts_with("--") => return Err(format!("Unknown flag `{}`", flag)),
+            positional => positionals.push(positional),
+        }
+    }
+
+    // Generated inputs have no input argument
+    if let Some(extra) = positionals.get(1) {
+        return Err(format!("Unexpected argument `{}`", extra));
+    }
+    let (days, _) = parse_days_and_input(false, positionals)?;
+
+    Ok(GenerateOptions {
+        day: days[0],
+        size,
+        seed,
+        output,
+    })
+}
+
+/// Reads the day (unless all of them are requested) and the optional input from the positional
+/// arguments
+fn parse_days_and_input(all: bool, positionals: Vec<&str>) -> Result<(Vec<u8>, PathBuf), String> {
+    let mut positionals = positionals.into_iter();
+    let days = if all {
+        DAYS.collect()
+    } else {
+        let day = positionals.next().ok_or("Missing day")?;
+        match day.parse::<u8>() {
+            Ok(day) if DAYS.contains(&day) => vec![day],
+            _ => return Err(format!("Invalid day `{}`, expected 1 to 25", day)),
+        }
+    };
+    let input = PathBuf::from(positionals.next().unwrap_or("."));
+    if let Some(extra) = positionals.next() {
+        return Err(format!("Unexpected argument `{}`", extra));
+    }
+    if input.as_os_str() == STDIN && days.len() > 1 {
+        return Err("The standard input can only be the input of a single day".to_string());
+    }
+
+    Ok((days, input))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::args::{
+        parse_args, BenchOptions, CheckOptions, Command, GenerateOptions, RunOptions,
+    };
+    use aoc_common::OutputFormat;
+    use std::path::PathBuf;
+    use std::time::Duration;
+
+    fn args(line: &str) -> Vec<String> {
+        line.split_whitespace().map(String::from).collect()
+    }
+
+    #[test]
+    fn parse_single_day() {
+        assert_eq!(
+            parse_args(&args("run 15 --part 2 input.txt")),
+            Ok(Command::Run(RunOptions {
+                days: vec![15],
+                parts: vec![2],
+                format: OutputFormat::Text,
+                threads: None,
+                timeout: None,
+                input: PathBuf::from("input.txt"),
+            }))
+        );
+        assert_eq!(
+            parse_args(&args("run 3 --format json")),
+            Ok(Command::Run(RunOptions {
+                days: vec![3],
+                parts: vec![1, 2],
+                format: OutputFormat::Json,
+                threads: None,
+                timeout: None,
+                input: PathBuf::from("."),
+            }))
+        );
+    }
+
+    #[test]
+    fn parse_pool_options() {
+        assert_eq!(
+            parse_args(&args("run --all --threads 4 --timeout 2.5")),
+            Ok(Command::Run(RunOptions {
+                days: (1..=25).collect(),
+                parts: vec![1, 2],
+                format: OutputFormat::Text,
+                threads: Some(4),
+                timeout: Some(Duration::from_millis(2500)),
+                input: PathBuf::from("."),
+            }))
+        );
+    }
+
+    #[test]
+    fn parse_stdin_input() {
+        assert_eq!(
+            parse_args(&args("run 5 -")),
+            Ok(Command::Run(RunOptions {
+                days: vec![5],
+                parts: vec![1, 2],
+                format: OutputFormat::Text,
+                threads: None,
+                timeout: None,
+                input: PathBuf::from("-"),
+            }))
+        );
+    }
+
+    #[test]
+    fn parse_all_days() {
+        assert_eq!(
+            parse_args(&args("run --all inputs/")),
+            Ok(Command::Run(RunOptions {
+                days: (1..=25).collect(),
+                parts: vec![1, 2],
+                format: OutputFormat::Text,
+                threads: None,
+                timeout: None,
+                input: PathBuf::from("inputs/"),
+            }))
+        );
+    }
+
+    #[test]
+    fn parse_check() {
+        assert_eq!(
+            parse_args(&args("check --all --update")),
+            Ok(Command::Check(CheckOptions {
+                days: (1..=25).collect(),
+                input: PathBuf::from("."),
+                answers: PathBuf::from("answers.txt"),
+                update: true,
+            }))
+        );
+        assert_eq!(
+            parse_args(&args("check 18 --answers expected.txt inputs/")),
+            Ok(Command::Check(CheckOptions {
+                days: vec![18],
+                input: PathBuf::from("inputs/"),
+                answers: PathBuf::from("expected.txt"),
+                update: false,
+            }))
+        );
+    }
+
+    #[test]
+    fn parse_bench() {
+        assert_eq!(
+            parse_args(&args("bench --all")),
+            Ok(Command::Bench(BenchOptions {
+                days: (1..=25).collect(),
+                input: PathBuf::from("."),
+                samples: 10,
+                json: None,
+                baseline: None,
+                threshold: 10.0,
+            }))
+        );
+        assert_eq!(
+            parse_args(&args(
+                "bench 23 --samples 3 --json new.json --baseline old.json --threshold 5 inputs/"
+            )),
+            Ok(Command::Bench(BenchOptions {
+                days: vec![23],
+                input: PathBuf::from("inputs/"),
+                samples: 3,
+                json: Some(PathBuf::from("new.json")),
+                baseline: Some(PathBuf::from("old.json")),
+                threshold: 5.0,
+            }))
+        );
+    }
+
+    #[test]
+    fn parse_generate() {
+        assert_eq!(
+            parse_args(&args("gen 5")),
+            Ok(Command::Generate(GenerateOptions {
+                day: 5,
+                size: None,
+                seed: 0,
+                output: None,
+            }))
+        );
+        assert_eq!(
+            parse_args(&args("gen 22 --size 1000 --seed 7 --output day-22.txt")),
+            Ok(Command::Generate(GenerateOptions {
+                day: 22,
+                size: Some(1000),
+                seed: 7,
+                output: Some(PathBuf::from("day-22.txt")),
+            }))
+        );
+    }
+
+    #[test]
+    fn parse_invalid_args() {
+        assert!(parse_args(&args("")).is_err());
+        assert!(parse_args(&args("walk 1")).is_err());
+        assert!(parse_args(&args("run")).is_err());
+        assert!(parse_args(&args("run 26 input.txt")).is_err());
+        assert!(parse_args(&args("run 1 --part 3 input.txt")).is_err());
+        assert!(parse_args(&args("run 1 --part")).is_err());
+        assert!(parse_args(&args("run 1 --format xml")).is_err());
+        assert!(parse_args(&args("run 1 input.txt other.txt")).is_err());
+        assert!(parse_args(&args("run --all -")).is_err());
+        assert!(parse_args(&args("run --all --threads 0")).is_err());
+        assert!(parse_args(&args("run --all --timeout -1")).is_err());
+        assert!(parse_args(&args("check 1 --answers")).is_err());
+        assert!(parse_args(&args("check 1 --part 1")).is_err());
+        assert!(parse_args(&args("bench 1 --samples 0")).is_err());
+        assert!(parse_args(&args("bench 1 --threshold -5")).is_err());
+        assert!(parse_args(&args("gen --all")).is_err());
+        assert!(parse_args(&args("gen 1 --size 0")).is_err());
+        assert!(parse_args(&args("gen 1 --seed -1")).is_err());
+        assert!(parse_args(&args("gen 1 input.txt")).is_err());
+    }
+}
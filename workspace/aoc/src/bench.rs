@@ -0,0 +1,351 @@
+use crate::answers::input_hash;
+use crate::args::BenchOptions;
+use crate::days::{solver, Solver};
+use crate::input::read_inputs;
+use crate::table::Alignment::{Left, Right};
+use crate::table::{format_duration, render_rows};
+use serde::{Deserialize, Serialize};
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::fs;
+use std::time::{Duration, Instant};
+
+const HEADERS: [&str; 7] = [
+    "Day", "Phase", "Samples", "Median", "Min", "Baseline", "Change",
+];
+
+/// A day stops being sampled once it has run for that long, so the slowest days are only solved
+/// a few times
+const TIME_BUDGET: Duration = Duration::from_secs(5);
+
+/// Slowdowns below this are measurement noise whatever their ratio, they are never regressions
+const NOISE_FLOOR: Duration = Duration::from_micros(20);
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Serialize, Deserialize)]
+pub enum Phase {
+    #[serde(rename = "parse")]
+    Parse,
+    #[serde(rename = "part_1")]
+    Part1,
+    #[serde(rename = "part_2")]
+    Part2,
+}
+
+impl Display for Phase {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Phase::Parse => write!(f, "parse"),
+            Phase::Part1 => write!(f, "part 1"),
+            Phase::Part2 => write!(f, "part 2"),
+        }
+    }
+}
+
+/// Timings of one phase of a day over all its samples
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub struct PhaseTiming {
+    pub day: u8,
+    pub phase: Phase,
+    /// Timings of different inputs are not comparable
+    pub input_hash: String,
+    pub samples: usize,
+    pub median_ns: u64,
+    pub min_ns: u64,
+}
+
+/// Results of a benchmark run, as saved in JSON and loaded back as a baseline
+#[derive(Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct BenchReport {
+    pub timings: Vec<PhaseTiming>,
+}
+
+/// Change of a phase median relative to the same phase in the baseline
+#[derive(Debug, PartialEq)]
+pub struct Comparison {
+    pub baseline_ns: u64,
+    /// In percents of the baseline
+    pub change: f64,
+    pub regression: bool,
+}
+
+/// Solves both parts of the day until `samples` runs are done or the time budget is spent, and
+/// returns the timings of each phase
+pub fn measure(
+    day: u8,
+    solver: Solver,
+    content: &str,
+    samples: usize,
+) -> Result<Vec<PhaseTiming>, Box<dyn Error>> {
+    let mut durations = [Vec::new(), Vec::new(), Vec::new()];
+    let start = Instant::now();
+    while durations[0].len() < samples.max(1)
+        && (durations[0].is_empty() || start.elapsed() < TIME_BUDGET)
+    {
+        let report = solver(&mut content.as_bytes(), &[1, 2])?;
+        durations[0].push(report.parse_duration);
+        for part in &report.parts {
+            durations[part.part as usize].push(part.duration);
+        }
+    }
+
+    let input_hash = format!("{:016x}", input_hash(content));
+    Ok([Phase::Parse, Phase::Part1, Phase::Part2]
+        .into_iter()
+        .zip(durations)
+        .map(|(phase, durations)| timing(day, phase, &input_hash, durations))
+        .collect())
+}
+
+fn timing(day: u8, phase: Phase, input_hash: &str, mut durations: Vec<Duration>) -> PhaseTiming {
+    durations.sort();
+
+    PhaseTiming {
+        day,
+        phase,
+        input_hash: input_hash.to_string(),
+        samples: durations.len(),
+        median_ns: durations[durations.len() / 2].as_nanos() as u64,
+        min_ns: durations[0].as_nanos() as u64,
+    }
+}
+
+/// Compares the timing with the same phase of the same input in the baseline, if any. It is a
+/// regression when the median is slower by more than `threshold` percents.
+pub fn compare(
+    timing: &PhaseTiming,
+    baseline: &[PhaseTiming],
+    threshold: f64,
+) -> Option<Comparison> {
+    let baseline = baseline.iter().find(|baseline| {
+        baseline.day == timing.day
+            && baseline.phase == timing.phase
+            && baseline.input_hash == timing.input_hash
+    })?;
+
+    let change = (timing.median_ns as f64 - baseline.median_ns as f64)
+        / baseline.median_ns.max(1) as f64
+        * 100.0;
+    let slowdown = timing.median_ns.saturating_sub(baseline.median_ns);
+
+    Some(Comparison {
+        baseline_ns: baseline.median_ns,
+        change,
+        regression: change > threshold && slowdown > NOISE_FLOOR.as_nanos() as u64,
+    })
+}
+
+/// Benchmarks the requested days, prints their timings along with the changes from the baseline,
+/// and saves them as JSON if requested. Fails when a phase regressed.
+pub fn bench(options: &BenchOptions) -> bool {
+    let baseline = match &options.baseline {
+        Some(path) => {
+            let baseline = fs::read_to_string(path)
+                .map_err(|error| error.to_string())
+                .and_then(|content| {
+                    serde_json::from_str::<BenchReport>(&content).map_err(|error| error.to_string())
+                });
+            match baseline {
+                Ok(baseline) => baseline.timings,
+                Err(error) => {
+                    eprintln!("{}: {}", path.display(), error);
+                    return false;
+                }
+            }
+        }
+        None => Vec::new(),
+    };
+
+    let mut success = true;
+    let mut timings = Vec::new();
+    for input in read_inputs(&options.input, &options.days) {
+        let mut input = match input {
+            Some(input) => input,
+            None => {
+                success = false;
+                continue;
+            }
+        };
+        let content = match input.read_to_string() {
+            Ok(content) => content,
+            Err(error) => {
+                input.report_error(&error);
+                success = false;
+                continue;
+            }
+        };
+
+        match measure(
+            input.day,
+            solver(input.day).unwrap(),
+            &content,
+            options.samples,
+        ) {
+            Ok(day_timings) => timings.extend(day_timings),
+            Err(error) => {
+                input.report_error(&*error);
+                success = false;
+            }
+        }
+    }
+
+    let mut rows = Vec::new();
+    let mut regressions = Vec::new();
+    for timing in &timings {
+        let comparison = compare(timing, &baseline, options.threshold);
+        let (baseline, change) = match &comparison {
+            Some(comparison) => (
+                format_duration(Duration::from_nanos(comparison.baseline_ns)),
+                format!("{:+.1}%", comparison.change),
+            ),
+            None => (String::new(), String::new()),
+        };
+        if comparison.is_some_and(|comparison| comparison.regression) {
+            regressions.push(format!(
+                "day {} {}: {} -> {} ({})",
+                timing.day,
+                timing.phase,
+                baseline,
+                format_duration(Duration::from_nanos(timing.median_ns)),
+                change
+            ));
+        }
+
+        rows.push(vec![
+            timing.day.to_string(),
+            timing.phase.to_string(),
+            timing.samples.to_string(),
+            format_duration(Duration::from_nanos(timing.median_ns)),
+            format_duration(Duration::from_nanos(timing.min_ns)),
+            baseline,
+            change,
+        ]);
+    }
+    print!(
+        "{}",
+        render_rows(
+            &HEADERS,
+            &[Right, Left, Right, Right, Right, Right, Right],
+            &rows
+        )
+    );
+
+    if let Some(path) = &options.json {
+        let json = serde_json::to_string_pretty(&BenchReport { timings }).unwrap();
+        if let Err(error) = fs::write(path, json + "\n") {
+            eprintln!("{}: {}", path.display(), error);
+            success = false;
+        }
+    }
+
+    if options.baseline.is_some() {
+        for regression in &regressions {
+            println!("{}", regression);
+        }
+        println!(
+            "{} regressions above {}%",
+            regressions.len(),
+            options.threshold
+        );
+    }
+
+    success && regressions.is_empty()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::bench::{compare, measure, timing, BenchReport, Comparison, Phase, PhaseTiming};
+    use crate::days::solver;
+    use std::time::Duration;
+
+    fn part_1_timing(median_ns: u64) -> PhaseTiming {
+        PhaseTiming {
+            day: 1,
+            phase: Phase::Part1,
+            input_hash: "00000000000000ff".to_string(),
+            samples: 10,
+            median_ns,
+            min_ns: median_ns / 2,
+        }
+    }
+
+    #[test]
+    fn median_and_min_of_samples() {
+        let durations = [30, 10, 50, 20, 40].map(Duration::from_micros).to_vec();
+
+        assert_eq!(
+            timing(1, Phase::Parse, "ff", durations),
+            PhaseTiming {
+                day: 1,
+                phase: Phase::Parse,
+                input_hash: "ff".to_string(),
+                samples: 5,
+                median_ns: 30_000,
+                min_ns: 10_000,
+            }
+        );
+    }
+
+    #[test]
+    fn measure_every_phase() {
+        let timings = measure(6, solver(6).unwrap(), "3,4,3,1,2\n", 3).unwrap();
+
+        assert_eq!(
+            timings
+                .iter()
+                .map(|timing| timing.phase)
+                .collect::<Vec<_>>(),
+            vec![Phase::Parse, Phase::Part1, Phase::Part2]
+        );
+        assert!(timings.iter().all(|timing| timing.samples == 3));
+    }
+
+    #[test]
+    fn compare_with_baseline() {
+        let baseline = vec![part_1_timing(1_000_000)];
+
+        assert_eq!(
+            compare(&part_1_timing(1_200_000), &baseline, 10.0),
+            Some(Comparison {
+                baseline_ns: 1_000_000,
+                change: 20.0,
+                regression: true,
+            })
+        );
+        assert_eq!(
+            compare(&part_1_timing(1_050_000), &baseline, 10.0).map(|c| c.regression),
+            Some(false)
+        );
+        assert_eq!(
+            compare(&part_1_timing(500_000), &baseline, 10.0).map(|c| c.regression),
+            Some(false)
+        );
+
+        let mut other_input = part_1_timing(1_200_000);
+        other_input.input_hash = "0000000000000001".to_string();
+        assert_eq!(compare(&other_input, &baseline, 10.0), None);
+    }
+
+    #[test]
+    fn ignore_noise_on_fast_phases() {
+        let baseline = vec![part_1_timing(2_000)];
+
+        assert_eq!(
+            compare(&part_1_timing(4_000), &baseline, 10.0).map(|c| c.regression),
+            Some(false)
+        );
+    }
+
+    #[test]
+    fn json_round_trip() {
+        let report = BenchReport {
+            timings: vec![part_1_timing(1_000)],
+        };
+
+        let json = serde_json::to_string(&report).unwrap();
+        assert_eq!(
+            json,
+            r#"{"timings":[{"day":1,"phase":"part_1","input_hash":"00000000000000ff","samples":10,"median_ns":1000,"min_ns":500}]}"#
+        );
+        assert_eq!(serde_json::from_str::<BenchReport>(&json).unwrap(), report);
+    }
+}
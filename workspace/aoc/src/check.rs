@@ -0,0 +1,162 @@
+use crate::answers::{input_hash, parse_manifest, render_manifest, ExpectedAnswer};
+use crate::args::CheckOptions;
+use crate::days::solver;
+use crate::input::read_inputs;
+use crate::table::render_table;
+use aoc_common::Answer;
+use std::fs;
+use std::io::ErrorKind;
+
+/// Solves the requested days and compares their answers with the manifest, or records them in it
+/// when updating. Days whose input differs from the recorded one are skipped, as their answers
+/// are unknown.
+pub fn check(options: &CheckOptions) -> bool {
+    let manifest = match fs::read_to_string(&options.answers) {
+        Ok(content) => parse_manifest(&content),
+        Err(error) if error.kind() == ErrorKind::NotFound && options.update => Ok(Vec::new()),
+        Err(error) => Err(error.to_string()),
+    };
+    let mut expected = match manifest {
+        Ok(expected) => expected,
+        Err(error) => {
+            eprintln!("{}: {}", options.answers.display(), error);
+            return false;
+        }
+    };
+
+    let mut success = true;
+    let mut reports = Vec::new();
+    let mut checked = 0;
+    let mut mismatches = Vec::new();
+    for input in read_inputs(&options.input, &options.days) {
+        let mut input = match input {
+            Some(input) => input,
+            None => {
+                success = false;
+                continue;
+            }
+        };
+        let content = match input.read_to_string() {
+            Ok(content) => content,
+            Err(error) => {
+                input.report_error(&error);
+                success = false;
+                continue;
+            }
+        };
+        let day = input.day;
+
+        let hash = input_hash(&content);
+        let recorded = expected
+            .iter()
+            .filter(|answer| answer.day == day)
+            .cloned()
+            .collect::<Vec<_>>();
+        let parts = if options.update {
+            vec![1, 2]
+        } else if recorded.is_empty() {
+            eprintln!("day {}: no recorded answers, skipped", day);
+            continue;
+        } else if recorded.iter().any(|answer| answer.input_hash != hash) {
+            eprintln!(
+                "day {}: {}: not the input of the recorded answers, skipped",
+                day, input.name
+            );
+            continue;
+        } else {
+            recorded.iter().map(|answer| answer.part).collect()
+        };
+
+        let report = match solver(day).unwrap()(&mut content.as_bytes(), &parts) {
+            Ok(report) => report,
+            Err(error) => {
+                input.report_error(&*error);
+                success = false;
+                continue;
+            }
+        };
+
+        if options.update {
+            expected.retain(|answer| answer.day != day);
+            expected.extend(
+                report
+                    .parts
+                    .iter()
+                    .filter(|part| part.answer != Answer::Empty)
+                    .map(|part| ExpectedAnswer {
+                        day,
+                        part: part.part,
+                        input_hash: hash,
+                        answer: part.answer.to_string(),
+                    }),
+            );
+        } else {
+            for (part, expected) in report.parts.iter().zip(&recorded) {
+                checked += 1;
+                let answer = part.answer.to_string();
+                if answer != expected.answer {
+                    mismatches.push(format!(
+                        "day {} part {}: expected {:?}, got {:?}",
+                        day, part.part, expected.answer, answer
+                    ));
+                }
+            }
+        }
+        reports.push(report);
+    }
+
+    print!("{}", render_table(&reports));
+    if options.update {
+        if let Err(error) = fs::write(&options.answers, render_manifest(&expected)) {
+            eprintln!("{}: {}", options.answers.display(), error);
+            return false;
+        }
+        println!(
+            "Recorded the answers of {} days in {}",
+            reports.len(),
+            options.answers.display()
+        );
+    } else {
+        for mismatch in &mismatches {
+            println!("{}", mismatch);
+        }
+        println!(
+            "{} answers checked, {} mismatches",
+            checked,
+            mismatches.len()
+        );
+    }
+
+    success && mismatches.is_empty()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::args::CheckOptions;
+    use crate::check::check;
+    use crate::days::DAYS;
+    use std::path::Path;
+
+    fn check_days(days: Vec<u8>) -> bool {
+        let workspace = Path::new(env!("CARGO_MANIFEST_DIR")).parent().unwrap();
+
+        check(&CheckOptions {
+            days,
+            input: workspace.to_path_buf(),
+            answers: workspace.join("answers.txt"),
+            update: false,
+        })
+    }
+
+    #[test]
+    fn checked_in_answers_of_fast_days() {
+        assert!(check_days((1..=14).chain([16, 17, 24]).collect()));
+    }
+
+    /// Some days take minutes even in release mode: `cargo test --release -p aoc -- --ignored`
+    #[test]
+    #[ignore]
+    fn checked_in_answers() {
+        assert!(check_days(DAYS.collect()));
+    }
+}
@@ -0,0 +1,108 @@
+use aoc_common::{generate, parse_input, solve, DayReport, ParsedInput};
+use std::error::Error;
+use std::io::BufRead;
+
+/// Days of the calendar that have a solution
+pub const DAYS: std::ops::RangeInclusive<u8> = 1..=25;
+
+/// Type erased entry point of a day: parses the input and solves the requested parts
+pub type Solver = fn(&mut dyn BufRead, &[u8]) -> Result<DayReport, Box<dyn Error>>;
+
+pub fn solver(day: u8) -> Option<Solver> {
+    match day {
+        1 => Some(solve::<day_1::Day1>),
+        2 => Some(solve::<day_2::Day2>),
+        3 => Some(solve::<day_3::Day3>),
+        4 => Some(solve::<day_4::Day4>),
+        5 => Some(solve::<day_5::Day5>),
+        6 => Some(solve::<day_6::Day6>),
+        7 => Some(solve::<day_7::Day7>),
+        8 => Some(solve::<day_8::Day8>),
+        9 => Some(solve::<day_9::Day9>),
+        10 => Some(solve::<day_10::Day10>),
+        11 => Some(solve::<day_11::Day11>),
+        12 => Some(solve::<day_12::Day12>),
+        13 => Some(solve::<day_13::Day13>),
+        14 => Some(solve::<day_14::Day14>),
+        15 => Some(solve::<day_15::Day15>),
+        16 => Some(solve::<day_16::Day16>),
+        17 => Some(solve::<day_17::Day17>),
+        18 => Some(solve::<day_18::Day18>),
+        19 => Some(solve::<day_19::Day19>),
+        20 => Some(solve::<day_20::Day20>),
+        21 => Some(solve::<day_21::Day21>),
+        22 => Some(solve::<day_22::Day22>),
+        23 => Some(solve::<day_23::Day23>),
+        24 => Some(solve::<day_24::Day24>),
+        25 => Some(solve::<day_25::Day25>),
+        _ => None,
+    }
+}
+
+/// Type erased parser of a day, whose parts can then be solved concurrently
+pub type Parser = fn(&mut dyn BufRead) -> Result<ParsedInput, Box<dyn Error>>;
+
+pub fn parser(day: u8) -> Option<Parser> {
+    match day {
+        1 => Some(parse_input::<day_1::Day1>),
+        2 => Some(parse_input::<day_2::Day2>),
+        3 => Some(parse_input::<day_3::Day3>),
+        4 => Some(parse_input::<day_4::Day4>),
+        5 => Some(parse_input::<day_5::Day5>),
+        6 => Some(parse_input::<day_6::Day6>),
+        7 => Some(parse_input::<day_7::Day7>),
+        8 => Some(parse_input::<day_8::Day8>),
+        9 => Some(parse_input::<day_9::Day9>),
+        10 => Some(parse_input::<day_10::Day10>),
+        11 => Some(parse_input::<day_11::Day11>),
+        12 => Some(parse_input::<day_12::Day12>),
+        13 => Some(parse_input::<day_13::Day13>),
+        14 => Some(parse_input::<day_14::Day14>),
+        15 => Some(parse_input::<day_15::Day15>),
+        16 => Some(parse_input::<day_16::Day16>),
+        17 => Some(parse_input::<day_17::Day17>),
+        18 => Some(parse_input::<day_18::Day18>),
+        19 => Some(parse_input::<day_19::Day19>),
+        20 => Some(parse_input::<day_20::Day20>),
+        21 => Some(parse_input::<day_21::Day21>),
+        22 => Some(parse_input::<day_22::Day22>),
+        23 => Some(parse_input::<day_23::Day23>),
+        24 => Some(parse_input::<day_24::Day24>),
+        25 => Some(parse_input::<day_25::Day25>),
+        _ => None,
+    }
+}
+
+/// Type erased input generator of a day: generates an input of the given size from the seed
+pub type InputGenerator = fn(Option<usize>, u64) -> String;
+
+pub fn generator(day: u8) -> Option<InputGenerator> {
+    match day {
+        1 => Some(generate::<day_1::Day1>),
+        2 => Some(generate::<day_2::Day2>),
+        3 => Some(generate::<day_3::Day3>),
+        4 => Some(generate::<day_4::Day4>),
+        5 => Some(generate::<day_5::Day5>),
+        6 => Some(generate::<day_6::Day6>),
+        7 => Some(generate::<day_7::Day7>),
+        8 => Some(generate::<day_8::Day8>),
+        9 => Some(generate::<day_9::Day9>),
+        10 => Some(generate::<day_10::Day10>),
+        11 => Some(generate::<day_11::Day11>),
+        12 => Some(generate::<day_12::Day12>),
+        13 => Some(generate::<day_13::Day13>),
+        14 => Some(generate::<day_14::Day14>),
+        15 => Some(generate::<day_15::Day15>),
+        16 => Some(generate::<day_16::Day16>),
+        17 => Some(generate::<day_17::Day17>),
+        18 => Some(generate::<day_18::Day18>),
+        19 => Some(generate::<day_19::Day19>),
+        20 => Some(generate::<day_20::Day20>),
+        21 => Some(generate::<day_21::Day21>),
+        22 => Some(generate::<day_22::Day22>),
+        23 => Some(generate::<day_23::Day23>),
+        24 => Some(generate::<day_24::Day24>),
+        25 => Some(generate::<day_25::Day25>),
+        _ => None,
+    }
+}
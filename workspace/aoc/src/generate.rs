@@ -0,0 +1,62 @@
+use crate::args::GenerateOptions;
+use crate::days::generator;
+use std::fs;
+
+/// Generates a random input of the requested day, and prints it or writes it to the output file
+pub fn generate(options: &GenerateOptions) -> bool {
+    let content = generator(options.day).unwrap()(options.size, options.seed);
+
+    match &options.output {
+        Some(path) => match fs::write(path, content) {
+            Ok(()) => true,
+            Err(error) => {
+                eprintln!("{}: {}", path.display(), error);
+                false
+            }
+        },
+        None => {
+            print!("{}", content);
+            true
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::days::{generator, solver, DAYS};
+
+    /// Small enough for every day to be parsed quickly in debug mode
+    const SIZE: Option<usize> = Some(8);
+
+    #[test]
+    fn same_seed_same_input() {
+        for day in DAYS {
+            let generate = generator(day).unwrap();
+
+            assert_eq!(generate(SIZE, 3), generate(SIZE, 3), "day {}", day);
+        }
+    }
+
+    #[test]
+    fn other_seed_other_input() {
+        for day in DAYS {
+            let generate = generator(day).unwrap();
+
+            assert!(
+                (1..10).any(|seed| generate(SIZE, seed) != generate(SIZE, 0)),
+                "day {}",
+                day
+            );
+        }
+    }
+
+    #[test]
+    fn parse_generated_inputs() {
+        for day in DAYS {
+            let content = generator(day).unwrap()(SIZE, 1);
+
+            let report = solver(day).unwrap()(&mut content.as_bytes(), &[]);
+            assert!(report.is_ok(), "day {}: {}", day, report.err().unwrap());
+        }
+    }
+}
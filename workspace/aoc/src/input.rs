@@ -0,0 +1,124 @@
+use std::error::Error;
+use std::fs::File;
+use std::io;
+use std::io::{stdin, BufRead, BufReader, Read};
+use std::path::{Path, PathBuf};
+
+/// Path of the input standing for the standard input
+pub const STDIN: &str = "-";
+
+/// Input stream of a day, either its input file or the standard input
+pub struct DayInput {
+    pub day: u8,
+    /// Name of the input in error messages
+    pub name: String,
+    /// Sendable, so that the input can be parsed on any thread of the pool
+    pub reader: Box<dyn BufRead + Send>,
+}
+
+impl DayInput {
+    /// Reads the whole input, for the commands that need it at once
+    pub fn read_to_string(&mut self) -> io::Result<String> {
+        let mut content = String::new();
+        self.reader.read_to_string(&mut content)?;
+        Ok(content)
+    }
+
+    /// Reports an error about this input on stderr
+    pub fn report_error(&self, error: &dyn Error) {
+        eprintln!("day {}: {}: {}", self.day, self.name, error);
+    }
+}
+
+/// Opens the input of each day, or reads the standard input if the input is `-`. Errors are
+/// reported on stderr and yielded as `None`, except missing inputs which are only skipped when
+/// several days are requested.
+pub fn read_inputs<'a>(
+    input: &'a Path,
+    days: &'a [u8],
+) -> impl Iterator<Item = Option<DayInput>> + 'a {
+    days.iter().filter_map(move |&day| {
+        if input == Path::new(STDIN) {
+            return Some(Some(DayInput {
+                day,
+                name: "stdin".to_string(),
+                reader: Box::new(BufReader::new(stdin())),
+            }));
+        }
+
+        let path = match resolve_input(input, day) {
+            Some(path) => path,
+            None if days.len() > 1 => {
+                eprintln!("day {}: no input found, skipped", day);
+                return None;
+            }
+            None => {
+                eprintln!("day {}: no input found in {}", day, input.display());
+                return Some(None);
+            }
+        };
+
+        match File::open(&path) {
+            Ok(file) => Some(Some(DayInput {
+                day,
+                name: path.display().to_string(),
+                reader: Box::new(BufReader::new(file)),
+            })),
+            Err(error) => {
+                eprintln!("day {}: {}: {}", day, path.display(), error);
+                Some(None)
+            }
+        }
+    })
+}
+
+/// Finds the input file of a day: `path` itself if it is a file, otherwise the first existing file
+/// following the naming conventions inside the `path` directory
+pub fn resolve_input(path: &Path, day: u8) -> Option<PathBuf> {
+    if path.is_file() {
+        return Some(path.to_path_buf());
+    }
+
+    [
+        path.join(format!("day-{}.txt", day)),
+        path.join(format!("day-{}", day))
+            .join("dataset")
+            .join("data.txt"),
+    ]
+    .into_iter()
+    .find(|candidate| candidate.is_file())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::input::resolve_input;
+    use std::fs;
+
+    #[test]
+    fn resolve_naming_conventions() {
+        let directory = std::env::temp_dir().join(format!("aoc-inputs-{}", std::process::id()));
+        fs::create_dir_all(directory.join("day-2").join("dataset")).unwrap();
+        fs::write(directory.join("day-1.txt"), "1").unwrap();
+        fs::write(
+            directory.join("day-2").join("dataset").join("data.txt"),
+            "2",
+        )
+        .unwrap();
+
+        assert_eq!(
+            resolve_input(&directory, 1),
+            Some(directory.join("day-1.txt"))
+        );
+        assert_eq!(
+            resolve_input(&directory, 2),
+            Some(directory.join("day-2").join("dataset").join("data.txt"))
+        );
+        assert_eq!(resolve_input(&directory, 3), None);
+        assert_eq!(
+            resolve_input(&directory.join("day-1.txt"), 3),
+            Some(directory.join("day-1.txt"))
+        );
+
+        fs::remove_dir_all(directory).unwrap();
+    }
+}
@@ -0,0 +1,84 @@
+use crate::args::{parse_args, Command, RunOptions, USAGE};
+use crate::bench::bench;
+use crate::check::check;
+use crate::generate::generate;
+use crate::input::read_inputs;
+use crate::pool::solve_in_pool;
+use crate::table::render_table;
+use aoc_common::{render_json, OutputFormat};
+use std::env;
+use std::process::exit;
+
+mod answers;
+mod args;
+mod bench;
+mod check;
+mod days;
+mod generate;
+mod input;
+mod pool;
+mod table;
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+
+    let command = match parse_args(&args) {
+        Ok(command) => command,
+        Err(error) => {
+            eprintln!("{}\n\n{}", error, USAGE);
+            exit(1);
+        }
+    };
+
+    let success = match command {
+        Command::Run(options) => run(&options),
+        Command::Check(options) => check(&options),
+        Command::Bench(options) => bench(&options),
+        Command::Generate(options) => generate(&options),
+    };
+    if !success {
+        exit(1);
+    }
+}
+
+/// Solves the requested days on the thread pool and prints their answers in the requested format.
+/// Days without an input file are skipped when running the whole calendar.
+fn run(options: &RunOptions) -> bool {
+    if let Some(threads) = options.threads {
+        rayon::ThreadPoolBuilder::new()
+            .num_threads(threads)
+            .build_global()
+            .unwrap();
+    }
+
+    let mut success = true;
+    let mut inputs = Vec::new();
+    for input in read_inputs(&options.input, &options.days) {
+        match input {
+            Some(input) => inputs.push(input),
+            None => success = false,
+        }
+    }
+
+    let mut reports = Vec::new();
+    for report in solve_in_pool(inputs, &options.parts, options.timeout) {
+        match report {
+            Ok(report) => reports.push(report),
+            Err(error) => {
+                eprintln!("{}", error);
+                success = false;
+            }
+        }
+    }
+
+    match options.format {
+        OutputFormat::Text => print!("{}", render_table(&reports)),
+        OutputFormat::Json => {
+            for report in &reports {
+                println!("{}", render_json(report));
+            }
+        }
+    }
+
+    success
+}
@@ -0,0 +1,286 @@
+use crate::days::parser;
+use crate::input::DayInput;
+use aoc_common::{DayReport, PartReport};
+use std::collections::BTreeMap;
+use std::error::Error;
+use std::panic::{catch_unwind, AssertUnwindSafe};
+use std::sync::mpsc::{channel, RecvTimeoutError, Sender};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+/// Progress of a day, sent by its tasks as they run on the pool
+enum Event {
+    Started(u8),
+    Parsed(u8, Duration),
+    Solved(u8, PartReport),
+    Failed(u8, String),
+}
+
+/// What is known of a day while its tasks run
+struct DayProgress {
+    name: String,
+    started: Option<Instant>,
+    parse_duration: Duration,
+    parts: Vec<PartReport>,
+    /// The parsing and each part, until they are done or the day failed
+    remaining_tasks: usize,
+    error: Option<String>,
+}
+
+impl DayProgress {
+    fn is_done(&self) -> bool {
+        self.remaining_tasks == 0
+    }
+}
+
+/// Solves the requested parts of every day on the rayon pool: each day is parsed by a task, which
+/// then spawns a task per part, so that all the parts of the calendar are balanced across the
+/// threads by work stealing. A day that takes longer than the timeout from the start of its
+/// parsing is abandoned, its tasks keep running in the background until the process exits.
+///
+/// Returns the report of each day in the order of the days, or the message of its failure.
+pub fn solve_in_pool(
+    inputs: Vec<DayInput>,
+    parts: &[u8],
+    timeout: Option<Duration>,
+) -> Vec<Result<DayReport, String>> {
+    let (sender, events) = channel();
+    let mut progress = BTreeMap::new();
+    for input in inputs {
+        progress.insert(
+            input.day,
+            DayProgress {
+                name: input.name.clone(),
+                started: None,
+                parse_duration: Duration::ZERO,
+                parts: Vec::new(),
+                remaining_tasks: 1 + parts.len(),
+                error: None,
+            },
+        );
+        spawn_day(input, parts.to_vec(), sender.clone());
+    }
+    drop(sender);
+
+    while progress.values().any(|day| !day.is_done()) {
+        let deadline = timeout.and_then(|timeout| {
+            progress
+                .values()
+                .filter(|day| !day.is_done())
+                .filter_map(|day| day.started)
+                .min()
+                .map(|started| started + timeout)
+        });
+        let event = match deadline {
+            Some(deadline) => {
+                events.recv_timeout(deadline.saturating_duration_since(Instant::now()))
+            }
+            None => events.recv().map_err(|_| RecvTimeoutError::Disconnected),
+        };
+
+        match event {
+            Ok(event) => record(&mut progress, event),
+            Err(RecvTimeoutError::Timeout) => {
+                let timeout = timeout.unwrap();
+                for day in progress.values_mut().filter(|day| !day.is_done()) {
+                    if day
+                        .started
+                        .is_some_and(|started| started.elapsed() >= timeout)
+                    {
+                        day.error = Some(format!("timed out after {}s", timeout.as_secs_f64()));
+                        day.remaining_tasks = 0;
+                    }
+                }
+            }
+            // Every task holds a sender until it is done
+            Err(RecvTimeoutError::Disconnected) => unreachable!(),
+        }
+    }
+
+    progress
+        .into_iter()
+        .map(|(day, mut progress)| match progress.error {
+            Some(error) => Err(format!("day {}: {}: {}", day, progress.name, error)),
+            None => {
+                progress.parts.sort_by_key(|part| part.part);
+                Ok(DayReport {
+                    day,
+                    parse_duration: progress.parse_duration,
+                    parts: progress.parts,
+                })
+            }
+        })
+        .collect()
+}
+
+fn record(progress: &mut BTreeMap<u8, DayProgress>, event: Event) {
+    let day = match &event {
+        Event::Started(day)
+        | Event::Parsed(day, _)
+        | Event::Solved(day, _)
+        | Event::Failed(day, _) => progress.get_mut(day).unwrap(),
+    };
+    // Late events of a day that already failed or timed out
+    if day.is_done() {
+        return;
+    }
+
+    match event {
+        Event::Started(_) => day.started = Some(Instant::now()),
+        Event::Parsed(_, duration) => {
+            day.parse_duration = duration;
+            day.remaining_tasks -= 1;
+        }
+        Event::Solved(_, part) => {
+            day.parts.push(part);
+            day.remaining_tasks -= 1;
+        }
+        Event::Failed(_, error) => {
+            day.error = Some(error);
+            day.remaining_tasks = 0;
+        }
+    }
+}
+
+/// Spawns the task parsing the input of the day, which then spawns a task per part
+fn spawn_day(mut input: DayInput, parts: Vec<u8>, events: Sender<Event>) {
+    rayon::spawn(move || {
+        let day = input.day;
+        let _ = events.send(Event::Started(day));
+        let parsed = match catch(|| parser(day).unwrap()(&mut input.reader)) {
+            Ok(parsed) => Arc::new(parsed),
+            Err(error) => {
+                let _ = events.send(Event::Failed(day, error));
+                return;
+            }
+        };
+        let _ = events.send(Event::Parsed(day, parsed.parse_duration));
+
+        for part in parts {
+            let parsed = parsed.clone();
+            let events = events.clone();
+            rayon::spawn(move || {
+                let event = match catch(|| parsed.solve(part)) {
+                    Ok(report) => Event::Solved(day, report),
+                    Err(error) => Event::Failed(day, error),
+                };
+                let _ = events.send(event);
+            });
+        }
+    });
+}
+
+/// Runs the task, turning its error or panic into a message. The panic itself is still printed by
+/// the panic hook.
+fn catch<T>(task: impl FnOnce() -> Result<T, Box<dyn Error>>) -> Result<T, String> {
+    match catch_unwind(AssertUnwindSafe(task)) {
+        Ok(result) => result.map_err(|error| error.to_string()),
+        Err(_) => Err("panicked".to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::days::solver;
+    use crate::input::DayInput;
+    use crate::pool::solve_in_pool;
+    use std::fs;
+    use std::io::{self, BufReader, Cursor, Read};
+    use std::path::Path;
+    use std::sync::mpsc::{channel, Receiver};
+    use std::time::Duration;
+
+    fn example(day: u8) -> Vec<u8> {
+        let workspace = Path::new(env!("CARGO_MANIFEST_DIR")).parent().unwrap();
+
+        fs::read(
+            workspace
+                .join(format!("day-{}", day))
+                .join("dataset")
+                .join("example.txt"),
+        )
+        .unwrap()
+    }
+
+    /// Reader blocking until its sender is dropped, then empty
+    struct Blocked(Receiver<()>);
+
+    impl Read for Blocked {
+        fn read(&mut self, _buffer: &mut [u8]) -> io::Result<usize> {
+            let _ = self.0.recv();
+            Ok(0)
+        }
+    }
+
+    fn input(day: u8, content: Vec<u8>) -> DayInput {
+        DayInput {
+            day,
+            name: "example".to_string(),
+            reader: Box::new(Cursor::new(content)),
+        }
+    }
+
+    #[test]
+    fn same_answers_as_sequential_solving() {
+        let days = (1..=14).collect::<Vec<_>>();
+        let inputs = days.iter().map(|&day| input(day, example(day))).collect();
+
+        let reports = solve_in_pool(inputs, &[1, 2], None);
+
+        assert_eq!(reports.len(), days.len());
+        for (report, day) in reports.into_iter().zip(days) {
+            let report = report.unwrap();
+            let expected = solver(day).unwrap()(&mut example(day).as_slice(), &[1, 2]).unwrap();
+            assert_eq!(report.day, day);
+            assert_eq!(
+                report
+                    .parts
+                    .iter()
+                    .map(|part| (part.part, &part.answer))
+                    .collect::<Vec<_>>(),
+                expected
+                    .parts
+                    .iter()
+                    .map(|part| (part.part, &part.answer))
+                    .collect::<Vec<_>>()
+            );
+        }
+    }
+
+    #[test]
+    fn report_failed_days() {
+        let inputs = vec![input(5, b"0,9 -> 5\n".to_vec()), input(6, example(6))];
+
+        let reports = solve_in_pool(inputs, &[2], None);
+
+        assert_eq!(
+            reports[0].as_ref().err().unwrap(),
+            "day 5: example: day 5, line 1, column 8: expected `x,y` coordinates"
+        );
+        assert_eq!(reports[1].as_ref().unwrap().parts[0].part, 2);
+    }
+
+    #[test]
+    fn abandon_days_after_timeout() {
+        // The input of day 22 is only readable once the pool gave up on it
+        let (release, blocked) = channel::<()>();
+        let reader = BufReader::new(Blocked(blocked));
+        let inputs = vec![
+            input(6, example(6)),
+            DayInput {
+                day: 22,
+                name: "example".to_string(),
+                reader: Box::new(reader),
+            },
+        ];
+
+        let reports = solve_in_pool(inputs, &[1], Some(Duration::from_millis(200)));
+        drop(release);
+
+        assert!(reports[0].is_ok());
+        assert_eq!(
+            reports[1].as_ref().err().unwrap(),
+            "day 22: example: timed out after 0.2s"
+        );
+    }
+}
@@ -0,0 +1,146 @@
+use crate::table::Alignment::{Left, Right};
+use aoc_common::DayReport;
+use std::time::Duration;
+
+const HEADERS: [&str; 4] = ["Day", "Part", "Answer", "Time"];
+
+/// Renders the answers of all the reports as a table, one row per part. Multi-line answers span
+/// several rows.
+pub fn render_table(reports: &[DayReport]) -> String {
+    let mut rows = Vec::new();
+    let mut total = Duration::ZERO;
+    for report in reports {
+        total += report.parse_duration;
+        for part in &report.parts {
+            total += part.duration;
+            let answer = part.answer.to_string();
+            let mut lines = answer.lines();
+            rows.push(vec![
+                report.day.to_string(),
+                part.part.to_string(),
+                lines.next().unwrap_or_default().to_string(),
+                format_duration(part.duration),
+            ]);
+            for line in lines {
+                rows.push(vec![
+                    String::new(),
+                    String::new(),
+                    line.to_string(),
+                    String::new(),
+                ]);
+            }
+        }
+    }
+
+    let mut table = render_rows(&HEADERS, &[Right, Right, Left, Right], &rows);
+    table.push_str(&format!("Total time: {}\n", format_duration(total)));
+
+    table
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum Alignment {
+    Left,
+    Right,
+}
+
+/// Renders the header, its underline and the rows, each column being as wide as its widest cell
+pub fn render_rows(headers: &[&str], alignments: &[Alignment], rows: &[Vec<String>]) -> String {
+    let mut widths = headers
+        .iter()
+        .map(|header| header.len())
+        .collect::<Vec<_>>();
+    for row in rows {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+
+    let headers = headers
+        .iter()
+        .map(|header| header.to_string())
+        .collect::<Vec<_>>();
+    let mut table = format_row(&headers, alignments, &widths);
+    table.push_str(
+        &widths
+            .iter()
+            .map(|width| "-".repeat(width + 2))
+            .collect::<Vec<_>>()
+            .join("+"),
+    );
+    table.push('\n');
+    for row in rows {
+        table.push_str(&format_row(row, alignments, &widths));
+    }
+
+    table
+}
+
+fn format_row(row: &[String], alignments: &[Alignment], widths: &[usize]) -> String {
+    let line = row
+        .iter()
+        .zip(alignments)
+        .zip(widths)
+        .map(|((cell, alignment), &width)| match alignment {
+            Left => format!(" {:<width$} ", cell, width = width),
+            Right => format!(" {:>width$} ", cell, width = width),
+        })
+        .collect::<Vec<_>>()
+        .join("|");
+    format!("{}\n", line.trim_end())
+}
+
+pub fn format_duration(duration: Duration) -> String {
+    if duration < Duration::from_millis(1) {
+        format!("{} µs", duration.as_micros())
+    } else if duration < Duration::from_secs(1) {
+        format!("{:.3} ms", duration.as_secs_f64() * 1_000.0)
+    } else {
+        format!("{:.3} s", duration.as_secs_f64())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::table::{format_duration, render_table};
+    use aoc_common::{Answer, DayReport, PartReport};
+    use std::time::Duration;
+
+    #[test]
+    fn format_durations() {
+        assert_eq!(format_duration(Duration::from_micros(42)), "42 µs");
+        assert_eq!(format_duration(Duration::from_micros(1_500)), "1.500 ms");
+        assert_eq!(format_duration(Duration::from_millis(2_250)), "2.250 s");
+    }
+
+    #[test]
+    fn render_multi_line_answers() {
+        let reports = vec![DayReport {
+            day: 13,
+            parse_duration: Duration::from_micros(10),
+            parts: vec![
+                PartReport {
+                    part: 1,
+                    answer: Answer::Number(17),
+                    duration: Duration::from_micros(20),
+                },
+                PartReport {
+                    part: 2,
+                    answer: Answer::Text("###\n#.#".to_string()),
+                    duration: Duration::from_micros(30),
+                },
+            ],
+        }];
+
+        assert_eq!(
+            render_table(&reports),
+            " Day | Part | Answer |  Time
+-----+------+--------+-------
+  13 |    1 | 17     | 20 µs
+  13 |    2 | ###    | 30 µs
+     |      | #.#    |
+Total time: 60 µs
+"
+        );
+    }
+}
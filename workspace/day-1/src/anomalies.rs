@@ -0,0 +1,303 @@
+use std::collections::VecDeque;
+use std::fmt::{self, Display, Formatter};
+use std::str::FromStr;
+
+/// Thresholds of the anomaly detection
+#[derive(Debug, Clone, PartialEq)]
+pub struct AnomalySettings {
+    /// Number of previous readings a depth is compared with to detect jumps
+    pub window: usize,
+    /// Number of standard deviations away from the mean of the window beyond which a depth is a
+    /// jump
+    pub deviations: f64,
+    /// Number of identical readings in a row from which the sonar is considered stuck
+    pub stuck_repeats: usize,
+}
+
+impl Default for AnomalySettings {
+    fn default() -> Self {
+        AnomalySettings {
+            window: 10,
+            deviations: 3.0,
+            stuck_repeats: 3,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Reason {
+    /// The depth is that many standard deviations away from the mean of the previous readings,
+    /// infinitely many if they were all identical
+    Jump {
+        deviations: f64,
+    },
+    /// The same depth has been read that many times in a row
+    Stuck {
+        repeats: usize,
+    },
+    Negative,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Anomaly {
+    /// Index of the reading, blank lines excluded
+    pub index: usize,
+    pub depth: i32,
+    pub reason: Reason,
+}
+
+/// Anomalies of a sonar sweep, by increasing index. A reading may appear several times, once per
+/// reason.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct AnomalyReport {
+    pub anomalies: Vec<Anomaly>,
+}
+
+impl AnomalyReport {
+    /// Indices of the anomalous readings
+    pub fn indices(&self) -> Vec<usize> {
+        let mut indices = self
+            .anomalies
+            .iter()
+            .map(|anomaly| anomaly.index)
+            .collect::<Vec<_>>();
+        indices.dedup();
+        indices
+    }
+}
+
+/// Detects the anomalies of a stream of depths, only keeping the last `window` readings in memory
+pub fn detect_anomalies(
+    depths: impl IntoIterator<Item = i32>,
+    settings: &AnomalySettings,
+) -> AnomalyReport {
+    let mut report = AnomalyReport::default();
+    let mut window = VecDeque::with_capacity(settings.window + 1);
+    // Sums of the depths of the window and of their squares, exact to compute the variance
+    let (mut sum, mut square_sum) = (0i128, 0i128);
+    let mut repeats = 0;
+
+    for (index, depth) in depths.into_iter().enumerate() {
+        let mut anomaly = |reason| {
+            report.anomalies.push(Anomaly {
+                index,
+                depth,
+                reason,
+            })
+        };
+
+        if settings.window > 0 && window.len() == settings.window {
+            let count = window.len() as i128;
+            let variance = (count * square_sum - sum * sum) as f64 / (count * count) as f64;
+            if variance > 0.0 {
+                let mean = sum as f64 / count as f64;
+                let deviations = (depth as f64 - mean).abs() / variance.sqrt();
+                if deviations > settings.deviations {
+                    anomaly(Reason::Jump { deviations });
+                }
+            } else if depth as i128 * count != sum {
+                // A window of identical readings has no spread: any other depth is a jump
+                anomaly(Reason::Jump {
+                    deviations: f64::INFINITY,
+                });
+            }
+        }
+
+        repeats = if window.back() == Some(&depth) {
+            repeats + 1
+        } else {
+            1
+        };
+        if repeats >= settings.stuck_repeats.max(2) {
+            anomaly(Reason::Stuck { repeats });
+        }
+
+        if depth < 0 {
+            anomaly(Reason::Negative);
+        }
+
+        window.push_back(depth);
+        sum += depth as i128;
+        square_sum += depth as i128 * depth as i128;
+        if window.len() > settings.window.max(1) {
+            let oldest = window.pop_front().unwrap() as i128;
+            sum -= oldest;
+            square_sum -= oldest * oldest;
+        }
+    }
+
+    report
+}
+
+impl Display for Anomaly {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "reading {} ({}): ", self.index, self.depth)?;
+        match self.reason {
+            Reason::Jump { deviations } if deviations.is_infinite() => {
+                write!(f, "jump from constant readings")
+            }
+            Reason::Jump { deviations } => {
+                write!(f, "jump of {:.1} standard deviations", deviations)
+            }
+            Reason::Stuck { repeats } => write!(f, "stuck, read {} times in a row", repeats),
+            Reason::Negative => write!(f, "negative depth"),
+        }
+    }
+}
+
+impl Display for AnomalyReport {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        if self.anomalies.is_empty() {
+            return writeln!(f, "no anomalies");
+        }
+        for anomaly in &self.anomalies {
+            writeln!(f, "{}", anomaly)?;
+        }
+        Ok(())
+    }
+}
+
+/// Reads the optional input path and the thresholds of the `--anomalies` mode
+pub fn parse_anomaly_args(args: &[String]) -> Result<(Option<&str>, AnomalySettings), String> {
+    let mut path = None;
+    let mut settings = AnomalySettings::default();
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        let mut value = |flag: &str| {
+            args.next()
+                .ok_or_else(|| format!("Missing value for `{}`", flag))
+        };
+        match arg.as_str() {
+            "--anomalies" => {}
+            "--window" => settings.window = parse_value(value("--window")?, "--window")?,
+            "--deviations" => {
+                settings.deviations = parse_value(value("--deviations")?, "--deviations")?
+            }
+            "--stuck" => settings.stuck_repeats = parse_value(value("--stuck")?, "--stuck")?,
+            flag if flag.starts_with("--") => return Err(format!("Unknown flag `{}`", flag)),
+            extra if path.is_some() => return Err(format!("Unexpected argument `{}`", extra)),
+            input => path = Some(input),
+        }
+    }
+
+    Ok((path, settings))
+}
+
+fn parse_value<T: FromStr>(value: &str, flag: &str) -> Result<T, String> {
+    value
+        .parse()
+        .map_err(|_| format!("Invalid value `{}` for `{}`", value, flag))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::anomalies::{
+        detect_anomalies, parse_anomaly_args, Anomaly, AnomalySettings, Reason,
+    };
+
+    #[test]
+    fn example_case_has_no_anomalies() {
+        let depths = [199, 200, 208, 210, 200, 207, 240, 269, 260, 263];
+
+        let report = detect_anomalies(depths, &AnomalySettings::default());
+
+        assert!(report.anomalies.is_empty());
+        assert_eq!(report.to_string(), "no anomalies\n");
+    }
+
+    #[test]
+    fn detect_jumps_stuck_and_negative_depths() {
+        let depths = [
+            100, 101, 99, 100, 102, 101, 100, 99, 101, 100, 500, -3, 7, 7, 7, 7,
+        ];
+
+        let report = detect_anomalies(depths, &AnomalySettings::default());
+
+        assert_eq!(report.indices(), vec![10, 11, 14, 15]);
+        assert!(matches!(
+            report.anomalies[0],
+            Anomaly { index: 10, depth: 500, reason: Reason::Jump { deviations } } if deviations > 400.0
+        ));
+        assert_eq!(
+            report.anomalies[1..],
+            [
+                Anomaly {
+                    index: 11,
+                    depth: -3,
+                    reason: Reason::Negative
+                },
+                Anomaly {
+                    index: 14,
+                    depth: 7,
+                    reason: Reason::Stuck { repeats: 3 }
+                },
+                Anomaly {
+                    index: 15,
+                    depth: 7,
+                    reason: Reason::Stuck { repeats: 4 }
+                },
+            ]
+        );
+        assert_eq!(
+            report.anomalies[3].to_string(),
+            "reading 15 (7): stuck, read 4 times in a row"
+        );
+    }
+
+    #[test]
+    fn detect_jumps_after_constant_readings() {
+        let settings = AnomalySettings {
+            window: 3,
+            stuck_repeats: 10,
+            ..AnomalySettings::default()
+        };
+
+        let report = detect_anomalies([100, 100, 100, 5000, 100], &settings);
+
+        assert_eq!(
+            report.anomalies,
+            [Anomaly {
+                index: 3,
+                depth: 5000,
+                reason: Reason::Jump {
+                    deviations: f64::INFINITY
+                }
+            }]
+        );
+        assert_eq!(
+            report.anomalies[0].to_string(),
+            "reading 3 (5000): jump from constant readings"
+        );
+        assert!(detect_anomalies([100, 100, 100, 100], &settings)
+            .anomalies
+            .is_empty());
+    }
+
+    #[test]
+    fn parse_settings() {
+        let args = [
+            "data.txt",
+            "--anomalies",
+            "--deviations",
+            "2.5",
+            "--stuck",
+            "5",
+        ]
+        .map(String::from);
+
+        assert_eq!(
+            parse_anomaly_args(&args),
+            Ok((
+                Some("data.txt"),
+                AnomalySettings {
+                    window: 10,
+                    deviations: 2.5,
+                    stuck_repeats: 5,
+                }
+            ))
+        );
+        assert!(parse_anomaly_args(&["--window".to_string()]).is_err());
+        assert!(parse_anomaly_args(&["--window".to_string(), "x".to_string()]).is_err());
+    }
+}
@@ -0,0 +1,29 @@
+use aoc_common::Rng;
+use std::fmt::Write;
+
+/// Generates `size` depths of a seafloor getting deeper on average
+pub fn generate(size: usize, rng: &mut Rng) -> String {
+    let mut content = String::new();
+    let mut depth = rng.range(100..=200);
+    for _ in 0..size {
+        writeln!(content, "{}", depth).unwrap();
+        depth = (depth + rng.range(-10..=15)).max(0);
+    }
+
+    content
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::generator::generate;
+    use crate::Day1;
+    use aoc_common::{Rng, Solution};
+
+    #[test]
+    fn generate_valid_input() {
+        let input = Day1::parse(generate(100, &mut Rng::new(1))).unwrap();
+
+        assert_eq!(input.data.len(), 100);
+        assert!(input.data.iter().all(|&depth| depth >= 0));
+    }
+}
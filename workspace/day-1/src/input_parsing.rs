@@ -1,25 +1,47 @@
 use crate::models::Input;
-use std::fs;
+use crate::Day1;
+use aoc_common::{input_lines, InputError, InputLine, ParseError, Solution};
+use std::io::BufRead;
 
-pub fn parse_file(file_path: String) -> Input {
-    let file_content = fs::read_to_string(file_path).expect("Error while reading the data file");
+pub fn parse_string(content: String) -> Result<Input, ParseError> {
+    let data = input_lines(Day1::DAY, &content)
+        .filter(|line| !line.text.trim().is_empty())
+        .map(|line| parse_depth(&line))
+        .collect::<Result<_, _>>()?;
 
-    parse_string(file_content)
+    Ok(Input { data })
 }
 
-fn parse_string(content: String) -> Input {
-    let data = content
-        .split_ascii_whitespace()
-        .map(|line| line.parse().unwrap())
-        .collect();
+/// Parses the depths as they are read, without holding the whole input in memory
+pub fn parse_reader<R: BufRead>(reader: R) -> Result<Input, InputError> {
+    let data = read_depths(reader).collect::<Result<_, _>>()?;
 
-    Input { data }
+    Ok(Input { data })
+}
+
+/// Iterates over the depths of a stream, reading a line at a time and skipping the blank ones
+pub fn read_depths<R: BufRead>(reader: R) -> impl Iterator<Item = Result<i32, InputError>> {
+    reader
+        .lines()
+        .enumerate()
+        .filter_map(|(index, text)| match text {
+            Ok(text) if text.trim().is_empty() => None,
+            Ok(text) => Some(
+                parse_depth(&InputLine::new(Day1::DAY, index, &text)).map_err(InputError::from),
+            ),
+            Err(error) => Some(Err(error.into())),
+        })
+}
+
+fn parse_depth(line: &InputLine) -> Result<i32, ParseError> {
+    line.parse(line.text.trim(), "a depth")
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::input_parsing::parse_string;
+    use crate::input_parsing::{parse_reader, parse_string, read_depths};
     use crate::models::Input;
+    use aoc_common::{InputError, ParseError};
 
     #[test]
     fn parse_example_case() {
@@ -35,7 +57,7 @@ mod tests {
 263"
         .to_string();
 
-        let input = parse_string(content);
+        let input = parse_string(content).unwrap();
 
         assert_eq!(
             input,
@@ -44,4 +66,41 @@ mod tests {
             }
         );
     }
+
+    #[test]
+    fn parse_stream() {
+        let content = "199\n200\n\n208\r\n210\n";
+
+        assert_eq!(
+            parse_reader(content.as_bytes()).unwrap(),
+            parse_string(content.to_string()).unwrap()
+        );
+        assert!(matches!(
+            parse_reader("199\n2O8\n".as_bytes()),
+            Err(InputError::Parse(error)) if error == ParseError::new(1, 2, 1, "a depth")
+        ));
+    }
+
+    #[test]
+    fn read_depths_lazily() {
+        let mut depths = read_depths("199\n\n200\n2O8\n".as_bytes());
+
+        assert_eq!(depths.next().unwrap().unwrap(), 199);
+        assert_eq!(depths.next().unwrap().unwrap(), 200);
+        assert!(matches!(
+            depths.next(),
+            Some(Err(InputError::Parse(error))) if error == ParseError::new(1, 4, 1, "a depth")
+        ));
+        assert!(depths.next().is_none());
+    }
+
+    #[test]
+    fn parse_invalid_depth() {
+        let content = "199\n200\n2O8\n".to_string();
+
+        assert_eq!(
+            parse_string(content),
+            Err(ParseError::new(1, 3, 1, "a depth"))
+        );
+    }
 }
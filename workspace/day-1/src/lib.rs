@@ -0,0 +1,45 @@
+use crate::input_parsing::{parse_reader, parse_string};
+use crate::models::Input;
+use crate::part_1::depth_increase_count;
+use crate::part_2::sliding_window_depth_increase_count;
+use aoc_common::{Answer, Generator, InputError, ParseError, Rng, Solution};
+use std::io::BufRead;
+
+pub mod anomalies;
+pub mod generator;
+pub mod input_parsing;
+pub mod models;
+pub mod part_1;
+pub mod part_2;
+pub mod windows;
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    const DAY: u8 = 1;
+    type Input = Input;
+
+    fn parse(content: String) -> Result<Input, ParseError> {
+        parse_string(content)
+    }
+
+    fn parse_reader<R: BufRead>(reader: R) -> Result<Input, InputError> {
+        parse_reader(reader)
+    }
+
+    fn part_1(input: &Input) -> Answer {
+        depth_increase_count(&input.data).into()
+    }
+
+    fn part_2(input: &Input) -> Answer {
+        sliding_window_depth_increase_count(input).into()
+    }
+}
+
+impl Generator for Day1 {
+    const DEFAULT_SIZE: usize = 2000;
+
+    fn generate(size: usize, rng: &mut Rng) -> String {
+        generator::generate(size, rng)
+    }
+}
@@ -1,23 +1,43 @@
-use crate::input_parsing::parse_file;
-use crate::part_1::depth_increase_count;
-use crate::part_2::sliding_window_depth_increase_count;
+use aoc_common::{input_reader, run};
+use day_1::anomalies::{detect_anomalies, parse_anomaly_args};
+use day_1::input_parsing::read_depths;
+use day_1::Day1;
 use std::env;
+use std::error::Error;
 use std::process::exit;
 
-mod input_parsing;
-mod models;
-mod part_1;
-mod part_2;
+fn main() -> Result<(), Box<dyn Error>> {
+    let args: Vec<String> = env::args().skip(1).collect();
+    if args.iter().any(|arg| arg == "--anomalies") {
+        return print_anomalies(&args);
+    }
 
-fn main() {
-    let args: Vec<String> = env::args().collect();
+    run::<Day1>()
+}
 
-    if args.len() != 2 {
-        eprintln!("Invalid arguments count");
+/// Lists the suspicious readings of the input instead of solving the puzzle
+fn print_anomalies(args: &[String]) -> Result<(), Box<dyn Error>> {
+    let usage = |error: &str| -> ! {
+        eprintln!(
+            "{}\n\nUsage: day-1 <input|-> --anomalies [--window <n>] [--deviations <k>] [--stuck <n>]",
+            error
+        );
         exit(1);
+    };
+    let (path, settings) = parse_anomaly_args(args).unwrap_or_else(|error| usage(&error));
+    let reader = match input_reader(path)? {
+        Some(reader) => reader,
+        None => usage("Missing input file"),
+    };
+
+    // Stream the depths into the detector, stopping at the first invalid line
+    let mut error = None;
+    let depths = read_depths(reader).map_while(|depth| depth.map_err(|e| error = Some(e)).ok());
+    let report = detect_anomalies(depths, &settings);
+    if let Some(error) = error {
+        return Err(error.into());
     }
-    let input = parse_file(args[1].clone());
+    print!("{}", report);
 
-    println!("part 1: {}", depth_increase_count(&input.data));
-    println!("part 1: {}", sliding_window_depth_increase_count(&input));
+    Ok(())
 }
@@ -1,4 +1,4 @@
-pub fn depth_increase_count(data: &Vec<i32>) -> u32 {
+pub fn depth_increase_count(data: &[i32]) -> u32 {
     let mut count = 0;
 
     let mut previous_deth = data[0];
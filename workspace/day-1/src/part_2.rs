@@ -1,15 +1,14 @@
-use crate::depth_increase_count;
 use crate::models::Input;
+use crate::windows::{Sonar, Trend};
 
 pub fn sliding_window_depth_increase_count(input: &Input) -> u32 {
-    // Input is not that long, just transform our raw data to an aggregated data with a petty ugly
-    // map
-    let aggregated_data = input.data[2..]
+    let trend = input
+        .data
         .iter()
-        .enumerate()
-        .map(|(i, &depth)| input.data[2 + i - 2] + input.data[2 + i - 1] + depth)
-        .collect();
+        .copied()
+        .sliding_windows(3)
+        .compared(1)
+        .collect::<Trend>();
 
-    // Reuse the depth increase count from part 1
-    depth_increase_count(&aggregated_data)
+    trend.increases as u32
 }
@@ -0,0 +1,280 @@
+use std::cmp::Ordering;
+use std::collections::VecDeque;
+
+/// Depths of a window of consecutive readings
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Window {
+    /// Index of the first reading of the window
+    pub start: usize,
+    pub size: usize,
+    pub sum: i64,
+    pub min: i32,
+    pub max: i32,
+}
+
+impl Window {
+    pub fn mean(&self) -> f64 {
+        self.sum as f64 / self.size as f64
+    }
+}
+
+/// Adaptor turning a stream of depths into the stream of its windows of a given size, see
+/// [`Sonar::sliding_windows`]. Each reading is handled in amortized constant time, and only the
+/// readings of the current window are kept in memory.
+pub struct SlidingWindows<I> {
+    depths: I,
+    size: usize,
+    /// Number of readings read so far
+    count: usize,
+    readings: VecDeque<i32>,
+    sum: i64,
+    /// Indices and depths of the readings which may still be the minimum of a window, increasing
+    minimums: VecDeque<(usize, i32)>,
+    /// Indices and depths of the readings which may still be the maximum of a window, decreasing
+    maximums: VecDeque<(usize, i32)>,
+}
+
+impl<I> SlidingWindows<I> {
+    /// Compares each window with the one `offset` readings before it, see [`Comparisons`]
+    pub fn compared(self, offset: usize) -> Comparisons<Self> {
+        assert!(offset > 0, "windows must be compared with previous ones");
+
+        Comparisons {
+            windows: self,
+            offset,
+            sums: VecDeque::with_capacity(offset + 1),
+        }
+    }
+}
+
+impl<I: Iterator<Item = i32>> Iterator for SlidingWindows<I> {
+    type Item = Window;
+
+    fn next(&mut self) -> Option<Window> {
+        loop {
+            let depth = self.depths.next()?;
+            let index = self.count;
+            self.count += 1;
+
+            self.readings.push_back(depth);
+            self.sum += depth as i64;
+            if self.readings.len() > self.size {
+                self.sum -= self.readings.pop_front().unwrap() as i64;
+            }
+
+            // A reading can no longer be the extremum of a window once a later one goes past it
+            while matches!(self.minimums.back(), Some(&(_, minimum)) if minimum >= depth) {
+                self.minimums.pop_back();
+            }
+            self.minimums.push_back((index, depth));
+            while matches!(self.maximums.back(), Some(&(_, maximum)) if maximum <= depth) {
+                self.maximums.pop_back();
+            }
+            self.maximums.push_back((index, depth));
+
+            if self.readings.len() == self.size {
+                let start = index + 1 - self.size;
+                while self.minimums[0].0 < start {
+                    self.minimums.pop_front();
+                }
+                while self.maximums[0].0 < start {
+                    self.maximums.pop_front();
+                }
+
+                return Some(Window {
+                    start,
+                    size: self.size,
+                    sum: self.sum,
+                    min: self.minimums[0].1,
+                    max: self.maximums[0].1,
+                });
+            }
+        }
+    }
+}
+
+/// Adaptor comparing the sum of each window with the sum of the window `offset` readings before
+/// it: `Greater` is an increase of the depth, `Less` a decrease and `Equal` a plateau.
+pub struct Comparisons<I> {
+    windows: I,
+    offset: usize,
+    /// Sums of the last windows, up to `offset` of them
+    sums: VecDeque<i64>,
+}
+
+impl<I: Iterator<Item = Window>> Iterator for Comparisons<I> {
+    type Item = Ordering;
+
+    fn next(&mut self) -> Option<Ordering> {
+        loop {
+            let window = self.windows.next()?;
+            self.sums.push_back(window.sum);
+            if self.sums.len() > self.offset {
+                let previous_sum = self.sums.pop_front().unwrap();
+                return Some(window.sum.cmp(&previous_sum));
+            }
+        }
+    }
+}
+
+/// Windowed analysis of any stream of depths
+pub trait Sonar: Iterator<Item = i32> + Sized {
+    /// Windows of `size` consecutive readings, one per reading from the `size`th one on
+    fn sliding_windows(self, size: usize) -> SlidingWindows<Self> {
+        assert!(size > 0, "windows must hold at least a reading");
+
+        SlidingWindows {
+            depths: self,
+            size,
+            count: 0,
+            readings: VecDeque::with_capacity(size + 1),
+            sum: 0,
+            minimums: VecDeque::new(),
+            maximums: VecDeque::new(),
+        }
+    }
+}
+
+impl<I: Iterator<Item = i32>> Sonar for I {}
+
+/// Summary of the comparisons of the windows
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+pub struct Trend {
+    pub increases: usize,
+    pub decreases: usize,
+    pub plateaus: usize,
+    /// Largest number of increases in a row
+    pub longest_increasing_run: usize,
+}
+
+impl FromIterator<Ordering> for Trend {
+    fn from_iter<T: IntoIterator<Item = Ordering>>(comparisons: T) -> Self {
+        let mut trend = Trend::default();
+        let mut increasing_run = 0;
+
+        for comparison in comparisons {
+            match comparison {
+                Ordering::Greater => {
+                    trend.increases += 1;
+                    increasing_run += 1;
+                    trend.longest_increasing_run = trend.longest_increasing_run.max(increasing_run);
+                }
+                Ordering::Less => {
+                    trend.decreases += 1;
+                    increasing_run = 0;
+                }
+                Ordering::Equal => {
+                    trend.plateaus += 1;
+                    increasing_run = 0;
+                }
+            }
+        }
+
+        trend
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::windows::{Sonar, Trend, Window};
+    use aoc_common::Rng;
+    use std::cmp::Ordering;
+
+    const EXAMPLE: [i32; 10] = [199, 200, 208, 210, 200, 207, 240, 269, 260, 263];
+
+    #[test]
+    fn trend_example_case() {
+        let trend = |size, offset| {
+            EXAMPLE
+                .into_iter()
+                .sliding_windows(size)
+                .compared(offset)
+                .collect::<Trend>()
+        };
+
+        assert_eq!(
+            trend(1, 1),
+            Trend {
+                increases: 7,
+                decreases: 2,
+                plateaus: 0,
+                longest_increasing_run: 3,
+            }
+        );
+        assert_eq!(trend(3, 1).increases, 5);
+        // Windows sharing all but a reading compare as the readings they do not share
+        assert_eq!(trend(3, 1), trend(1, 3));
+        assert_eq!(trend(11, 1), Trend::default());
+    }
+
+    #[test]
+    fn moving_statistics_example_case() {
+        let windows = EXAMPLE.into_iter().sliding_windows(3).collect::<Vec<_>>();
+
+        assert_eq!(windows.len(), 8);
+        assert_eq!(
+            windows[3],
+            Window {
+                start: 3,
+                size: 3,
+                sum: 617,
+                min: 200,
+                max: 210,
+            }
+        );
+        assert_eq!(windows[7].mean(), 264.0);
+    }
+
+    #[test]
+    fn windows_match_slices() {
+        let mut rng = Rng::new(1);
+        for _ in 0..50 {
+            let depths = (0..rng.range(0..=40))
+                .map(|_| rng.range(-5..=5) as i32)
+                .collect::<Vec<_>>();
+            let size = rng.range(1..=6) as usize;
+            let offset = rng.range(1..=4) as usize;
+
+            let expected = depths
+                .windows(size)
+                .map(|window| {
+                    (
+                        window.iter().map(|&depth| depth as i64).sum::<i64>(),
+                        *window.iter().min().unwrap(),
+                        *window.iter().max().unwrap(),
+                    )
+                })
+                .collect::<Vec<_>>();
+            let windows = depths.iter().copied().sliding_windows(size);
+            assert_eq!(
+                windows
+                    .map(|window| (window.sum, window.min, window.max))
+                    .collect::<Vec<_>>(),
+                expected
+            );
+
+            let comparisons = depths
+                .iter()
+                .copied()
+                .sliding_windows(size)
+                .compared(offset);
+            let expected_comparisons = (offset..expected.len())
+                .map(|i| expected[i].0.cmp(&expected[i - offset].0))
+                .collect::<Vec<Ordering>>();
+            assert_eq!(comparisons.collect::<Vec<_>>(), expected_comparisons);
+        }
+    }
+
+    #[test]
+    fn unbounded_stream() {
+        let depths = (0..).map(|depth| depth % 7);
+        let trend = depths
+            .sliding_windows(2)
+            .compared(1)
+            .take(1_000)
+            .collect::<Trend>();
+
+        assert_eq!(trend.longest_increasing_run, 5);
+        assert_eq!(trend.increases + trend.decreases, 1_000);
+    }
+}
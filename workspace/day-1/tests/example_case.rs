@@ -0,0 +1,29 @@
+use aoc_common::{Answer, Solution};
+use day_1::input_parsing::parse_string;
+use day_1::part_1::depth_increase_count;
+use day_1::part_2::sliding_window_depth_increase_count;
+use day_1::Day1;
+
+const EXAMPLE: &str = include_str!("../dataset/example.txt");
+
+#[test]
+fn part_1_example_case() {
+    let input = parse_string(EXAMPLE.to_string()).unwrap();
+
+    assert_eq!(depth_increase_count(&input.data), 7);
+}
+
+#[test]
+fn part_2_example_case() {
+    let input = parse_string(EXAMPLE.to_string()).unwrap();
+
+    assert_eq!(sliding_window_depth_increase_count(&input), 5);
+}
+
+#[test]
+fn solution_example_case() {
+    let input = Day1::parse(EXAMPLE.to_string()).unwrap();
+
+    assert_eq!(Day1::part_1(&input), Answer::Number(7));
+    assert_eq!(Day1::part_2(&input), Answer::Number(5));
+}
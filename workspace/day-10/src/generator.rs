@@ -0,0 +1,82 @@
+use aoc_common::Rng;
+
+const BRACKETS: [(char, char); 4] = [('(', ')'), ('[', ']'), ('{', '}'), ('<', '>')];
+
+/// Completion scores multiply by 5 for each missing bracket, more would overflow them
+const MAX_DEPTH: usize = 25;
+
+/// Generates `size` lines, either corrupted or incomplete. The number of incomplete lines is odd,
+/// so that their scores have a middle one.
+pub fn generate(size: usize, rng: &mut Rng) -> String {
+    let size = size.max(1);
+    let mut incomplete = (0..size).map(|_| rng.chance(0.5)).collect::<Vec<_>>();
+    if incomplete.iter().filter(|&&incomplete| incomplete).count() % 2 == 0 {
+        incomplete[0] = !incomplete[0];
+    }
+
+    let mut content = String::new();
+    for incomplete in incomplete {
+        let mut open = Vec::new();
+        let length = rng.range(20..=110) as usize;
+        walk(&mut content, &mut open, length / 2, rng);
+        if incomplete {
+            walk(&mut content, &mut open, length - length / 2, rng);
+            if open.is_empty() {
+                let (opening, closing) = *rng.choose(&BRACKETS);
+                content.push(opening);
+                open.push(closing);
+            }
+        } else {
+            let expected = open.last().copied();
+            let (_, corrupted) = *rng.choose(
+                &BRACKETS
+                    .into_iter()
+                    .filter(|&(_, closing)| Some(closing) != expected)
+                    .collect::<Vec<_>>(),
+            );
+            content.push(corrupted);
+            walk(&mut content, &mut open, length / 2, rng);
+        }
+        content.push('\n');
+    }
+
+    content
+}
+
+/// Appends `length` brackets, either opening new chunks or closing the current one
+fn walk(content: &mut String, open: &mut Vec<char>, length: usize, rng: &mut Rng) {
+    for _ in 0..length {
+        if !open.is_empty() && (open.len() >= MAX_DEPTH || rng.chance(0.45)) {
+            content.push(open.pop().unwrap());
+        } else {
+            let (opening, closing) = *rng.choose(&BRACKETS);
+            content.push(opening);
+            open.push(closing);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::generator::generate;
+    use crate::models::ParsedLine;
+    use crate::Day10;
+    use aoc_common::{Rng, Solution};
+
+    #[test]
+    fn generate_valid_input() {
+        for seed in 0..10 {
+            let input = Day10::parse(generate(50, &mut Rng::new(seed))).unwrap();
+            let lines = &input.parsed_lines;
+
+            assert_eq!(lines.len(), 50);
+            assert!(!lines.contains(&ParsedLine::Valid));
+            let incomplete = lines
+                .iter()
+                .filter(|line| matches!(line, ParsedLine::Incomplete(_)))
+                .count();
+            assert_eq!(incomplete % 2, 1);
+            Day10::part_2(&input);
+        }
+    }
+}
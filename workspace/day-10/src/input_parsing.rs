@@ -1,26 +1,48 @@
 use crate::models::{Input, ParsedLine};
-use std::error::Error;
-use std::fs;
+use crate::Day10;
+use aoc_common::{input_lines, read_lines, InputError, ParseError, Solution};
+use std::io::BufRead;
 
-pub fn parse_file(file_path: String) -> Result<Input, Box<dyn Error>> {
-    let file_content = fs::read_to_string(file_path).expect("Error while reading the data file");
+pub fn parse_string(content: String) -> Result<Input, ParseError> {
+    let parsed_lines = input_lines(Day10::DAY, &content)
+        .map(|line| ParsedLine::parse_str(&line))
+        .collect::<Result<_, _>>()?;
 
-    parse_string(file_content)
+    Ok(Input { parsed_lines })
 }
 
-pub fn parse_string(content: String) -> Result<Input, Box<dyn Error>> {
-    let parsed_lines = content
-        .lines()
-        .map(|line| ParsedLine::from_str(line))
-        .collect();
+/// Parses the lines as they are read, without holding the whole input in memory
+pub fn parse_reader<R: BufRead>(reader: R) -> Result<Input, InputError> {
+    let mut parsed_lines = Vec::new();
+    read_lines(Day10::DAY, reader, |line| {
+        parsed_lines.push(ParsedLine::parse_str(&line)?);
+        Ok(())
+    })?;
 
     Ok(Input { parsed_lines })
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::input_parsing::parse_string;
+    use crate::input_parsing::{parse_reader, parse_string};
     use crate::models::{Input, ParsedLine, Symbol};
+    use aoc_common::{InputError, ParseError};
+
+    /// Builds the expected incomplete line from the completion string given in the puzzle statement
+    fn incomplete(completion: &str) -> ParsedLine {
+        ParsedLine::Incomplete(
+            completion
+                .chars()
+                .rev()
+                .map(|c| match c {
+                    ')' => Symbol::RoundBracketOpen,
+                    ']' => Symbol::SquareBracketOpen,
+                    '}' => Symbol::CurlyBracketOpen,
+                    _ => Symbol::AngleBracketOpen,
+                })
+                .collect(),
+        )
+    }
 
     #[test]
     fn parse_example_case() {
@@ -43,18 +65,40 @@ mod tests {
             input,
             Input {
                 parsed_lines: vec![
-                    ParsedLine::Incomplete,
-                    ParsedLine::Incomplete,
+                    incomplete("}}]])})]"),
+                    incomplete(")}>]})"),
                     ParsedLine::Corrupted(Symbol::CurlyBracketClose),
-                    ParsedLine::Incomplete,
+                    incomplete("}}>}>))))"),
                     ParsedLine::Corrupted(Symbol::RoundBracketClose),
                     ParsedLine::Corrupted(Symbol::SquareBracketClose),
-                    ParsedLine::Incomplete,
+                    incomplete("]]}}]}]}>"),
                     ParsedLine::Corrupted(Symbol::RoundBracketClose),
                     ParsedLine::Corrupted(Symbol::AngleBracketClose),
-                    ParsedLine::Incomplete,
+                    incomplete("])}>"),
                 ]
             }
         );
     }
+
+    #[test]
+    fn parse_stream() {
+        let content = "[({(<(())[]>[[{[]{<()<>>\n{([(<{}[<>[]}>{[]{[(<()>\n";
+
+        assert_eq!(
+            parse_reader(content.as_bytes()).unwrap(),
+            parse_string(content.to_string()).unwrap()
+        );
+        assert!(matches!(
+            parse_reader("[({(<(())[]>[[{[]{<()<>>\n[(()[<>])]({[<{<<a]>>(\n".as_bytes()),
+            Err(InputError::Parse(error)) if error == ParseError::new(10, 2, 18, "a bracket")
+        ));
+    }
+
+    #[test]
+    fn parse_invalid_symbol() {
+        assert_eq!(
+            parse_string("[({(<(())[]>[[{[]{<()<>>\n[(()[<>])]({[<{<<a]>>(\n".to_string()),
+            Err(ParseError::new(10, 2, 18, "a bracket"))
+        );
+    }
 }
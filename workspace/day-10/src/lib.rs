@@ -0,0 +1,41 @@
+use crate::input_parsing::{parse_reader, parse_string};
+use crate::models::Input;
+use aoc_common::{Answer, Generator, InputError, ParseError, Rng, Solution};
+use std::io::BufRead;
+
+pub mod generator;
+pub mod input_parsing;
+pub mod models;
+pub mod part_1;
+pub mod part_2;
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+    type Input = Input;
+
+    fn parse(content: String) -> Result<Input, ParseError> {
+        parse_string(content)
+    }
+
+    fn parse_reader<R: BufRead>(reader: R) -> Result<Input, InputError> {
+        parse_reader(reader)
+    }
+
+    fn part_1(input: &Input) -> Answer {
+        part_1::syntax_error_score(input).into()
+    }
+
+    fn part_2(input: &Input) -> Answer {
+        part_2::autocomplete_score(input).into()
+    }
+}
+
+impl Generator for Day10 {
+    const DEFAULT_SIZE: usize = 100;
+
+    fn generate(size: usize, rng: &mut Rng) -> String {
+        generator::generate(size, rng)
+    }
+}
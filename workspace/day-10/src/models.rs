@@ -1,3 +1,5 @@
+use aoc_common::{InputLine, ParseError};
+
 #[derive(Debug, Eq, PartialEq)]
 pub struct Input {
     pub parsed_lines: Vec<ParsedLine>,
@@ -23,17 +25,17 @@ pub enum Symbol {
 }
 
 impl Symbol {
-    fn from_char(c: char) -> Self {
+    fn from_char(c: char) -> Option<Self> {
         match c {
-            '(' => Symbol::RoundBracketOpen,
-            ')' => Symbol::RoundBracketClose,
-            '[' => Symbol::SquareBracketOpen,
-            ']' => Symbol::SquareBracketClose,
-            '{' => Symbol::CurlyBracketOpen,
-            '}' => Symbol::CurlyBracketClose,
-            '<' => Symbol::AngleBracketOpen,
-            '>' => Symbol::AngleBracketClose,
-            _ => panic!("Invalid character found"),
+            '(' => Some(Symbol::RoundBracketOpen),
+            ')' => Some(Symbol::RoundBracketClose),
+            '[' => Some(Symbol::SquareBracketOpen),
+            ']' => Some(Symbol::SquareBracketClose),
+            '{' => Some(Symbol::CurlyBracketOpen),
+            '}' => Some(Symbol::CurlyBracketClose),
+            '<' => Some(Symbol::AngleBracketOpen),
+            '>' => Some(Symbol::AngleBracketClose),
+            _ => None,
         }
     }
 
@@ -49,11 +51,12 @@ impl Symbol {
 }
 
 impl ParsedLine {
-    pub fn from_str(line: &str) -> Self {
+    pub fn parse_str(line: &InputLine) -> Result<Self, ParseError> {
         let mut symbols_queue = Vec::new();
 
-        for (index, c) in line.chars().enumerate() {
-            match Symbol::from_char(c) {
+        for (offset, c) in line.text.char_indices() {
+            let symbol = Symbol::from_char(c).ok_or_else(|| line.error_at(offset, "a bracket"))?;
+            match symbol {
                 symbol
                     if symbol == Symbol::RoundBracketOpen
                         || symbol == Symbol::SquareBracketOpen
@@ -63,20 +66,20 @@ impl ParsedLine {
                     symbols_queue.push(symbol);
                 }
                 symbol => match symbols_queue.pop() {
-                    None => return ParsedLine::Corrupted(symbol),
+                    None => return Ok(ParsedLine::Corrupted(symbol)),
                     Some(previous_symbol) => {
                         if previous_symbol != symbol.opening_symbol() {
-                            return ParsedLine::Corrupted(symbol);
+                            return Ok(ParsedLine::Corrupted(symbol));
                         }
                     }
                 },
             }
         }
 
-        if symbols_queue.len() > 0 {
-            ParsedLine::Incomplete(symbols_queue)
+        if !symbols_queue.is_empty() {
+            Ok(ParsedLine::Incomplete(symbols_queue))
         } else {
-            ParsedLine::Valid
+            Ok(ParsedLine::Valid)
         }
     }
 }
@@ -84,24 +87,29 @@ impl ParsedLine {
 #[cfg(test)]
 mod tests {
     use crate::models::{ParsedLine, Symbol};
+    use aoc_common::InputLine;
+
+    fn parse(line: &str) -> ParsedLine {
+        ParsedLine::parse_str(&InputLine::new(10, 0, line)).unwrap()
+    }
 
     #[test]
     fn corrupted_chunks() {
         // Corrupted examples
         assert_eq!(
-            ParsedLine::from_str("(]"),
+            parse("(]"),
             ParsedLine::Corrupted(Symbol::SquareBracketClose)
         );
         assert_eq!(
-            ParsedLine::from_str("{()()()>"),
+            parse("{()()()>"),
             ParsedLine::Corrupted(Symbol::AngleBracketClose)
         );
         assert_eq!(
-            ParsedLine::from_str("(((()))}"),
+            parse("(((()))}"),
             ParsedLine::Corrupted(Symbol::CurlyBracketClose)
         );
         assert_eq!(
-            ParsedLine::from_str("<([]){()}[{}])"),
+            parse("<([]){()}[{}])"),
             ParsedLine::Corrupted(Symbol::RoundBracketClose)
         );
     }
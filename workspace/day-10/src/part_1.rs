@@ -4,15 +4,14 @@ pub fn syntax_error_score(input: &Input) -> u64 {
     let mut score = 0;
 
     for line in &input.parsed_lines {
-        match line {
-            ParsedLine::Corrupted(symbol) => match symbol {
+        if let ParsedLine::Corrupted(symbol) = line {
+            match symbol {
                 Symbol::RoundBracketClose => score += 3,
                 Symbol::SquareBracketClose => score += 57,
                 Symbol::CurlyBracketClose => score += 1197,
                 Symbol::AngleBracketClose => score += 25137,
                 _ => {}
-            },
-            _ => {}
+            }
         }
     }
 
@@ -28,16 +27,16 @@ mod tests {
     fn part_1_example_case() {
         let input = Input {
             parsed_lines: vec![
-                ParsedLine::Incomplete,
-                ParsedLine::Incomplete,
+                ParsedLine::Incomplete(vec![]),
+                ParsedLine::Incomplete(vec![]),
                 ParsedLine::Corrupted(Symbol::CurlyBracketClose),
-                ParsedLine::Incomplete,
+                ParsedLine::Incomplete(vec![]),
                 ParsedLine::Corrupted(Symbol::RoundBracketClose),
                 ParsedLine::Corrupted(Symbol::SquareBracketClose),
-                ParsedLine::Incomplete,
+                ParsedLine::Incomplete(vec![]),
                 ParsedLine::Corrupted(Symbol::RoundBracketClose),
                 ParsedLine::Corrupted(Symbol::AngleBracketClose),
-                ParsedLine::Incomplete,
+                ParsedLine::Incomplete(vec![]),
             ],
         };
 
@@ -0,0 +1,29 @@
+use aoc_common::{Answer, Solution};
+use day_10::input_parsing::parse_string;
+use day_10::part_1::syntax_error_score;
+use day_10::part_2::autocomplete_score;
+use day_10::Day10;
+
+const EXAMPLE: &str = include_str!("../dataset/example.txt");
+
+#[test]
+fn part_1_example_case() {
+    let input = parse_string(EXAMPLE.to_string()).unwrap();
+
+    assert_eq!(syntax_error_score(&input), 26397);
+}
+
+#[test]
+fn part_2_example_case() {
+    let input = parse_string(EXAMPLE.to_string()).unwrap();
+
+    assert_eq!(autocomplete_score(&input), 288957);
+}
+
+#[test]
+fn solution_example_case() {
+    let input = Day10::parse(EXAMPLE.to_string()).unwrap();
+
+    assert_eq!(Day10::part_1(&input), Answer::Number(26397));
+    assert_eq!(Day10::part_2(&input), Answer::Number(288957));
+}
@@ -0,0 +1,42 @@
+use crate::part_2::synchronized_flash;
+use crate::Day11;
+use aoc_common::{Rng, Solution};
+
+/// Grids whose octopuses are still not synchronized after that many steps are generated again
+const MAX_STEPS: usize = 10_000;
+
+/// Generates a grid of energy levels whose octopuses all end up flashing at once. The grid is
+/// always 10 × 10, so the size is ignored.
+pub fn generate(_size: usize, rng: &mut Rng) -> String {
+    loop {
+        let mut content = String::with_capacity(110);
+        for _ in 0..10 {
+            for _ in 0..10 {
+                content.push(char::from_digit(rng.below(10) as u32, 10).unwrap());
+            }
+            content.push('\n');
+        }
+
+        let input = Day11::parse(content.clone()).unwrap();
+        if synchronized_flash(&input, MAX_STEPS).is_some() {
+            return content;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::generator::generate;
+    use crate::part_2::synchronized_flash;
+    use crate::Day11;
+    use aoc_common::{Rng, Solution};
+
+    #[test]
+    fn generate_valid_input() {
+        for seed in 0..5 {
+            let input = Day11::parse(generate(10, &mut Rng::new(seed))).unwrap();
+
+            assert!(synchronized_flash(&input, 10_000).is_some());
+        }
+    }
+}
@@ -1,36 +1,29 @@
 use crate::models::Input;
-use std::error::Error;
-use std::fs;
+use crate::Day11;
+use aoc_common::{input_lines, Grid, ParseError, Solution};
 
-pub fn parse_file(file_path: String) -> Result<Input, Box<dyn Error>> {
-    let file_content = fs::read_to_string(file_path).expect("Error while reading the data file");
-
-    parse_string(file_content)
-}
-
-pub fn parse_string(content: String) -> Result<Input, Box<dyn Error>> {
-    let octopuses = content
-        .lines()
-        .map(|line| {
-            line.chars()
-                .map(|c| (c as u8 - '0' as u8) as i8)
-                .collect::<Vec<i8>>()
-        })
-        .flatten()
-        .collect::<Vec<i8>>();
+pub fn parse_string(content: String) -> Result<Input, ParseError> {
+    let lines = input_lines(Day11::DAY, &content).collect::<Vec<_>>();
+    if lines.len() < 10 {
+        return Err(ParseError::new(Day11::DAY, lines.len() + 1, 1, "10 lines"));
+    }
 
-    let mut input = Input {
-        octopuses: [0; 100],
-    };
-    input.octopuses.copy_from_slice(&*octopuses);
+    let octopuses = Grid::parse_digits(lines.iter().take(10).copied(), "energy levels")?;
+    if octopuses.width() != 10 {
+        return Err(lines[0].error_at(octopuses.width().min(10), "10 energy levels"));
+    }
 
-    Ok(input)
+    Ok(Input {
+        octopuses: octopuses.map(|&energy_level| energy_level as i8),
+    })
 }
 
 #[cfg(test)]
 mod tests {
     use crate::input_parsing::parse_string;
     use crate::models::Input;
+    use aoc_common::Grid;
+    use aoc_common::ParseError;
 
     #[test]
     fn parse_example_case() {
@@ -52,13 +45,29 @@ mod tests {
         assert_eq!(
             input,
             Input {
-                octopuses: [
-                    5, 4, 8, 3, 1, 4, 3, 2, 2, 3, 2, 7, 4, 5, 8, 5, 4, 7, 1, 1, 5, 2, 6, 4, 5, 5,
-                    6, 1, 7, 3, 6, 1, 4, 1, 3, 3, 6, 1, 4, 6, 6, 3, 5, 7, 3, 8, 5, 4, 7, 8, 4, 1,
-                    6, 7, 5, 2, 4, 6, 4, 5, 2, 1, 7, 6, 8, 4, 1, 7, 2, 1, 6, 8, 8, 2, 8, 8, 1, 1,
-                    3, 4, 4, 8, 4, 6, 8, 4, 8, 5, 5, 4, 5, 2, 8, 3, 7, 5, 1, 5, 2, 6,
-                ]
+                octopuses: Grid::new(
+                    10,
+                    10,
+                    vec![
+                        5, 4, 8, 3, 1, 4, 3, 2, 2, 3, 2, 7, 4, 5, 8, 5, 4, 7, 1, 1, 5, 2, 6, 4, 5,
+                        5, 6, 1, 7, 3, 6, 1, 4, 1, 3, 3, 6, 1, 4, 6, 6, 3, 5, 7, 3, 8, 5, 4, 7, 8,
+                        4, 1, 6, 7, 5, 2, 4, 6, 4, 5, 2, 1, 7, 6, 8, 4, 1, 7, 2, 1, 6, 8, 8, 2, 8,
+                        8, 1, 1, 3, 4, 4, 8, 4, 6, 8, 4, 8, 5, 5, 4, 5, 2, 8, 3, 7, 5, 1, 5, 2, 6,
+                    ],
+                )
             }
         );
     }
+
+    #[test]
+    fn parse_invalid_grid() {
+        assert_eq!(
+            parse_string("5483143223\n2745854711\n".to_string()),
+            Err(ParseError::new(11, 3, 1, "10 lines"))
+        );
+        assert_eq!(
+            parse_string("548314322\n".repeat(10)),
+            Err(ParseError::new(11, 1, 10, "10 energy levels"))
+        );
+    }
 }
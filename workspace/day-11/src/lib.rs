@@ -0,0 +1,44 @@
+use crate::input_parsing::parse_string;
+use crate::models::Input;
+use aoc_common::{Answer, FrameSink, Generator, ParseError, Rng, Solution, Visualize};
+use std::io;
+
+pub mod generator;
+pub mod input_parsing;
+pub mod models;
+pub mod part_1;
+pub mod part_2;
+pub mod visualization;
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: u8 = 11;
+    type Input = Input;
+
+    fn parse(content: String) -> Result<Input, ParseError> {
+        parse_string(content)
+    }
+
+    fn part_1(input: &Input) -> Answer {
+        part_1::count_flashes(input, 100).into()
+    }
+
+    fn part_2(input: &Input) -> Answer {
+        part_2::all_octopus_flash(input).into()
+    }
+}
+
+impl Generator for Day11 {
+    const DEFAULT_SIZE: usize = 10;
+
+    fn generate(size: usize, rng: &mut Rng) -> String {
+        generator::generate(size, rng)
+    }
+}
+
+impl Visualize for Day11 {
+    fn visualize(input: &Input, frames: &mut dyn FrameSink) -> io::Result<()> {
+        visualization::visualize(input, frames)
+    }
+}
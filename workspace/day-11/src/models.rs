@@ -1,4 +1,52 @@
+use aoc_common::Grid;
+
 #[derive(Debug, Eq, PartialEq)]
 pub struct Input {
-    pub octopuses: [i8; 100],
+    pub octopuses: Grid<i8>,
+}
+
+/// Runs a step of the octopuses energy levels, and returns the number of octopuses that flashed
+pub fn step(octopuses: &mut Grid<i8>) -> usize {
+    let mut flash_count = 0;
+
+    // First increase all by 1
+    for octopus in octopuses.values_mut() {
+        *octopus += 1;
+    }
+
+    // Then, propagate and count flashes
+    loop {
+        let mut new_propagation_required = false;
+        for position in octopuses.positions() {
+            if octopuses[position] > 9 {
+                // Flash !
+                flash_count += 1;
+                octopuses[position] = -1;
+
+                // Propagate
+                for neighbour in octopuses.neighbours_8(position).collect::<Vec<_>>() {
+                    let value = octopuses[neighbour];
+                    if value != -1 {
+                        octopuses[neighbour] = value + 1;
+                        if value + 1 > 9 {
+                            new_propagation_required = true;
+                        }
+                    }
+                }
+            }
+        }
+
+        if !new_propagation_required {
+            break;
+        }
+    }
+
+    // End of propagation, put the fhased octopuses to the 0 state
+    for octopus in octopuses.values_mut() {
+        if *octopus == -1 {
+            *octopus = 0;
+        }
+    }
+
+    flash_count
 }
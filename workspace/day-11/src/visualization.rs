@@ -0,0 +1,50 @@
+use crate::models::{step, Input};
+use aoc_common::{FrameSink, Grid};
+use std::io;
+
+/// Steps shown at most when the octopuses never all flash at once
+const MAX_STEPS: usize = 1_000;
+
+/// Shows the energy levels of the octopuses step by step, until they all flash at once. The
+/// octopuses that just flashed are white, the others are darker the lower their energy level.
+pub fn visualize(input: &Input, frames: &mut dyn FrameSink) -> io::Result<()> {
+    let mut octopuses = input.octopuses.clone();
+    let octopus_count = octopuses.width() * octopuses.height();
+
+    frames.frame(&frame(&octopuses))?;
+    for _ in 0..MAX_STEPS {
+        let flash_count = step(&mut octopuses);
+        frames.frame(&frame(&octopuses))?;
+
+        if flash_count == octopus_count {
+            break;
+        }
+    }
+
+    Ok(())
+}
+
+fn frame(octopuses: &Grid<i8>) -> Grid<u8> {
+    octopuses.map(|&energy_level| match energy_level {
+        0 => 255,
+        energy_level => energy_level as u8 * 20,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::input_parsing::parse_string;
+    use crate::visualization::visualize;
+
+    #[test]
+    fn visualize_example_case() {
+        let input = parse_string(include_str!("../dataset/example.txt").to_string()).unwrap();
+        let mut frames = Vec::new();
+        visualize(&input, &mut frames).unwrap();
+
+        // The initial state, then each step until the synchronized flash
+        assert_eq!(frames.len(), 196);
+        assert_eq!(frames[0][(0, 0)], 100);
+        assert!(frames[195].values().all(|&level| level == 255));
+    }
+}
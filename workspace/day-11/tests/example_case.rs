@@ -0,0 +1,30 @@
+use aoc_common::{Answer, Solution};
+use day_11::input_parsing::parse_string;
+use day_11::part_1::count_flashes;
+use day_11::part_2::all_octopus_flash;
+use day_11::Day11;
+
+const EXAMPLE: &str = include_str!("../dataset/example.txt");
+
+#[test]
+fn part_1_example_case() {
+    let input = parse_string(EXAMPLE.to_string()).unwrap();
+
+    assert_eq!(count_flashes(&input, 10), 204);
+    assert_eq!(count_flashes(&input, 100), 1656);
+}
+
+#[test]
+fn part_2_example_case() {
+    let input = parse_string(EXAMPLE.to_string()).unwrap();
+
+    assert_eq!(all_octopus_flash(&input), 195);
+}
+
+#[test]
+fn solution_example_case() {
+    let input = Day11::parse(EXAMPLE.to_string()).unwrap();
+
+    assert_eq!(Day11::part_1(&input), Answer::Number(1656));
+    assert_eq!(Day11::part_2(&input), Answer::Number(195));
+}
@@ -0,0 +1,98 @@
+use aoc_common::Rng;
+use std::fmt::Write;
+
+/// Generates `size` tunnels (or the few more needed to connect every cave) between the start, the
+/// end, small and big caves. Big caves are never connected together, otherwise there would be
+/// infinitely many paths.
+pub fn generate(size: usize, rng: &mut Rng) -> String {
+    let small_caves = cave_names(size / 4 + 2, |c| c.to_ascii_lowercase(), rng);
+    let big_caves = cave_names(size / 10 + 1, |c| c, rng);
+
+    // Connect every cave: the small caves form a tree reached from the start and reaching the
+    // end, each big cave leads to one of them
+    let mut tunnels = vec![("start".to_string(), small_caves[0].clone())];
+    for (i, small_cave) in small_caves.iter().enumerate().skip(1) {
+        tunnels.push((small_caves[rng.below(i)].clone(), small_cave.clone()));
+    }
+    tunnels.push((rng.choose(&small_caves).clone(), "end".to_string()));
+    for big_cave in &big_caves {
+        tunnels.push((big_cave.clone(), rng.choose(&small_caves).clone()));
+    }
+
+    let mut caves = vec!["start".to_string(), "end".to_string()];
+    caves.extend(small_caves.iter().cloned());
+    let mut candidates = Vec::new();
+    for (i, a) in caves.iter().enumerate() {
+        for b in caves[i + 1..].iter().chain(&big_caves) {
+            if !(a == "start" && b == "end") {
+                candidates.push((a.clone(), b.clone()));
+            }
+        }
+    }
+    rng.shuffle(&mut candidates);
+    for candidate in candidates {
+        if tunnels.len() >= size {
+            break;
+        }
+        let (a, b) = &candidate;
+        if !tunnels
+            .iter()
+            .any(|(x, y)| (x == a && y == b) || (x == b && y == a))
+        {
+            tunnels.push(candidate);
+        }
+    }
+
+    rng.shuffle(&mut tunnels);
+    let mut content = String::new();
+    for (a, b) in tunnels {
+        if rng.chance(0.5) {
+            writeln!(content, "{}-{}", a, b).unwrap();
+        } else {
+            writeln!(content, "{}-{}", b, a).unwrap();
+        }
+    }
+
+    content
+}
+
+/// Distinct random cave names of two letters
+fn cave_names(count: usize, case: impl Fn(char) -> char, rng: &mut Rng) -> Vec<String> {
+    let mut names = Vec::new();
+    while names.len() < count.min(26 * 26) {
+        let name = (0..2)
+            .map(|_| case((b'A' + rng.below(26) as u8) as char))
+            .collect::<String>();
+        if !names.contains(&name) {
+            names.push(name);
+        }
+    }
+
+    names
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::generator::generate;
+    use crate::models::CaveType;
+    use crate::Day12;
+    use aoc_common::{Answer, Rng, Solution};
+
+    #[test]
+    fn generate_valid_input() {
+        for seed in 0..5 {
+            let input = Day12::parse(generate(12, &mut Rng::new(seed))).unwrap();
+
+            for cave in &input.caves {
+                if cave.cave_type == CaveType::Big {
+                    assert!(cave
+                        .tunnel_to_cave_index
+                        .iter()
+                        .all(|&index| input.caves[index].cave_type != CaveType::Big));
+                }
+            }
+            assert_ne!(Day12::part_1(&input), Answer::Number(0));
+            Day12::part_2(&input);
+        }
+    }
+}
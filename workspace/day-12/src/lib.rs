@@ -0,0 +1,32 @@
+use crate::models::CaveGraph;
+use aoc_common::{Answer, Generator, ParseError, Rng, Solution};
+
+pub mod generator;
+pub mod models;
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    const DAY: u8 = 12;
+    type Input = CaveGraph;
+
+    fn parse(content: String) -> Result<CaveGraph, ParseError> {
+        CaveGraph::parse_string(content)
+    }
+
+    fn part_1(input: &CaveGraph) -> Answer {
+        input.find_all_paths_count().into()
+    }
+
+    fn part_2(input: &CaveGraph) -> Answer {
+        input.find_all_paths_with_twice_small_visit_count().into()
+    }
+}
+
+impl Generator for Day12 {
+    const DEFAULT_SIZE: usize = 20;
+
+    fn generate(size: usize, rng: &mut Rng) -> String {
+        generator::generate(size, rng)
+    }
+}
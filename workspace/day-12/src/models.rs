@@ -1,5 +1,6 @@
+use crate::Day12;
+use aoc_common::{input_lines, ParseError, Solution};
 use itertools::Itertools;
-use std::error::Error;
 
 #[derive(Debug, Default, Eq, PartialEq)]
 pub struct CaveGraph {
@@ -24,16 +25,25 @@ pub enum CaveType {
 }
 
 impl CaveGraph {
-    pub fn parse_string(content: String) -> Result<CaveGraph, Box<dyn Error>> {
+    pub fn parse_string(content: String) -> Result<CaveGraph, ParseError> {
         let mut cave_graph = CaveGraph::default();
 
-        let edges = content
-            .lines()
+        let edges = input_lines(Day12::DAY, &content)
+            .filter(|line| !line.text.is_empty())
             .map(|line| {
-                let parts = line.split('-').collect::<Vec<&str>>();
-                vec![parts[0], parts[1]]
+                let (start, end) = line
+                    .text
+                    .split_once('-')
+                    .ok_or_else(|| line.error_at_end("`-`"))?;
+                if start.is_empty() {
+                    return Err(line.error(start, "a cave name"));
+                }
+                if end.is_empty() {
+                    return Err(line.error_at_end("a cave name"));
+                }
+                Ok(vec![start, end])
             })
-            .collect::<Vec<_>>();
+            .collect::<Result<Vec<_>, _>>()?;
 
         // Index the unique caves
         let cave_names = edges
@@ -87,21 +97,17 @@ impl CaveGraph {
         // A state is the state of a node visit, with the index of the visited node, and history of already visited nodes (for small ones)
         let mut states = Vec::with_capacity(100);
         states.push((self.start_index, vec![false; self.caves.len()]));
-        while states.len() > 0 {
-            let (current_index, mut visited_caves) = states.pop().unwrap();
+        while let Some((current_index, mut visited_caves)) = states.pop() {
             match &self.caves[current_index] {
                 Cave {
                     cave_type: CaveType::End,
-                    cave_name,
-                    tunnel_to_cave_index,
+                    ..
                 } => {
                     possible_path_count += 1;
                 }
-                Cave {
-                    cave_type,
-                    cave_name,
-                    tunnel_to_cave_index,
-                } if *cave_type == CaveType::Small || *cave_type == CaveType::Start => {
+                Cave { cave_type, .. }
+                    if *cave_type == CaveType::Small || *cave_type == CaveType::Start =>
+                {
                     visited_caves[current_index] = true;
                     for &next_cave_index in &self.caves[current_index].tunnel_to_cave_index {
                         if !visited_caves[next_cave_index] {
@@ -111,8 +117,7 @@ impl CaveGraph {
                 }
                 Cave {
                     cave_type: CaveType::Big,
-                    cave_name,
-                    tunnel_to_cave_index,
+                    ..
                 } => {
                     for &next_cave_index in &self.caves[current_index].tunnel_to_cave_index {
                         if !visited_caves[next_cave_index] {
@@ -133,21 +138,17 @@ impl CaveGraph {
         // A state is the state of a node visit, with the index of the visited node, and history of already visited nodes (for small ones)
         let mut states = Vec::with_capacity(100);
         states.push((self.start_index, vec![false; self.caves.len()], false));
-        while states.len() > 0 {
-            let (current_index, mut visited_caves, twice_visit_done) = states.pop().unwrap();
+        while let Some((current_index, mut visited_caves, twice_visit_done)) = states.pop() {
             match &self.caves[current_index] {
                 Cave {
                     cave_type: CaveType::End,
-                    cave_name,
-                    tunnel_to_cave_index,
+                    ..
                 } => {
                     possible_path_count += 1;
                 }
-                Cave {
-                    cave_type,
-                    cave_name,
-                    tunnel_to_cave_index,
-                } if *cave_type == CaveType::Small || *cave_type == CaveType::Start => {
+                Cave { cave_type, .. }
+                    if *cave_type == CaveType::Small || *cave_type == CaveType::Start =>
+                {
                     if twice_visit_done {
                         visited_caves[current_index] = true;
                         for &next_cave_index in &self.caves[current_index].tunnel_to_cave_index {
@@ -170,8 +171,7 @@ impl CaveGraph {
                 }
                 Cave {
                     cave_type: CaveType::Big,
-                    cave_name,
-                    tunnel_to_cave_index,
+                    ..
                 } => {
                     if twice_visit_done {
                         for &next_cave_index in &self.caves[current_index].tunnel_to_cave_index {
@@ -202,6 +202,7 @@ impl CaveGraph {
 #[cfg(test)]
 mod tests {
     use crate::models::{Cave, CaveGraph, CaveType};
+    use aoc_common::ParseError;
 
     #[test]
     fn parse_example_case() {
@@ -341,4 +342,16 @@ b-end
 
         assert_eq!(36, input.find_all_paths_with_twice_small_visit_count());
     }
+
+    #[test]
+    fn parse_invalid_tunnels() {
+        assert_eq!(
+            CaveGraph::parse_string("start-A\nstart b\n".to_string()),
+            Err(ParseError::new(12, 2, 8, "`-`"))
+        );
+        assert_eq!(
+            CaveGraph::parse_string("start-A\nA-\n".to_string()),
+            Err(ParseError::new(12, 2, 3, "a cave name"))
+        );
+    }
 }
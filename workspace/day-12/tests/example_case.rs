@@ -0,0 +1,27 @@
+use aoc_common::{Answer, Solution};
+use day_12::models::CaveGraph;
+use day_12::Day12;
+
+const EXAMPLE: &str = include_str!("../dataset/example.txt");
+
+#[test]
+fn part_1_example_case() {
+    let input = CaveGraph::parse_string(EXAMPLE.to_string()).unwrap();
+
+    assert_eq!(input.find_all_paths_count(), 10);
+}
+
+#[test]
+fn part_2_example_case() {
+    let input = CaveGraph::parse_string(EXAMPLE.to_string()).unwrap();
+
+    assert_eq!(input.find_all_paths_with_twice_small_visit_count(), 36);
+}
+
+#[test]
+fn solution_example_case() {
+    let input = Day12::parse(EXAMPLE.to_string()).unwrap();
+
+    assert_eq!(Day12::part_1(&input), Answer::Number(10));
+    assert_eq!(Day12::part_2(&input), Answer::Number(36));
+}
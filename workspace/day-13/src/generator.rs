@@ -0,0 +1,91 @@
+use aoc_common::Rng;
+use std::collections::HashSet;
+use std::fmt::Write;
+
+/// Size of the code revealed once the paper is folded, as in the puzzle inputs
+const CODE_WIDTH: i32 = 40;
+const CODE_HEIGHT: i32 = 6;
+
+/// Number of folds along each axis
+const X_FOLDS: usize = 5;
+const Y_FOLDS: usize = 6;
+
+/// Generates a sheet of `size` dots (at least the dots of the code) revealing a random code once
+/// folded. The sheet is made by unfolding the code, each dot landing on either side of the fold.
+pub fn generate(size: usize, rng: &mut Rng) -> String {
+    let mut code = Vec::new();
+    for y in 0..CODE_HEIGHT {
+        for x in 0..CODE_WIDTH {
+            if rng.chance(0.5) {
+                code.push((x, y));
+            }
+        }
+    }
+    if code.is_empty() {
+        code.push((0, 0));
+    }
+
+    // Folds in the order of the instructions, found by unfolding the code from the last one
+    let mut axes = [vec!['x'; X_FOLDS], vec!['y'; Y_FOLDS]].concat();
+    rng.shuffle(&mut axes);
+    let (mut width, mut height) = (CODE_WIDTH, CODE_HEIGHT);
+    let mut folds = Vec::new();
+    for &axis in axes.iter().rev() {
+        let size = if axis == 'x' { &mut width } else { &mut height };
+        folds.push((axis, *size));
+        *size = 2 * *size + 1;
+    }
+    folds.reverse();
+
+    let mut dots = HashSet::new();
+    let mut attempts = 0;
+    while attempts < code.len() || (dots.len() < size && attempts < 10 * size) {
+        let (mut x, mut y) = code[attempts % code.len()];
+        for &(axis, line) in folds.iter().rev() {
+            if rng.chance(0.5) {
+                match axis {
+                    'x' => x = 2 * line - x,
+                    _ => y = 2 * line - y,
+                }
+            }
+        }
+        dots.insert((x, y));
+        attempts += 1;
+    }
+
+    let mut dots = dots.into_iter().collect::<Vec<_>>();
+    dots.sort();
+    rng.shuffle(&mut dots);
+    let mut content = String::new();
+    for (x, y) in dots {
+        writeln!(content, "{},{}", x, y).unwrap();
+    }
+    content.push('\n');
+    for (axis, line) in folds {
+        writeln!(content, "fold along {}={}", axis, line).unwrap();
+    }
+
+    content
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::generator::generate;
+    use crate::Day13;
+    use aoc_common::{Answer, Rng, Solution};
+
+    #[test]
+    fn generate_valid_input() {
+        for seed in 0..5 {
+            let input = Day13::parse(generate(800, &mut Rng::new(seed))).unwrap();
+
+            assert_eq!(input.points.len(), 800);
+            assert_eq!(input.foldings.len(), 11);
+            let Answer::Text(code) = Day13::part_2(&input) else {
+                panic!("expected a drawn code");
+            };
+            assert!(code.lines().count() <= 6);
+            assert!(code.lines().all(|line| line.len() <= 40));
+        }
+    }
+}
@@ -0,0 +1,40 @@
+use crate::models::TransparentSheet;
+use aoc_common::{Answer, FrameSink, Generator, ParseError, Rng, Solution, Visualize};
+use std::io;
+
+pub mod generator;
+pub mod models;
+pub mod visualization;
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    const DAY: u8 = 13;
+    type Input = TransparentSheet;
+
+    fn parse(content: String) -> Result<TransparentSheet, ParseError> {
+        TransparentSheet::parse_string(content)
+    }
+
+    fn part_1(input: &TransparentSheet) -> Answer {
+        input.count_fold_once().into()
+    }
+
+    fn part_2(input: &TransparentSheet) -> Answer {
+        input.fold_and_render().into()
+    }
+}
+
+impl Generator for Day13 {
+    const DEFAULT_SIZE: usize = 800;
+
+    fn generate(size: usize, rng: &mut Rng) -> String {
+        generator::generate(size, rng)
+    }
+}
+
+impl Visualize for Day13 {
+    fn visualize(input: &TransparentSheet, frames: &mut dyn FrameSink) -> io::Result<()> {
+        visualization::visualize(input, frames)
+    }
+}
@@ -1,5 +1,6 @@
+use crate::Day13;
+use aoc_common::{input_lines, ParseError, Solution};
 use std::collections::{HashSet, VecDeque};
-use std::error::Error;
 
 #[derive(Debug, Clone, Default, Eq, PartialEq)]
 pub struct TransparentSheet {
@@ -20,32 +21,35 @@ pub enum Folding {
 }
 
 impl TransparentSheet {
-    pub fn parse_string(content: String) -> Result<TransparentSheet, Box<dyn Error>> {
+    pub fn parse_string(content: String) -> Result<TransparentSheet, ParseError> {
         let mut transparent_sheet = TransparentSheet::default();
 
         let mut folding_parsing = false;
-        for line in content.lines() {
+        for line in input_lines(Day13::DAY, &content) {
             if !folding_parsing {
-                if line.is_empty() {
+                if line.text.is_empty() {
                     folding_parsing = true;
                 } else {
-                    let parts = line
-                        .split(',')
-                        .map(|n| n.parse::<i32>())
-                        .collect::<Result<Vec<_>, _>>()?;
+                    let (x, y) = line
+                        .text
+                        .split_once(',')
+                        .ok_or_else(|| line.error_at_end("`,`"))?;
                     transparent_sheet.points.insert(Point {
-                        x: parts[0],
-                        y: parts[1],
+                        x: line.parse(x, "a number")?,
+                        y: line.parse(y, "a number")?,
                     });
                 }
-            } else {
-                let parts = line[11..].split('=').collect::<Vec<_>>();
-                let value = parts[1].parse::<i32>()?;
+            } else if !line.text.is_empty() {
+                let folding = line.strip_prefix("fold along ")?;
+                let (axis, value) = folding
+                    .split_once('=')
+                    .ok_or_else(|| line.error_at_end("`=`"))?;
+                let value = line.parse::<i32>(value, "a number")?;
 
-                if parts[0] == "x" {
-                    transparent_sheet.foldings.push_back(Folding::Left(value));
-                } else {
-                    transparent_sheet.foldings.push_back(Folding::Up(value));
+                match axis {
+                    "x" => transparent_sheet.foldings.push_back(Folding::Left(value)),
+                    "y" => transparent_sheet.foldings.push_back(Folding::Up(value)),
+                    _ => return Err(line.error(axis, "`x` or `y`")),
                 }
             }
         }
@@ -61,7 +65,8 @@ impl TransparentSheet {
         sheet.points.len()
     }
 
-    fn fold(&mut self) -> Result<(), Box<dyn Error>> {
+    /// Applies the next folding, if any
+    pub(crate) fn fold(&mut self) {
         let fold = self.foldings.pop_front();
 
         let mut updated_points = HashSet::new();
@@ -94,20 +99,18 @@ impl TransparentSheet {
             }
             None => {}
         }
-
-        Ok(())
     }
 
-    pub fn fold_and_print(&self) {
+    pub fn fold_and_render(&self) -> String {
         let mut sheet = self.clone();
-        while sheet.foldings.len() > 0 {
+        while !sheet.foldings.is_empty() {
             sheet.fold();
         }
 
-        sheet.print();
+        sheet.render()
     }
 
-    fn print(&self) {
+    fn render(&self) -> String {
         let mut width = 0;
         let mut height = 0;
 
@@ -122,18 +125,18 @@ impl TransparentSheet {
         }
 
         // Display
-        for y in 0..height {
-            for x in 0..width {
-                print!("{}", pixels[y * width + x]);
-            }
-            println!();
-        }
+        pixels
+            .chunks(width)
+            .map(|row| row.iter().collect::<String>())
+            .collect::<Vec<_>>()
+            .join("\n")
     }
 }
 
 #[cfg(test)]
 mod tests {
     use crate::models::{Folding, Point, TransparentSheet};
+    use aoc_common::ParseError;
     use std::collections::{HashSet, VecDeque};
 
     #[test]
@@ -194,7 +197,7 @@ fold along x=5
 
     #[test]
     fn part_1_example_case() {
-        let mut input = TransparentSheet {
+        let input = TransparentSheet {
             points: HashSet::<_>::from_iter([
                 Point { x: 6, y: 10 },
                 Point { x: 0, y: 14 },
@@ -219,7 +222,57 @@ fold along x=5
         };
 
         assert_eq!(input.count_fold_once(), 17);
+    }
+
+    #[test]
+    fn part_2_example_case() {
+        let input = TransparentSheet {
+            points: HashSet::<_>::from_iter([
+                Point { x: 6, y: 10 },
+                Point { x: 0, y: 14 },
+                Point { x: 9, y: 10 },
+                Point { x: 0, y: 3 },
+                Point { x: 10, y: 4 },
+                Point { x: 4, y: 11 },
+                Point { x: 6, y: 0 },
+                Point { x: 6, y: 12 },
+                Point { x: 4, y: 1 },
+                Point { x: 0, y: 13 },
+                Point { x: 10, y: 12 },
+                Point { x: 3, y: 4 },
+                Point { x: 3, y: 0 },
+                Point { x: 8, y: 4 },
+                Point { x: 1, y: 10 },
+                Point { x: 2, y: 14 },
+                Point { x: 8, y: 10 },
+                Point { x: 9, y: 0 },
+            ]),
+            foldings: VecDeque::from_iter([Folding::Up(7), Folding::Left(5)]),
+        };
+
+        assert_eq!(
+            input.fold_and_render(),
+            "#####
+#...#
+#...#
+#...#
+#####"
+        );
+    }
 
-        input.fold_and_print();
+    #[test]
+    fn parse_invalid_instructions() {
+        assert_eq!(
+            TransparentSheet::parse_string("6,10\n0,x\n".to_string()),
+            Err(ParseError::new(13, 2, 3, "a number"))
+        );
+        assert_eq!(
+            TransparentSheet::parse_string("6,10\n\nfold along z=7\n".to_string()),
+            Err(ParseError::new(13, 3, 12, "`x` or `y`"))
+        );
+        assert_eq!(
+            TransparentSheet::parse_string("6,10\n\nfold y=7\n".to_string()),
+            Err(ParseError::new(13, 3, 1, "`fold along`"))
+        );
     }
 }
@@ -0,0 +1,57 @@
+use crate::models::TransparentSheet;
+use aoc_common::{FrameSink, Grid};
+use std::io;
+
+/// Shows the dots of the sheet before and after each folding. All the frames have the size of the
+/// unfolded sheet, so the dots gather in its top left corner as it gets folded.
+pub fn visualize(sheet: &TransparentSheet, frames: &mut dyn FrameSink) -> io::Result<()> {
+    let width = sheet
+        .points
+        .iter()
+        .map(|point| point.x + 1)
+        .max()
+        .unwrap_or(0);
+    let height = sheet
+        .points
+        .iter()
+        .map(|point| point.y + 1)
+        .max()
+        .unwrap_or(0);
+    let mut sheet = sheet.clone();
+
+    loop {
+        let mut frame = Grid::filled(width.max(0) as usize, height.max(0) as usize, 0);
+        for point in &sheet.points {
+            if point.x >= 0 && point.y >= 0 {
+                frame[(point.x as usize, point.y as usize)] = 255;
+            }
+        }
+        frames.frame(&frame)?;
+
+        if sheet.foldings.is_empty() {
+            return Ok(());
+        }
+        sheet.fold();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::models::TransparentSheet;
+    use crate::visualization::visualize;
+
+    #[test]
+    fn visualize_example_case() {
+        let content = include_str!("../dataset/example.txt").to_string();
+        let sheet = TransparentSheet::parse_string(content).unwrap();
+        let mut frames = Vec::new();
+        visualize(&sheet, &mut frames).unwrap();
+
+        assert_eq!(frames.len(), 3);
+        assert_eq!((frames[0].width(), frames[0].height()), (11, 15));
+        let dots = frames
+            .iter()
+            .map(|frame| frame.values().filter(|&&level| level == 255).count());
+        assert_eq!(dots.collect::<Vec<_>>(), vec![18, 17, 16]);
+    }
+}
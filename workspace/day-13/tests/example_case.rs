@@ -0,0 +1,29 @@
+use aoc_common::{Answer, Solution};
+use day_13::models::TransparentSheet;
+use day_13::Day13;
+
+const EXAMPLE: &str = include_str!("../dataset/example.txt");
+
+const SQUARE: &str = "#####\n#...#\n#...#\n#...#\n#####";
+
+#[test]
+fn part_1_example_case() {
+    let input = TransparentSheet::parse_string(EXAMPLE.to_string()).unwrap();
+
+    assert_eq!(input.count_fold_once(), 17);
+}
+
+#[test]
+fn part_2_example_case() {
+    let input = TransparentSheet::parse_string(EXAMPLE.to_string()).unwrap();
+
+    assert_eq!(input.fold_and_render(), SQUARE);
+}
+
+#[test]
+fn solution_example_case() {
+    let input = Day13::parse(EXAMPLE.to_string()).unwrap();
+
+    assert_eq!(Day13::part_1(&input), Answer::Number(17));
+    assert_eq!(Day13::part_2(&input), Answer::from(SQUARE.to_string()));
+}
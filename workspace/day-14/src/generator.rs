@@ -0,0 +1,42 @@
+use aoc_common::Rng;
+use std::fmt::Write;
+
+/// Number of distinct elements, as in the puzzle inputs
+const ELEMENT_COUNT: usize = 10;
+
+/// Generates a polymer template of `size` elements, with an insertion rule for every pair of
+/// elements
+pub fn generate(size: usize, rng: &mut Rng) -> String {
+    let mut letters = ('A'..='Z').collect::<Vec<_>>();
+    rng.shuffle(&mut letters);
+    let elements = &letters[..ELEMENT_COUNT];
+
+    let mut content = (0..size.max(2))
+        .map(|_| *rng.choose(elements))
+        .collect::<String>();
+    content.push_str("\n\n");
+    for &first in elements {
+        for &second in elements {
+            writeln!(content, "{}{} -> {}", first, second, rng.choose(elements)).unwrap();
+        }
+    }
+
+    content
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::generator::generate;
+    use crate::Day14;
+    use aoc_common::{Rng, Solution};
+
+    #[test]
+    fn generate_valid_input() {
+        let input = Day14::parse(generate(20, &mut Rng::new(1))).unwrap();
+
+        assert_eq!(input.template.len(), 20);
+        assert_eq!(input.elements.len(), 10);
+        assert_eq!(input.pairs.len(), 100);
+        Day14::part_2(&input);
+    }
+}
@@ -0,0 +1,32 @@
+use crate::models::Polymerization;
+use aoc_common::{Answer, Generator, ParseError, Rng, Solution};
+
+pub mod generator;
+pub mod models;
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    const DAY: u8 = 14;
+    type Input = Polymerization;
+
+    fn parse(content: String) -> Result<Polymerization, ParseError> {
+        Polymerization::parse_string(content)
+    }
+
+    fn part_1(input: &Polymerization) -> Answer {
+        input.polymerize(10).into()
+    }
+
+    fn part_2(input: &Polymerization) -> Answer {
+        input.polymerize(40).into()
+    }
+}
+
+impl Generator for Day14 {
+    const DEFAULT_SIZE: usize = 20;
+
+    fn generate(size: usize, rng: &mut Rng) -> String {
+        generator::generate(size, rng)
+    }
+}
@@ -1,5 +1,6 @@
-use std::collections::{HashMap, HashSet};
-use std::error::Error;
+use std::collections::{HashMap, HashSet, VecDeque};
+use aoc_common::{input_lines, ParseError, Solution};
+use crate::Day14;
 
 #[derive(Debug, Clone, Default, Eq, PartialEq)]
 pub struct Polymerization {
@@ -9,22 +10,61 @@ pub struct Polymerization {
 }
 
 impl Polymerization {
-    pub fn parse_string(content: String) -> Result<Polymerization, Box<dyn Error>> {
+    pub fn parse_string(content: String) -> Result<Polymerization, ParseError> {
         let mut polymerization = Polymerization::default();
 
-        let lines = content.lines().collect::<Vec<_>>();
+        let mut lines = input_lines(Day14::DAY, &content);
 
-        polymerization.template = lines[0].to_string();
-        for (index, c) in lines[0].chars().enumerate() {
+        let template = lines.next()
+            .filter(|line| !line.text.is_empty())
+            .ok_or_else(|| ParseError::new(Day14::DAY, 1, 1, "the polymer template"))?;
+        polymerization.template = template.text.to_string();
+        for c in template.text.chars() {
             polymerization.elements.insert(c);
         }
 
+        if let Some(line) = lines.next() {
+            if !line.text.is_empty() {
+                return Err(line.error(line.text, "an empty line"));
+            }
+        }
+
+        for line in lines.filter(|line| !line.text.is_empty()) {
+            let (pair, element) = line.text.split_once(" -> ")
+                .ok_or_else(|| line.error_at_end("` -> `"))?;
+            if pair.chars().count() != 2 {
+                return Err(line.error(pair, "a pair of elements"));
+            }
+            let mut element_chars = element.chars();
+            let element = match (element_chars.next(), element_chars.next()) {
+                (Some(element), None) => element,
+                _ => return Err(line.error(element, "a single element")),
+            };
+
+            polymerization.pairs.insert(pair.to_string(), element);
+            polymerization.elements.extend(pair.chars());
+            polymerization.elements.insert(element);
+        }
 
-        for &line in lines[2..].iter() {
-            polymerization.pairs.insert(line[0..2].to_string(), line.chars().nth(6).unwrap());
-            polymerization.elements.insert(line.chars().nth(0).unwrap());
-            polymerization.elements.insert(line.chars().nth(1).unwrap());
-            polymerization.elements.insert(line.chars().nth(6).unwrap());
+        // Every pair of the polymer must have a rule, including the pairs inserted by the rules
+        let mut pairs = VecDeque::new();
+        let mut seen = HashSet::new();
+        for (offset, first) in template.text.char_indices() {
+            if let Some(second) = template.text[offset + first.len_utf8()..].chars().next() {
+                pairs.push_back((format!("{}{}", first, second), offset));
+            }
+        }
+        while let Some((pair, offset)) = pairs.pop_front() {
+            if !seen.insert(pair.clone()) {
+                continue;
+            }
+            let element = *polymerization.pairs.get(&pair)
+                .ok_or_else(|| template.error_at(offset, format!("an insertion rule for `{}`", pair)))?;
+
+            let mut chars = pair.chars();
+            let (first, second) = (chars.next().unwrap(), chars.next().unwrap());
+            pairs.push_back((format!("{}{}", first, element), offset));
+            pairs.push_back((format!("{}{}", element, second), offset));
         }
 
         Ok(polymerization)
@@ -44,7 +84,7 @@ impl Polymerization {
         }
 
         for i in 1..self.template.len() {
-            match pair_counts.get_mut(&self.template[i-1..=i].to_string()) {
+            match pair_counts.get_mut(&self.template[i-1..=i]) {
                 Some(n) => {
                     *n += 1;
                 }
@@ -54,9 +94,7 @@ impl Polymerization {
             }
         }
 
-        for step in 0..steps {
-            eprintln!("step {}", step);
-
+        for _step in 0..steps {
             let old_pairs = pair_counts.clone();
 
             for (pair, &count) in &old_pairs {
@@ -113,6 +151,7 @@ impl Polymerization {
 mod tests {
     use std::collections::{HashMap, HashSet};
     use crate::models::{Polymerization};
+    use aoc_common::ParseError;
 
     #[test]
     fn parse_example_case() {
@@ -224,4 +263,37 @@ CN -> C
 
         assert_eq!(input.polymerize(40), 2188189693529);
     }
+
+    #[test]
+    fn parse_invalid_rules() {
+        assert_eq!(
+            Polymerization::parse_string("NNCB\n\nCH -> B\nHH => N\n".to_string()),
+            Err(ParseError::new(14, 4, 8, "` -> `"))
+        );
+        assert_eq!(
+            Polymerization::parse_string("NNCB\n\nCHH -> B\n".to_string()),
+            Err(ParseError::new(14, 3, 1, "a pair of elements"))
+        );
+        assert_eq!(
+            Polymerization::parse_string("NNCB\n\nCH -> BN\n".to_string()),
+            Err(ParseError::new(14, 3, 7, "a single element"))
+        );
+    }
+
+    #[test]
+    fn parse_missing_rules() {
+        assert_eq!(
+            Polymerization::parse_string("abc,def\n\n".to_string()),
+            Err(ParseError::new(14, 1, 1, "an insertion rule for `ab`"))
+        );
+        assert_eq!(
+            Polymerization::parse_string("NNC\n\nNN -> C\n".to_string()),
+            Err(ParseError::new(14, 1, 2, "an insertion rule for `NC`"))
+        );
+        // CN is only produced by the insertion of N between C and C
+        assert_eq!(
+            Polymerization::parse_string("NCC\n\nNC -> C\nCC -> N\nNN -> N\n".to_string()),
+            Err(ParseError::new(14, 1, 2, "an insertion rule for `CN`"))
+        );
+    }
 }
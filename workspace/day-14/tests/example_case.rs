@@ -0,0 +1,27 @@
+use aoc_common::{Answer, Solution};
+use day_14::models::Polymerization;
+use day_14::Day14;
+
+const EXAMPLE: &str = include_str!("../dataset/example.txt");
+
+#[test]
+fn part_1_example_case() {
+    let input = Polymerization::parse_string(EXAMPLE.to_string()).unwrap();
+
+    assert_eq!(input.polymerize(10), 1588);
+}
+
+#[test]
+fn part_2_example_case() {
+    let input = Polymerization::parse_string(EXAMPLE.to_string()).unwrap();
+
+    assert_eq!(input.polymerize(40), 2188189693529);
+}
+
+#[test]
+fn solution_example_case() {
+    let input = Day14::parse(EXAMPLE.to_string()).unwrap();
+
+    assert_eq!(Day14::part_1(&input), Answer::Number(1588));
+    assert_eq!(Day14::part_2(&input), Answer::Number(2188189693529));
+}
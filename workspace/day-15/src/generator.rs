@@ -0,0 +1,33 @@
+use aoc_common::Rng;
+
+/// Generates a `size` × `size` map of risk levels from 1 to 9
+pub fn generate(size: usize, rng: &mut Rng) -> String {
+    let size = size.max(1);
+    let mut content = String::with_capacity((size + 1) * size);
+    for _ in 0..size {
+        for _ in 0..size {
+            content.push(char::from_digit(rng.range(1..=9) as u32, 10).unwrap());
+        }
+        content.push('\n');
+    }
+
+    content
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::generator::generate;
+    use crate::Day15;
+    use aoc_common::{Answer, Rng, Solution};
+
+    #[test]
+    fn generate_valid_input() {
+        let input = Day15::parse(generate(20, &mut Rng::new(1))).unwrap();
+
+        // The path along the edges costs at most 9 per step
+        let Answer::Number(risk) = Day15::part_1(&input) else {
+            panic!("expected a number");
+        };
+        assert!((38..=9 * 38).contains(&risk));
+    }
+}
@@ -0,0 +1,32 @@
+use crate::models::ChitonDensityMap;
+use aoc_common::{Answer, Generator, ParseError, Rng, Solution};
+
+pub mod generator;
+pub mod models;
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    const DAY: u8 = 15;
+    type Input = ChitonDensityMap;
+
+    fn parse(content: String) -> Result<ChitonDensityMap, ParseError> {
+        ChitonDensityMap::parse_string(content)
+    }
+
+    fn part_1(input: &ChitonDensityMap) -> Answer {
+        input.shortest_path_score().into()
+    }
+
+    fn part_2(input: &ChitonDensityMap) -> Answer {
+        input.shortest_path_score_5x().into()
+    }
+}
+
+impl Generator for Day15 {
+    const DEFAULT_SIZE: usize = 100;
+
+    fn generate(size: usize, rng: &mut Rng) -> String {
+        generator::generate(size, rng)
+    }
+}
@@ -1,87 +1,52 @@
 use std::cmp::Ordering;
 use std::collections::BinaryHeap;
-use std::error::Error;
+use aoc_common::{input_lines, Grid, ParseError, Solution};
+use crate::Day15;
 
-#[derive(Debug, Clone, Default, Eq, PartialEq)]
+#[derive(Debug, Clone, Eq, PartialEq)]
 pub struct ChitonDensityMap {
-    map: Vec<usize>,
-    width: usize,
-    height: usize
+    risk_levels: Grid<u8>
 }
 
 impl ChitonDensityMap {
-    pub fn parse_string(content: String) -> Result<ChitonDensityMap, Box<dyn Error>> {
-        let mut density_map = ChitonDensityMap::default();
+    pub fn parse_string(content: String) -> Result<ChitonDensityMap, ParseError> {
+        let lines = input_lines(Day15::DAY, &content).filter(|line| !line.text.is_empty());
+        let risk_levels = Grid::parse_digits(lines, "risk levels")?;
 
-        let lines = content.lines().collect::<Vec<_>>();
-        density_map.width = lines[0].len();
-        density_map.height = lines.len();
-
-        density_map.map = lines
-            .iter()
-            .flat_map(|&line| {
-                line
-                    .chars()
-                    .map(|c| (c as u8 - '0' as u8) as usize)
-            }).collect::<Vec<_>>();
+        if risk_levels.height() == 0 {
+            return Err(ParseError::new(Day15::DAY, 1, 1, "risk levels"));
+        }
 
-        Ok(density_map)
+        Ok(ChitonDensityMap { risk_levels })
     }
 
     pub fn shortest_path_score(&self) -> usize {
-        let mut costs = vec![usize::MAX; self.width * self.height];
-        costs[0] = 0;
+        let mut costs = Grid::filled(self.risk_levels.width(), self.risk_levels.height(), usize::MAX);
+        costs[(0, 0)] = 0;
         let mut queue = Vec::with_capacity(100);
 
         queue.push((0, 0));
 
-        while queue.len() > 0 {
-            let (x, y) = queue.pop().unwrap();
-            let current_cost = costs[y * self.width + x];
-
-            // Left
-            if x > 0 {
-                let target_cost = costs[y * self.width + x - 1];
-                if target_cost == usize::MAX || current_cost + self.map[y * self.width + x - 1] < target_cost {
-                    costs[y * self.width + x - 1] = current_cost + self.map[y * self.width + x - 1];
-                    queue.push((x - 1, y));
-                }
-            }
-
-            // Right
-            if x < self.width - 1 {
-                let target_cost = costs[y * self.width + x + 1];
-                if target_cost == usize::MAX || current_cost + self.map[y * self.width + x + 1] < target_cost {
-                    costs[y * self.width + x + 1] = current_cost + self.map[y * self.width + x + 1];
-                    queue.push((x + 1, y));
-                }
-            }
+        while let Some(position) = queue.pop() {
+            let current_cost = costs[position];
 
-            // Top
-            if y > 0 {
-                let target_cost = costs[(y - 1) * self.width + x];
-                if target_cost == usize::MAX || current_cost + self.map[(y - 1) * self.width + x] < target_cost {
-                    costs[(y - 1) * self.width + x] = current_cost + self.map[(y - 1) * self.width + x];
-                    queue.push((x, y - 1));
-                }
-            }
-
-            // Bottom
-            if y < self.height - 1 {
-                let target_cost = costs[(y + 1) * self.width + x];
-                if target_cost == usize::MAX || current_cost + self.map[(y + 1) * self.width + x] < target_cost {
-                    costs[(y + 1) * self.width + x] = current_cost + self.map[(y + 1) * self.width + x];
-                    queue.push((x, y + 1));
+            costs.visit_neighbours_4(position, |costs, neighbour| {
+                let target_cost = costs[neighbour];
+                let cell_cost = self.risk_levels[neighbour] as usize;
+                if target_cost == usize::MAX || current_cost + cell_cost < target_cost {
+                    costs[neighbour] = current_cost + cell_cost;
+                    queue.push(neighbour);
                 }
-            }
+            });
         }
 
-        costs[costs.len() - 1]
+        costs[(costs.width() - 1, costs.height() - 1)]
     }
 
     pub fn shortest_path_score_5x(&self) -> usize {
-        let mut costs = vec![usize::MAX; (5 * self.width) * (5 * self.height)];
-        costs[0] = 0;
+        let mut costs = Grid::filled(5 * self.risk_levels.width(), 5 * self.risk_levels.height(), usize::MAX);
+        let end = (costs.width() - 1, costs.height() - 1);
+        costs[(0, 0)] = 0;
         let mut queue = BinaryHeap::new();
 
         queue.push(State {
@@ -90,81 +55,36 @@ impl ChitonDensityMap {
             cost: 0
         });
 
-        while queue.len() > 0 {
-            let state = queue.pop().unwrap();
-            let x = state.x;
-            let y = state.y;
-            let current_cost = costs[y * 5 * self.width + x];
+        while let Some(state) = queue.pop() {
+            let position = (state.x, state.y);
+            let current_cost = costs[position];
 
-            if x == 5 * self.width - 1 && y == 5 * self.height - 1 {
+            if position == end {
                 break;
             }
 
-            // Left
-            if x > 0 {
-                let target_cost = costs[y * 5 * self.width + x - 1];
-                let cell_cost = self.get_5x_cost(x - 1, y);
-                if target_cost == usize::MAX || current_cost + cell_cost < target_cost {
-                    costs[y * 5 * self.width + x - 1] = current_cost + cell_cost;
-                    queue.push(State {
-                        x: x - 1,
-                        y: y,
-                        cost: current_cost + cell_cost
-                    });
-                }
-            }
-
-            // Right
-            if x < 5 * self.width - 1 {
-                let target_cost = costs[y * 5 * self.width + x + 1];
-                let cell_cost = self.get_5x_cost(x + 1, y);
-                if target_cost == usize::MAX || current_cost + cell_cost < target_cost {
-                    costs[y * 5 * self.width + x + 1] = current_cost + cell_cost;
-                    queue.push(State {
-                        x: x + 1,
-                        y: y,
-                        cost: current_cost + cell_cost
-                    });
-                }
-            }
-
-            // Top
-            if y > 0 {
-                let target_cost = costs[(y - 1) * 5 * self.width + x];
-                let cell_cost = self.get_5x_cost(x, y - 1);
+            costs.visit_neighbours_4(position, |costs, (x, y)| {
+                let target_cost = costs[(x, y)];
+                let cell_cost = self.get_5x_cost(x, y);
                 if target_cost == usize::MAX || current_cost + cell_cost < target_cost {
-                    costs[(y - 1) * 5 * self.width + x] = current_cost + cell_cost;
+                    costs[(x, y)] = current_cost + cell_cost;
                     queue.push(State {
-                        x: x,
-                        y: y - 1,
+                        x,
+                        y,
                         cost: current_cost + cell_cost
                     });
                 }
-            }
-
-            // Bottom
-            if y <  5 * self.height - 1 {
-                let target_cost = costs[(y + 1) * 5 * self.width + x];
-                let cell_cost = self.get_5x_cost(x, y + 1);
-                if target_cost == usize::MAX || current_cost + cell_cost < target_cost {
-                    costs[(y + 1) * 5 * self.width + x] = current_cost + cell_cost;
-                    queue.push(State {
-                        x: x,
-                        y: y + 1,
-                        cost: current_cost + cell_cost
-                    });
-                }
-            }
+            });
         }
 
-        costs[costs.len() - 1]
+        costs[end]
     }
 
     fn get_5x_cost(&self, x: usize, y: usize) -> usize {
-        let (xq, xm) = (x / self.width, x % self.width);
-        let (yq, ym) = (y / self.height, y % self.height);
+        let (xq, xm) = (x / self.risk_levels.width(), x % self.risk_levels.width());
+        let (yq, ym) = (y / self.risk_levels.height(), y % self.risk_levels.height());
 
-        let cost = self.map[ym * self.width + xm] + xq + yq;
+        let cost = self.risk_levels[(xm, ym)] as usize + xq + yq;
         if cost > 9 {
             cost % 10 + 1
         }
@@ -198,6 +118,7 @@ impl PartialOrd for State {
 #[cfg(test)]
 mod tests {
     use crate::ChitonDensityMap;
+    use aoc_common::{Grid, ParseError};
 
     #[test]
     fn parse_example_case() {
@@ -217,7 +138,7 @@ mod tests {
         let input = ChitonDensityMap::parse_string(content).unwrap();
 
         assert_eq!(input, ChitonDensityMap {
-            map: vec![
+            risk_levels: Grid::new(10, 10, vec![
                 1,1,6,3,7,5,1,7,4,2,
                 1,3,8,1,3,7,3,6,7,2,
                 2,1,3,6,5,1,1,3,2,8,
@@ -228,16 +149,14 @@ mod tests {
                 3,1,2,5,4,2,1,6,3,9,
                 1,2,9,3,1,3,8,5,2,1,
                 2,3,1,1,9,4,4,5,8,1,
-            ],
-            width: 10,
-            height: 10
+            ])
         });
     }
 
     #[test]
     fn part_1_example_case() {
         let input = ChitonDensityMap {
-            map: vec![
+            risk_levels: Grid::new(10, 10, vec![
                 1,1,6,3,7,5,1,7,4,2,
                 1,3,8,1,3,7,3,6,7,2,
                 2,1,3,6,5,1,1,3,2,8,
@@ -248,9 +167,7 @@ mod tests {
                 3,1,2,5,4,2,1,6,3,9,
                 1,2,9,3,1,3,8,5,2,1,
                 2,3,1,1,9,4,4,5,8,1,
-            ],
-            width: 10,
-            height: 10
+            ])
         };
 
         assert_eq!(input.shortest_path_score(), 40);
@@ -259,7 +176,7 @@ mod tests {
     #[test]
     fn part_2_example_case() {
         let input = ChitonDensityMap {
-            map: vec![
+            risk_levels: Grid::new(10, 10, vec![
                 1,1,6,3,7,5,1,7,4,2,
                 1,3,8,1,3,7,3,6,7,2,
                 2,1,3,6,5,1,1,3,2,8,
@@ -270,11 +187,21 @@ mod tests {
                 3,1,2,5,4,2,1,6,3,9,
                 1,2,9,3,1,3,8,5,2,1,
                 2,3,1,1,9,4,4,5,8,1,
-            ],
-            width: 10,
-            height: 10
+            ])
         };
 
         assert_eq!(input.shortest_path_score_5x(), 315);
     }
+
+    #[test]
+    fn parse_invalid_risk_levels() {
+        assert_eq!(
+            ChitonDensityMap::parse_string("1163\n13a1\n".to_string()),
+            Err(ParseError::new(15, 2, 3, "a digit"))
+        );
+        assert_eq!(
+            ChitonDensityMap::parse_string("1163\n138\n".to_string()),
+            Err(ParseError::new(15, 2, 4, "4 risk levels"))
+        );
+    }
 }
@@ -0,0 +1,102 @@
+use aoc_common::Rng;
+use day_15::models::ChitonDensityMap;
+
+/// Generates a grid of random risk levels of up to 8 × 8, as lines of digits
+fn random_risk_levels(rng: &mut Rng) -> Vec<Vec<usize>> {
+    let width = rng.range(1..=8) as usize;
+    let height = rng.range(1..=8) as usize;
+
+    (0..height)
+        .map(|_| (0..width).map(|_| rng.range(1..=9) as usize).collect())
+        .collect()
+}
+
+/// Tiles the grid 5 times in both directions, the risk levels increasing by 1 with each tile and
+/// wrapping back to 1 after 9
+fn tiled(risk_levels: &[Vec<usize>]) -> Vec<Vec<usize>> {
+    (0..5)
+        .flat_map(|tile_y| {
+            risk_levels.iter().map(move |row| {
+                (0..5)
+                    .flat_map(|tile_x| {
+                        row.iter()
+                            .map(move |risk| (risk + tile_x + tile_y - 1) % 9 + 1)
+                    })
+                    .collect()
+            })
+        })
+        .collect()
+}
+
+fn parse(risk_levels: &[Vec<usize>]) -> ChitonDensityMap {
+    let content = risk_levels
+        .iter()
+        .map(|row| row.iter().map(|risk| risk.to_string()).collect::<String>() + "\n")
+        .collect();
+
+    ChitonDensityMap::parse_string(content).unwrap()
+}
+
+/// Naive reference relaxing every position until none of the lowest total risks changes
+fn reference_lowest_risk(risk_levels: &[Vec<usize>]) -> usize {
+    let (width, height) = (risk_levels[0].len(), risk_levels.len());
+    let mut lowest = vec![vec![usize::MAX; width]; height];
+    lowest[0][0] = 0;
+
+    let mut changed = true;
+    while changed {
+        changed = false;
+        for y in 0..height {
+            for x in 0..width {
+                let neighbours = [
+                    (x.wrapping_sub(1), y),
+                    (x + 1, y),
+                    (x, y.wrapping_sub(1)),
+                    (x, y + 1),
+                ];
+                for (nx, ny) in neighbours {
+                    if nx < width && ny < height && lowest[ny][nx] != usize::MAX {
+                        let risk = lowest[ny][nx] + risk_levels[y][x];
+                        if risk < lowest[y][x] {
+                            lowest[y][x] = risk;
+                            changed = true;
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    lowest[height - 1][width - 1]
+}
+
+#[test]
+fn shortest_path_matches_reference() {
+    for seed in 0..200 {
+        let risk_levels = random_risk_levels(&mut Rng::new(seed));
+
+        assert_eq!(
+            parse(&risk_levels).shortest_path_score(),
+            reference_lowest_risk(&risk_levels),
+            "seed {}",
+            seed
+        );
+    }
+}
+
+#[test]
+fn tiled_shortest_path_matches_reference() {
+    for seed in 0..50 {
+        let risk_levels = random_risk_levels(&mut Rng::new(seed));
+        let tiled = tiled(&risk_levels);
+
+        let lowest_risk = parse(&risk_levels).shortest_path_score_5x();
+        assert_eq!(lowest_risk, reference_lowest_risk(&tiled), "seed {}", seed);
+        assert_eq!(
+            lowest_risk,
+            parse(&tiled).shortest_path_score(),
+            "seed {}",
+            seed
+        );
+    }
+}
@@ -0,0 +1,27 @@
+use aoc_common::{Answer, Solution};
+use day_15::models::ChitonDensityMap;
+use day_15::Day15;
+
+const EXAMPLE: &str = include_str!("../dataset/example.txt");
+
+#[test]
+fn part_1_example_case() {
+    let input = ChitonDensityMap::parse_string(EXAMPLE.to_string()).unwrap();
+
+    assert_eq!(input.shortest_path_score(), 40);
+}
+
+#[test]
+fn part_2_example_case() {
+    let input = ChitonDensityMap::parse_string(EXAMPLE.to_string()).unwrap();
+
+    assert_eq!(input.shortest_path_score_5x(), 315);
+}
+
+#[test]
+fn solution_example_case() {
+    let input = Day15::parse(EXAMPLE.to_string()).unwrap();
+
+    assert_eq!(Day15::part_1(&input), Answer::Number(40));
+    assert_eq!(Day15::part_2(&input), Answer::Number(315));
+}
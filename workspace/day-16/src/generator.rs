@@ -0,0 +1,147 @@
+use crate::models::{BITSPacket, BITSPacketHeader};
+use crate::part_2::compute_packet_value;
+use aoc_common::Rng;
+
+/// Packets are nested at most this deep, to keep the recursive parsing shallow
+const MAX_DEPTH: usize = 32;
+
+/// Products larger than this become sums, so that values never overflow
+const MAX_PRODUCT: u64 = 1 << 40;
+
+/// Generates a transmission of `size` packets, mixing every operator
+pub fn generate(size: usize, rng: &mut Rng) -> String {
+    let mut bits = Vec::new();
+    let packet = packet(size.max(1), 0, rng);
+    encode(&packet, &mut bits, rng);
+    while bits.len() % 4 != 0 {
+        bits.push(false);
+    }
+
+    let mut content = bits
+        .chunks(4)
+        .map(|nibble| {
+            let digit = nibble.iter().fold(0, |digit, &bit| digit * 2 + bit as u32);
+            char::from_digit(digit, 16).unwrap().to_ascii_uppercase()
+        })
+        .collect::<String>();
+    content.push('\n');
+    content
+}
+
+/// Random packet made of `size` packets, itself included
+fn packet(size: usize, depth: usize, rng: &mut Rng) -> BITSPacket {
+    let version = rng.below(8) as u8;
+    if size == 1 || depth == MAX_DEPTH {
+        return BITSPacket::LiteralPacket {
+            header: BITSPacketHeader {
+                version,
+                packet_type: 4,
+            },
+            value: rng.range(0..=4095) as u64,
+        };
+    }
+
+    // Comparisons have exactly 2 sub-packets, other operators up to 5
+    let mut packet_type = *rng.choose(if size >= 3 {
+        &[0, 1, 2, 3, 5, 6, 7][..]
+    } else {
+        &[0, 1, 2, 3][..]
+    });
+    let sub_packet_count = if packet_type >= 5 {
+        2
+    } else {
+        rng.range(1..=(size as i64 - 1).min(5)) as usize
+    };
+    let mut sub_packet_sizes = vec![1; sub_packet_count];
+    for _ in sub_packet_count..size - 1 {
+        sub_packet_sizes[rng.below(sub_packet_count)] += 1;
+    }
+    let sub_packets = sub_packet_sizes
+        .into_iter()
+        .map(|size| packet(size, depth + 1, rng))
+        .collect::<Vec<_>>();
+
+    if packet_type == 1 {
+        let product = sub_packets.iter().try_fold(1u64, |product, packet| {
+            product.checked_mul(compute_packet_value(packet))
+        });
+        if !matches!(product, Some(product) if product <= MAX_PRODUCT) {
+            packet_type = 0;
+        }
+    }
+
+    BITSPacket::OperatorPacket {
+        header: BITSPacketHeader {
+            version,
+            packet_type,
+        },
+        sub_packets,
+    }
+}
+
+/// Appends the bits of the packet, with a random length type for operators
+fn encode(packet: &BITSPacket, bits: &mut Vec<bool>, rng: &mut Rng) {
+    let push = |bits: &mut Vec<bool>, value: u64, bit_count: usize| {
+        bits.extend((0..bit_count).rev().map(|i| value >> i & 1 == 1));
+    };
+
+    match packet {
+        BITSPacket::LiteralPacket { header, value } => {
+            push(bits, header.version as u64, 3);
+            push(bits, header.packet_type as u64, 3);
+            let nibble_count = (u64::BITS - value.leading_zeros()).div_ceil(4).max(1) as usize;
+            for i in (0..nibble_count).rev() {
+                push(bits, (i > 0) as u64, 1);
+                push(bits, value >> (4 * i) & 0xf, 4);
+            }
+        }
+        BITSPacket::OperatorPacket {
+            header,
+            sub_packets,
+        } => {
+            push(bits, header.version as u64, 3);
+            push(bits, header.packet_type as u64, 3);
+            let mut sub_bits = Vec::new();
+            for sub_packet in sub_packets {
+                encode(sub_packet, &mut sub_bits, rng);
+            }
+            // The total length only fits on 15 bits, the number of sub-packets always fits on 11
+            if sub_bits.len() < 1 << 15 && rng.chance(0.5) {
+                push(bits, 0, 1);
+                push(bits, sub_bits.len() as u64, 15);
+            } else {
+                push(bits, 1, 1);
+                push(bits, sub_packets.len() as u64, 11);
+            }
+            bits.extend(sub_bits);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::generator::generate;
+    use crate::models::BITSPacket;
+    use crate::Day16;
+    use aoc_common::{Rng, Solution};
+
+    fn packet_count(packet: &BITSPacket) -> usize {
+        match packet {
+            BITSPacket::LiteralPacket { .. } => 1,
+            BITSPacket::OperatorPacket { sub_packets, .. } => {
+                1 + sub_packets.iter().map(packet_count).sum::<usize>()
+            }
+        }
+    }
+
+    #[test]
+    fn generate_valid_input() {
+        for seed in 0..10 {
+            let input = Day16::parse(generate(300, &mut Rng::new(seed))).unwrap();
+
+            assert_eq!(packet_count(&input), 300);
+            Day16::part_1(&input);
+            Day16::part_2(&input);
+        }
+    }
+}
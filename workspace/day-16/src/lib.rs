@@ -0,0 +1,34 @@
+use crate::models::BITSPacket;
+use aoc_common::{Answer, Generator, ParseError, Rng, Solution};
+
+pub mod generator;
+pub mod models;
+pub mod part_1;
+pub mod part_2;
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    const DAY: u8 = 16;
+    type Input = BITSPacket;
+
+    fn parse(content: String) -> Result<BITSPacket, ParseError> {
+        BITSPacket::parse_string(content)
+    }
+
+    fn part_1(input: &BITSPacket) -> Answer {
+        part_1::sum_header_versions(input).into()
+    }
+
+    fn part_2(input: &BITSPacket) -> Answer {
+        part_2::compute_packet_value(input).into()
+    }
+}
+
+impl Generator for Day16 {
+    const DEFAULT_SIZE: usize = 300;
+
+    fn generate(size: usize, rng: &mut Rng) -> String {
+        generator::generate(size, rng)
+    }
+}
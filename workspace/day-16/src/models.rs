@@ -1,5 +1,6 @@
-use std::error::Error;
+use aoc_common::{input_lines, ParseError, Solution};
 use crate::BITSPacket::LiteralPacket;
+use crate::Day16;
 
 #[derive(Debug, Clone, Default, Eq, PartialEq)]
 pub struct BITSPacketHeader {
@@ -25,35 +26,33 @@ struct RawData {
 }
 
 impl BITSPacket {
-    pub fn parse_string(content: String) -> Result<BITSPacket, Box<dyn Error>> {
-        // Transform the message in raw u64 values
-        let lines = content.split_ascii_whitespace().collect::<Vec<_>>();
-        let u64_count = lines[0].len() / 16 + 1;
-        let raw_data = (0..u64_count).map(|i| {
-            let slice_start_index = i * 16;
-            if slice_start_index + 16 > lines[0].len() {
-                let mut slice = lines[0][slice_start_index..].to_string();
-
-                for _i in 0..slice_start_index + 16 - lines[0].len() {
-                    slice.push('0');
-                }
-                u64::from_str_radix(&slice, 16)
-            }
-            else {
-                u64::from_str_radix(&lines[0][slice_start_index..slice_start_index + 16], 16)
-            }
+    pub fn parse_string(content: String) -> Result<BITSPacket, ParseError> {
+        let line = input_lines(Day16::DAY, &content).next()
+            .ok_or_else(|| ParseError::new(Day16::DAY, 1, 1, "a hexadecimal transmission"))?;
+        let transmission = line.text.trim();
+        let digits = transmission.char_indices().map(|(offset, c)| {
+            c.to_digit(16)
+                .map(u64::from)
+                .ok_or_else(|| line.error(&transmission[offset..], "a hexadecimal digit"))
         }).collect::<Result<Vec<_>, _>>()?;
 
-        let (packet, _) = parse_packet(&RawData { data: raw_data, bit_count: lines[0].len() * 4 }, 0);
+        // Transform the message in raw u64 values, padded with zeros
+        let raw_data = digits.chunks(16).map(|chunk| {
+            (0..16).fold(0, |value, i| value * 16 + chunk.get(i).unwrap_or(&0))
+        }).collect();
+
+        let (packet, _) = parse_packet(&RawData { data: raw_data, bit_count: digits.len() * 4 }, 0)
+            .ok_or_else(|| line.error_at_end("more bits in the transmission"))?;
         Ok(packet)
     }
 }
 
-fn parse_packet(raw_data: &RawData, start_bit_index: usize) -> (BITSPacket, usize) {
+/// Parses the packet starting at the given bit, returns `None` if the data ends before the packet
+fn parse_packet(raw_data: &RawData, start_bit_index: usize) -> Option<(BITSPacket, usize)> {
     // Extract the version
-    let ref mut current_bit_index = start_bit_index.clone();
-    let version = extract_bits(raw_data, current_bit_index, 3) as u8;
-    let packet_type = extract_bits(raw_data, current_bit_index, 3) as u8;
+    let current_bit_index = &mut start_bit_index.clone();
+    let version = extract_bits(raw_data, current_bit_index, 3)? as u8;
+    let packet_type = extract_bits(raw_data, current_bit_index, 3)? as u8;
     let header = BITSPacketHeader { version, packet_type };
 
     let packet = match header.packet_type {
@@ -61,8 +60,8 @@ fn parse_packet(raw_data: &RawData, start_bit_index: usize) -> (BITSPacket, usiz
             // Parse a litteral packet
             let mut value = 0;
             loop {
-                let continue_flag = extract_bits(raw_data, current_bit_index, 1);
-                let read_value = extract_bits(raw_data, current_bit_index, 4);
+                let continue_flag = extract_bits(raw_data, current_bit_index, 1)?;
+                let read_value = extract_bits(raw_data, current_bit_index, 4)?;
                 value = value * 16 + read_value;
 
                 if continue_flag == 0 {
@@ -77,21 +76,21 @@ fn parse_packet(raw_data: &RawData, start_bit_index: usize) -> (BITSPacket, usiz
         _ => {
             // Parse an operator packet
             let mut sub_packets = Vec::new();
-            let length_type_id = extract_bits(raw_data, current_bit_index, 1);
+            let length_type_id = extract_bits(raw_data, current_bit_index, 1)?;
             if length_type_id == 0 {
-                let sub_packets_total_length = extract_bits(raw_data, current_bit_index, 15) as usize;
+                let sub_packets_total_length = extract_bits(raw_data, current_bit_index, 15)? as usize;
                 let packets_end_bit = *current_bit_index + sub_packets_total_length;
 
                 while *current_bit_index != packets_end_bit {
-                    let (packet, end_bit) = parse_packet(raw_data, *current_bit_index);
+                    let (packet, end_bit) = parse_packet(raw_data, *current_bit_index)?;
                     sub_packets.push(packet);
                     *current_bit_index = end_bit;
                 }
 
             } else {
-                let sub_packets_count = extract_bits(raw_data, current_bit_index, 11) as usize;
+                let sub_packets_count = extract_bits(raw_data, current_bit_index, 11)? as usize;
                 for _i in 0..sub_packets_count {
-                    let (packet, end_bit) = parse_packet(raw_data, *current_bit_index);
+                    let (packet, end_bit) = parse_packet(raw_data, *current_bit_index)?;
                     sub_packets.push(packet);
                     *current_bit_index = end_bit;
                 }
@@ -100,10 +99,13 @@ fn parse_packet(raw_data: &RawData, start_bit_index: usize) -> (BITSPacket, usiz
         }
     };
 
-    (packet, *current_bit_index)
+    Some((packet, *current_bit_index))
 }
 
-fn extract_bits(raw_data: &RawData, start:  &mut usize, n: usize) -> u64 {
+fn extract_bits(raw_data: &RawData, start:  &mut usize, n: usize) -> Option<u64> {
+    if *start + n > raw_data.bit_count {
+        return None;
+    }
 
     let (index, padding) = (*start / 64, *start % 64);
     let value = if padding + n <= 64 {
@@ -128,13 +130,14 @@ fn extract_bits(raw_data: &RawData, start:  &mut usize, n: usize) -> u64 {
     };
     *start += n;
 
-    value
+    Some(value)
 }
 
 #[cfg(test)]
 mod tests {
     use crate::BITSPacket;
     use crate::models::BITSPacketHeader;
+    use aoc_common::ParseError;
 
     #[test]
     fn parse_example_case_1() {
@@ -264,4 +267,16 @@ mod tests {
             ]
         });
     }
+
+    #[test]
+    fn parse_invalid_transmissions() {
+        assert_eq!(
+            BITSPacket::parse_string("D2FG28".to_string()),
+            Err(ParseError::new(16, 1, 4, "a hexadecimal digit"))
+        );
+        assert_eq!(
+            BITSPacket::parse_string("38006F4529".to_string()),
+            Err(ParseError::new(16, 1, 11, "more bits in the transmission"))
+        );
+    }
 }
@@ -2,22 +2,22 @@ use crate::BITSPacket;
 
 pub fn compute_packet_value(packet: &BITSPacket) -> u64 {
     match packet {
-        BITSPacket::LiteralPacket { header, value } => {
+        BITSPacket::LiteralPacket { value, .. } => {
             *value
         }
         BITSPacket::OperatorPacket { header, sub_packets } => {
             match header.packet_type {
                 0 => {
-                    sub_packets.iter().map(|packet| compute_packet_value(packet)).sum()
+                    sub_packets.iter().map(compute_packet_value).sum()
                 }
                 1 => {
-                    sub_packets.iter().map(|packet| compute_packet_value(packet)).fold(1, |a, b| a * b)
+                    sub_packets.iter().map(compute_packet_value).product()
                 }
                 2 => {
-                    sub_packets.iter().map(|packet| compute_packet_value(packet)).min().unwrap()
+                    sub_packets.iter().map(compute_packet_value).min().unwrap()
                 }
                 3 => {
-                    sub_packets.iter().map(|packet| compute_packet_value(packet)).max().unwrap()
+                    sub_packets.iter().map(compute_packet_value).max().unwrap()
                 }
                 5 => {
                     let first_packet = compute_packet_value(&sub_packets[0]);
@@ -0,0 +1,45 @@
+use aoc_common::{Answer, Solution};
+use day_16::models::BITSPacket;
+use day_16::part_1::sum_header_versions;
+use day_16::part_2::compute_packet_value;
+use day_16::Day16;
+
+#[test]
+fn part_1_example_case() {
+    for (transmission, version_sum) in [
+        ("8A004A801A8002F478", 16),
+        ("620080001611562C8802118E34", 12),
+        ("C0015000016115A2E0802F182340", 23),
+        ("A0016C880162017C3686B18A3D4780", 31),
+    ] {
+        let packet = BITSPacket::parse_string(transmission.to_string()).unwrap();
+
+        assert_eq!(sum_header_versions(&packet), version_sum);
+    }
+}
+
+#[test]
+fn part_2_example_case() {
+    for (transmission, value) in [
+        ("C200B40A82", 3),
+        ("04005AC33890", 54),
+        ("880086C3E88112", 7),
+        ("CE00C43D881120", 9),
+        ("D8005AC2A8F0", 1),
+        ("F600BC2D8F", 0),
+        ("9C005AC2F8F0", 0),
+        ("9C0141080250320F1802104A08", 1),
+    ] {
+        let packet = BITSPacket::parse_string(transmission.to_string()).unwrap();
+
+        assert_eq!(compute_packet_value(&packet), value);
+    }
+}
+
+#[test]
+fn solution_example_case() {
+    let input = Day16::parse("C200B40A82".to_string()).unwrap();
+
+    assert_eq!(Day16::part_1(&input), Answer::Number(14));
+    assert_eq!(Day16::part_2(&input), Answer::Number(3));
+}
@@ -0,0 +1,35 @@
+use aoc_common::Rng;
+
+/// Generates a target area ahead of the probe and below it, its distance and its dimensions
+/// growing with `size`
+pub fn generate(size: usize, rng: &mut Rng) -> String {
+    let size = size.max(4) as i64;
+    let x_min = rng.range(size..=3 * size / 2);
+    let x_max = x_min + rng.range(size / 4..=size / 2);
+    let y_max = -rng.range(size..=3 * size / 2);
+    let y_min = y_max - rng.range(size / 4..=size / 2);
+
+    format!(
+        "target area: x={}..{}, y={}..{}\n",
+        x_min, x_max, y_min, y_max
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::generator::generate;
+    use crate::Day17;
+    use aoc_common::{Answer, Rng, Solution};
+
+    #[test]
+    fn generate_valid_input() {
+        for seed in 0..5 {
+            let input = Day17::parse(generate(20, &mut Rng::new(seed))).unwrap();
+
+            assert!(0 < input.x_range[0] && input.x_range[0] <= input.x_range[1]);
+            assert!(input.y_range[0] <= input.y_range[1] && input.y_range[1] < 0);
+            // Shooting straight at the target always hits it
+            assert_ne!(Day17::part_2(&input), Answer::Number(0));
+        }
+    }
+}
@@ -0,0 +1,32 @@
+use crate::models::TargetArea;
+use aoc_common::{Answer, Generator, ParseError, Rng, Solution};
+
+pub mod generator;
+pub mod models;
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    const DAY: u8 = 17;
+    type Input = TargetArea;
+
+    fn parse(content: String) -> Result<TargetArea, ParseError> {
+        TargetArea::parse_string(content)
+    }
+
+    fn part_1(input: &TargetArea) -> Answer {
+        input.find_highest_hit().into()
+    }
+
+    fn part_2(input: &TargetArea) -> Answer {
+        input.count_viable_initial_velocity().into()
+    }
+}
+
+impl Generator for Day17 {
+    const DEFAULT_SIZE: usize = 100;
+
+    fn generate(size: usize, rng: &mut Rng) -> String {
+        generator::generate(size, rng)
+    }
+}
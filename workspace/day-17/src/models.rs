@@ -1,4 +1,9 @@
+use std::ops::RangeInclusive;
+use aoc_common::{input_lines, InputLine, ParseError, Solution};
+use rayon::prelude::*;
+use crate::Day17;
 
+#[derive(Debug, Eq, PartialEq)]
 pub struct TargetArea {
     pub x_range: [i32; 2],
     pub y_range: [i32; 2],
@@ -17,6 +22,21 @@ enum SimulationResult {
 }
 
 impl TargetArea {
+    pub fn parse_string(content: String) -> Result<TargetArea, ParseError> {
+        let line = input_lines(Day17::DAY, &content)
+            .next()
+            .unwrap_or(InputLine::new(Day17::DAY, 0, ""));
+
+        let ranges = line.strip_prefix("target area: ")?;
+        let (x_range, y_range) = ranges.split_once(", ")
+            .ok_or_else(|| line.error_at_end("`, ` followed by the y range"))?;
+
+        Ok(TargetArea {
+            x_range: parse_range(&line, x_range, "x")?,
+            y_range: parse_range(&line, y_range, "y")?,
+        })
+    }
+
     fn simulate(&self, initial_velocity: [i32; 2]) -> (SimulationResult, i32) {
         let mut state = SimulationState {
             position: [0, 0],
@@ -62,15 +82,13 @@ impl TargetArea {
     pub fn find_highest_hit(&self) -> i32 {
         let mut best_height = 0;
 
-        for y in -160..160 {
-            for x in 0..162 {
-                match self.simulate([x, y]) {
-                    (SimulationResult::Hit, height) => {
-                        if height > best_height {
-                            best_height = height
-                        }
+        let (x_velocities, y_velocities) = self.velocity_search_space();
+        for y in y_velocities {
+            for x in x_velocities.clone() {
+                if let (SimulationResult::Hit, height) = self.simulate([x, y]) {
+                    if height > best_height {
+                        best_height = height
                     }
-                    _ => {}
                 }
             }
         }
@@ -78,29 +96,44 @@ impl TargetArea {
         best_height
     }
 
+    /// Simulates the rows of initial velocities in parallel
     pub fn count_viable_initial_velocity(&self) -> i32 {
-        let mut viable_velocity_count  = 0;
-
-        for y in -160..160 {
-            for x in 0..162 {
-                match self.simulate([x, y]) {
-                    (SimulationResult::Hit, height) => {
-                        viable_velocity_count += 1
-                    }
-                    _ => {}
-                }
-            }
+        let (x_velocities, y_velocities) = self.velocity_search_space();
+        y_velocities.into_par_iter()
+            .map(|y| {
+                x_velocities.clone()
+                    .filter(|&x| self.simulate([x, y]).0 == SimulationResult::Hit)
+                    .count() as i32
+            })
+            .sum()
+    }
 
-        }
+    /// Initial velocities worth simulating: faster ones overshoot the target on the first step, and a
+    /// probe thrown upwards comes back to y=0 with the opposite of its initial vertical velocity
+    fn velocity_search_space(&self) -> (RangeInclusive<i32>, RangeInclusive<i32>) {
+        let x_velocities = self.x_range[0].min(0)..=self.x_range[1].max(0);
+        let highest_y_velocity = self.y_range[0].abs().max(self.y_range[1].abs());
 
-        viable_velocity_count
+        (x_velocities, self.y_range[0].min(0)..=highest_y_velocity)
     }
 }
 
+/// Parses an `axis=min..max` range
+fn parse_range(line: &InputLine, text: &str, axis: &str) -> Result<[i32; 2], ParseError> {
+    let range = text.strip_prefix(axis)
+        .and_then(|range| range.strip_prefix('='))
+        .ok_or_else(|| line.error(text, format!("`{}=`", axis)))?;
+    let (min, max) = range.split_once("..")
+        .ok_or_else(|| line.error(range, "`..`"))?;
+
+    Ok([line.parse(min, "a number")?, line.parse(max, "a number")?])
+}
+
 #[cfg(test)]
 mod tests {
     use crate::models::SimulationResult;
     use crate::TargetArea;
+    use aoc_common::ParseError;
 
     #[test]
     fn example_case() {
@@ -132,4 +165,30 @@ mod tests {
 
         assert_eq!(target.count_viable_initial_velocity(), 112);
     }
+
+    #[test]
+    fn parse_example_case() {
+        let target = TargetArea::parse_string("target area: x=20..30, y=-10..-5\n".to_string()).unwrap();
+
+        assert_eq!(target, TargetArea {
+            x_range: [20, 30],
+            y_range: [-10, -5]
+        });
+    }
+
+    #[test]
+    fn parse_invalid_target_area() {
+        assert_eq!(
+            TargetArea::parse_string("target: x=20..30, y=-10..-5\n".to_string()).err(),
+            Some(ParseError::new(17, 1, 1, "`target area:`"))
+        );
+        assert_eq!(
+            TargetArea::parse_string("target area: x=20..30 y=-10..-5\n".to_string()).err(),
+            Some(ParseError::new(17, 1, 32, "`, ` followed by the y range"))
+        );
+        assert_eq!(
+            TargetArea::parse_string("target area: x=20..30, y=-10..a\n".to_string()).err(),
+            Some(ParseError::new(17, 1, 31, "a number"))
+        );
+    }
 }
@@ -0,0 +1,27 @@
+use aoc_common::{Answer, Solution};
+use day_17::models::TargetArea;
+use day_17::Day17;
+
+const EXAMPLE: &str = include_str!("../dataset/example.txt");
+
+#[test]
+fn part_1_example_case() {
+    let input = TargetArea::parse_string(EXAMPLE.to_string()).unwrap();
+
+    assert_eq!(input.find_highest_hit(), 45);
+}
+
+#[test]
+fn part_2_example_case() {
+    let input = TargetArea::parse_string(EXAMPLE.to_string()).unwrap();
+
+    assert_eq!(input.count_viable_initial_velocity(), 112);
+}
+
+#[test]
+fn solution_example_case() {
+    let input = Day17::parse(EXAMPLE.to_string()).unwrap();
+
+    assert_eq!(Day17::part_1(&input), Answer::Number(45));
+    assert_eq!(Day17::part_2(&input), Answer::Number(112));
+}
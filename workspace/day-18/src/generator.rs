@@ -0,0 +1,45 @@
+use aoc_common::Rng;
+
+/// Pairs nested inside 4 pairs explode, numbers of the homework are already reduced
+const MAX_DEPTH: usize = 4;
+
+/// Generates a homework of `size` reduced snailfish numbers
+pub fn generate(size: usize, rng: &mut Rng) -> String {
+    let mut content = String::new();
+    for _ in 0..size.max(1) {
+        number(&mut content, 0, rng);
+        content.push('\n');
+    }
+
+    content
+}
+
+fn number(content: &mut String, depth: usize, rng: &mut Rng) {
+    if depth == MAX_DEPTH || (depth > 0 && rng.chance(0.35)) {
+        content.push(char::from_digit(rng.below(10) as u32, 10).unwrap());
+    } else {
+        content.push('[');
+        number(content, depth + 1, rng);
+        content.push(',');
+        number(content, depth + 1, rng);
+        content.push(']');
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::generator::generate;
+    use crate::Day18;
+    use aoc_common::{Rng, Solution};
+
+    #[test]
+    fn generate_valid_input() {
+        for seed in 0..5 {
+            let input = Day18::parse(generate(20, &mut Rng::new(seed))).unwrap();
+
+            assert_eq!(input.values.len(), 20);
+            Day18::part_1(&input);
+            Day18::part_2(&input);
+        }
+    }
+}
@@ -0,0 +1,32 @@
+use crate::models::SnailfishMathProblem;
+use aoc_common::{Answer, Generator, ParseError, Rng, Solution};
+
+pub mod generator;
+pub mod models;
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    const DAY: u8 = 18;
+    type Input = SnailfishMathProblem;
+
+    fn parse(content: String) -> Result<SnailfishMathProblem, ParseError> {
+        SnailfishMathProblem::parse_string(content)
+    }
+
+    fn part_1(input: &SnailfishMathProblem) -> Answer {
+        input.solve_part_1().into()
+    }
+
+    fn part_2(input: &SnailfishMathProblem) -> Answer {
+        input.solve_part_2().into()
+    }
+}
+
+impl Generator for Day18 {
+    const DEFAULT_SIZE: usize = 100;
+
+    fn generate(size: usize, rng: &mut Rng) -> String {
+        generator::generate(size, rng)
+    }
+}
@@ -1,19 +1,29 @@
+use std::fmt::{Display, Formatter};
 use std::ops::Add;
+use aoc_common::{input_lines, InputLine, ParseError, Solution};
+use rayon::prelude::*;
+use crate::Day18;
 
 pub struct SnailfishMathProblem {
     pub values: Vec<SnailfishNumber>,
 }
 
 impl SnailfishMathProblem {
-    pub fn parse_string(content: String) -> SnailfishMathProblem {
-        let values = content
-            .lines()
-            .map(|line| SnailfishNumber::parse_str(line, 0).0)
-            .collect::<_>();
+    pub fn parse_string(content: String) -> Result<SnailfishMathProblem, ParseError> {
+        let values = input_lines(Day18::DAY, &content)
+            .filter(|line| !line.text.is_empty())
+            .map(|line| {
+                let (number, index) = SnailfishNumber::parse_str(&line, 0)?;
+                if index != line.text.len() {
+                    return Err(line.error_at(index, "the end of the line"));
+                }
+                Ok(number)
+            })
+            .collect::<Result<_, _>>()?;
 
-        SnailfishMathProblem {
+        Ok(SnailfishMathProblem {
             values
-        }
+        })
     }
 
     pub fn sum(&self) -> SnailfishNumber {
@@ -31,23 +41,22 @@ impl SnailfishMathProblem {
         magnitude(&self.sum())
     }
 
+    /// Searches the pairs of numbers in parallel, each thread taking the pairs of a first number
     pub fn solve_part_2(&self) -> i32 {
-        let mut max_magnitude = 0;
-
-        for i in 0..self.values.len() {
-            for j in 0..self.values.len() {
-                if i != j {
-                    let mut n = self.values[i].clone() + self.values[j].clone();
-                    n.reduce();
-                    let magnitude = magnitude(&n);
-                    if magnitude > max_magnitude {
-                        max_magnitude = magnitude;
+        (0..self.values.len()).into_par_iter()
+            .map(|i| {
+                let mut max_magnitude = 0;
+                for j in 0..self.values.len() {
+                    if i != j {
+                        let mut n = self.values[i].clone() + self.values[j].clone();
+                        n.reduce();
+                        max_magnitude = max_magnitude.max(magnitude(&n));
                     }
                 }
-            }
-        }
-
-        max_magnitude
+                max_magnitude
+            })
+            .max()
+            .unwrap_or(0)
     }
 }
 
@@ -63,18 +72,32 @@ pub enum SnailfishNumber {
 }
 
 impl SnailfishNumber {
-    fn parse_str(number_str: &str, index: usize) -> (SnailfishNumber, usize) {
-        match number_str.chars().nth(index).unwrap() {
-            '[' => {
-                let (left, index) = SnailfishNumber::parse_str(number_str, index + 1);
-                let (right, index) = SnailfishNumber::parse_str(number_str, index);
+    /// Parses the number starting at the given index of the line, returns it along with the index
+    /// following it
+    fn parse_str(line: &InputLine, index: usize) -> Result<(SnailfishNumber, usize), ParseError> {
+        let expect = |index: usize, symbol: u8| {
+            if line.text.as_bytes().get(index) == Some(&symbol) {
+                Ok(index + 1)
+            }
+            else {
+                Err(line.error_at(index, format!("`{}`", symbol as char)))
+            }
+        };
+
+        match line.text.as_bytes().get(index) {
+            Some(b'[') => {
+                let (left, index) = SnailfishNumber::parse_str(line, index + 1)?;
+                let index = expect(index, b',')?;
+                let (right, index) = SnailfishNumber::parse_str(line, index)?;
+                let index = expect(index, b']')?;
 
-                (SnailfishNumber::Pair { left: Box::new(left), right: Box::new(right) }, index + 1)
+                Ok((SnailfishNumber::Pair { left: Box::new(left), right: Box::new(right) }, index))
             }
-            n => {
-                let value = (n as u8 - '0' as u8) as i32;
-                (SnailfishNumber::Literal { value }, index + 2)
+            Some(n) if n.is_ascii_digit() => {
+                let value = (n - b'0') as i32;
+                Ok((SnailfishNumber::Literal { value }, index + 1))
             }
+            _ => Err(line.error_at(index, "`[` or a digit")),
         }
     }
 
@@ -85,21 +108,15 @@ impl SnailfishNumber {
 
         let mut last_literal: Option<Box<&mut SnailfishNumber>> = None;
         let mut explosion = None;
-        while queue.len() > 0 {
-            let (current_node, current_depth) = queue.pop().unwrap();
+        while let Some((current_node, current_depth)) = queue.pop() {
 
             if current_depth != 4 || explosion.is_some() {
                 match *current_node {
                     SnailfishNumber::Literal { value } => {
-                        if explosion.is_some() {
-                            let (left_value, right_value) = explosion.unwrap();
-                            match last_literal {
-                                None => {}
-                                Some(last_literal) => {
-                                    match *last_literal {
-                                        SnailfishNumber::Literal { value } => { *value += left_value; }
-                                        _ => {}
-                                    }
+                        if let Some((left_value, right_value)) = explosion {
+                            if let Some(last_literal) = last_literal {
+                                if let SnailfishNumber::Literal { value } = *last_literal {
+                                    *value += left_value;
                                 }
                             }
                             *value += right_value;
@@ -118,15 +135,10 @@ impl SnailfishNumber {
             else {
                 match *current_node {
                     SnailfishNumber::Literal { value } => {
-                        if explosion.is_some() {
-                            let (left_value, right_value) = explosion.unwrap();
-                            match last_literal {
-                                None => {}
-                                Some(last_literal) => {
-                                    match *last_literal {
-                                        SnailfishNumber::Literal { value } => { *value += left_value; }
-                                        _ => {}
-                                    }
+                        if let Some((left_value, right_value)) = explosion {
+                            if let Some(last_literal) = last_literal {
+                                if let SnailfishNumber::Literal { value } = *last_literal {
+                                    *value += left_value;
                                 }
                             }
                             *value += right_value;
@@ -172,15 +184,14 @@ impl SnailfishNumber {
             }
         }
 
-        return false;
+        false
     }
 
     fn split(&mut self) -> bool {
         let mut queue = Vec::new();
 
         queue.push(Box::new(self));
-        while queue.len() > 0 {
-            let current_node = queue.pop().unwrap();
+        while let Some(current_node) = queue.pop() {
 
             match *current_node {
                 SnailfishNumber::Literal { value } => {
@@ -200,7 +211,7 @@ impl SnailfishNumber {
             }
         }
 
-        return false;
+        false
     }
 
     fn reduce(&mut self) {
@@ -227,17 +238,11 @@ fn magnitude(number: &SnailfishNumber) -> i32 {
     }
 }
 
-fn print_debug(number: &SnailfishNumber) {
-    match number {
-        SnailfishNumber::Literal { value } => {
-            eprint!("{}", value);
-        }
-        SnailfishNumber::Pair { left, right } => {
-            eprint!("[");
-            print_debug(left);
-            eprint!(",");
-            print_debug(right);
-            eprint!("]");
+impl Display for SnailfishNumber {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SnailfishNumber::Literal { value } => write!(f, "{}", value),
+            SnailfishNumber::Pair { left, right } => write!(f, "[{},{}]", left, right),
         }
     }
 }
@@ -256,19 +261,24 @@ impl Add for SnailfishNumber {
 
 #[cfg(test)]
 mod tests {
-    use crate::models::{magnitude, print_debug, SnailfishNumber};
+    use crate::models::{magnitude, SnailfishNumber};
     use crate::models::SnailfishNumber::{Literal, Pair};
     use crate::SnailfishMathProblem;
+    use aoc_common::{InputLine, ParseError};
+
+    fn parse(number: &str) -> SnailfishNumber {
+        SnailfishNumber::parse_str(&InputLine::new(18, 0, number), 0).unwrap().0
+    }
 
     #[test]
     fn parse_example_case_1_2() {
-        let n = SnailfishNumber::parse_str("[1,2]", 0).0;
+        let n = parse("[1,2]");
         assert_eq!(n, SnailfishNumber::Pair {
             left: Box::new(SnailfishNumber::Literal { value: 1 }),
             right: Box::new(SnailfishNumber::Literal { value: 2 }),
         });
 
-        let n = SnailfishNumber::parse_str("[[1,2],3]", 0).0;
+        let n = parse("[[1,2],3]");
         assert_eq!(n, SnailfishNumber::Pair {
             left: Box::new(SnailfishNumber::Pair {
                 left: Box::new(SnailfishNumber::Literal { value: 1 }),
@@ -280,7 +290,7 @@ mod tests {
 
     #[test]
     fn parse_example_case_3_4() {
-        let n = SnailfishNumber::parse_str("[9,[8,7]]", 0).0;
+        let n = parse("[9,[8,7]]");
         assert_eq!(n, SnailfishNumber::Pair {
             left: Box::new(SnailfishNumber::Literal { value: 9 }),
             right: Box::new(SnailfishNumber::Pair {
@@ -289,7 +299,7 @@ mod tests {
             }),
         });
 
-        let n = SnailfishNumber::parse_str("[[1,9],[8,5]]", 0).0;
+        let n = parse("[[1,9],[8,5]]");
         assert_eq!(n, SnailfishNumber::Pair {
             left: Box::new(SnailfishNumber::Pair {
                 left: Box::new(SnailfishNumber::Literal { value: 1 }),
@@ -304,7 +314,7 @@ mod tests {
 
     #[test]
     fn parse_example_case_7() {
-        let n = SnailfishNumber::parse_str("[[[[1,3],[5,3]],[[1,3],[8,7]]],[[[4,9],[6,9]],[[8,2],[7,3]]]]", 0).0;
+        let n = parse("[[[[1,3],[5,3]],[[1,3],[8,7]]],[[[4,9],[6,9]],[[8,2],[7,3]]]]");
         assert_eq!(n, SnailfishNumber::Pair {
             left: Box::new(SnailfishNumber::Pair {
                 left: Box::new(SnailfishNumber::Pair {
@@ -355,7 +365,7 @@ mod tests {
 
     #[test]
     fn explode_1() {
-        let mut n = SnailfishNumber::parse_str("[[[[[9,8],1],2],3],4]", 0).0;
+        let mut n = parse("[[[[[9,8],1],2],3],4]");
         n.explode();
         assert_eq!(n,
                    SnailfishNumber::Pair {
@@ -376,7 +386,7 @@ mod tests {
 
     #[test]
     fn explode_2() {
-        let mut n = SnailfishNumber::parse_str("[7,[6,[5,[4,[3,2]]]]]", 0).0;
+        let mut n = parse("[7,[6,[5,[4,[3,2]]]]]");
         n.explode();
         assert_eq!(n,
                    SnailfishNumber::Pair {
@@ -397,7 +407,7 @@ mod tests {
 
     #[test]
     fn explode_3() {
-        let mut n = SnailfishNumber::parse_str("[[6,[5,[4,[3,2]]]],1]", 0).0;
+        let mut n = parse("[[6,[5,[4,[3,2]]]],1]");
         n.explode();
         assert_eq!(n,
                    SnailfishNumber::Pair {
@@ -418,7 +428,7 @@ mod tests {
 
     #[test]
     fn explode_4() {
-        let mut n = SnailfishNumber::parse_str("[[[[0,7],4],[7,[[8,4],9]]],[1,1]]", 0).0;
+        let mut n = parse("[[[[0,7],4],[7,[[8,4],9]]],[1,1]]");
         n.explode();
         assert_eq!(n,
                    Pair {
@@ -466,8 +476,8 @@ mod tests {
 
     #[test]
     fn reduce_1() {
-        let n1 = SnailfishNumber::parse_str("[[[[4,3],4],4],[7,[[8,4],9]]]", 0).0;
-        let n2 = SnailfishNumber::parse_str("[1,1]", 0).0;
+        let n1 = parse("[[[[4,3],4],4],[7,[[8,4],9]]]");
+        let n2 = parse("[1,1]");
 
         let mut n = n1 + n2;
 
@@ -504,7 +514,7 @@ mod tests {
 
     #[test]
     fn magnitude_1() {
-        let n = SnailfishNumber::parse_str("[[1,2],[[3,4],5]]", 0).0;
+        let n = parse("[[1,2],[[3,4],5]]");
         assert_eq!(magnitude(&n), 143);
     }
 
@@ -512,66 +522,82 @@ mod tests {
     fn small_add() {
         let problem = SnailfishMathProblem {
             values: vec![
-                SnailfishNumber::parse_str("[1,1]", 0).0,
-                SnailfishNumber::parse_str("[2,2]", 0).0,
-                SnailfishNumber::parse_str("[3,3]", 0).0,
-                SnailfishNumber::parse_str("[4,4]", 0).0,
-                SnailfishNumber::parse_str("[5,5]", 0).0,
-                SnailfishNumber::parse_str("[6,6]", 0).0,
+                parse("[1,1]"),
+                parse("[2,2]"),
+                parse("[3,3]"),
+                parse("[4,4]"),
+                parse("[5,5]"),
+                parse("[6,6]"),
             ]
         };
 
-        assert_eq!(problem.sum(), SnailfishNumber::parse_str("[[[[5,0],[7,4]],[5,5]],[6,6]]", 0).0);
+        assert_eq!(problem.sum(), parse("[[[[5,0],[7,4]],[5,5]],[6,6]]"));
     }
 
     #[test]
     fn add_two() {
-        let n1 = SnailfishNumber::parse_str("[[[0,[4,5]],[0,0]],[[[4,5],[2,6]],[9,5]]]", 0).0;
-        let n2 = SnailfishNumber::parse_str("[7,[[[3,7],[4,3]],[[6,3],[8,8]]]]", 0).0;
+        let n1 = parse("[[[0,[4,5]],[0,0]],[[[4,5],[2,6]],[9,5]]]");
+        let n2 = parse("[7,[[[3,7],[4,3]],[[6,3],[8,8]]]]");
 
         let mut n = n1 + n2;
         n.reduce();
 
-        assert_eq!(n, SnailfishNumber::parse_str("[[[[4,0],[5,4]],[[7,7],[6,0]]],[[8,[7,7]],[[7,9],[5,0]]]]", 0).0);
+        assert_eq!(n, parse("[[[[4,0],[5,4]],[[7,7],[6,0]]],[[8,[7,7]],[[7,9],[5,0]]]]"));
     }
 
     #[test]
     fn big_add() {
         let problem = SnailfishMathProblem {
             values: vec![
-                SnailfishNumber::parse_str("[[[0,[4,5]],[0,0]],[[[4,5],[2,6]],[9,5]]]", 0).0,
-                SnailfishNumber::parse_str("[7,[[[3,7],[4,3]],[[6,3],[8,8]]]]", 0).0,
-                SnailfishNumber::parse_str("[[2,[[0,8],[3,4]]],[[[6,7],1],[7,[1,6]]]]", 0).0,
-                SnailfishNumber::parse_str("[[[[2,4],7],[6,[0,5]]],[[[6,8],[2,8]],[[2,1],[4,5]]]]", 0).0,
-                SnailfishNumber::parse_str("[7,[5,[[3,8],[1,4]]]]", 0).0,
-                SnailfishNumber::parse_str("[[2,[2,2]],[8,[8,1]]]", 0).0,
-                SnailfishNumber::parse_str("[2,9]", 0).0,
-                SnailfishNumber::parse_str("[1,[[[9,3],9],[[9,0],[0,7]]]]", 0).0,
-                SnailfishNumber::parse_str("[[[5,[7,4]],7],1]", 0).0,
-                SnailfishNumber::parse_str("[[[[4,2],2],6],[8,7]]", 0).0,
+                parse("[[[0,[4,5]],[0,0]],[[[4,5],[2,6]],[9,5]]]"),
+                parse("[7,[[[3,7],[4,3]],[[6,3],[8,8]]]]"),
+                parse("[[2,[[0,8],[3,4]]],[[[6,7],1],[7,[1,6]]]]"),
+                parse("[[[[2,4],7],[6,[0,5]]],[[[6,8],[2,8]],[[2,1],[4,5]]]]"),
+                parse("[7,[5,[[3,8],[1,4]]]]"),
+                parse("[[2,[2,2]],[8,[8,1]]]"),
+                parse("[2,9]"),
+                parse("[1,[[[9,3],9],[[9,0],[0,7]]]]"),
+                parse("[[[5,[7,4]],7],1]"),
+                parse("[[[[4,2],2],6],[8,7]]"),
             ]
         };
 
-        assert_eq!(problem.sum(), SnailfishNumber::parse_str("[[[[8,7],[7,7]],[[8,6],[7,7]]],[[[0,7],[6,6]],[8,7]]]", 0).0);
+        assert_eq!(problem.sum(), parse("[[[[8,7],[7,7]],[[8,6],[7,7]]],[[[0,7],[6,6]],[8,7]]]"));
     }
 
     #[test]
     fn part_1_solve() {
         let problem = SnailfishMathProblem {
             values: vec![
-                SnailfishNumber::parse_str("[[[0,[5,8]],[[1,7],[9,6]]],[[4,[1,2]],[[1,4],2]]]", 0).0,
-                SnailfishNumber::parse_str("[[[5,[2,8]],4],[5,[[9,9],0]]]", 0).0,
-                SnailfishNumber::parse_str("[6,[[[6,2],[5,6]],[[7,6],[4,7]]]]", 0).0,
-                SnailfishNumber::parse_str("[[[6,[0,7]],[0,9]],[4,[9,[9,0]]]]", 0).0,
-                SnailfishNumber::parse_str("[[[7,[6,4]],[3,[1,3]]],[[[5,5],1],9]]", 0).0,
-                SnailfishNumber::parse_str("[[6,[[7,3],[3,2]]],[[[3,8],[5,7]],4]]", 0).0,
-                SnailfishNumber::parse_str("[[[[5,4],[7,7]],8],[[8,3],8]]", 0).0,
-                SnailfishNumber::parse_str("[[9,3],[[9,9],[6,[4,9]]]]", 0).0,
-                SnailfishNumber::parse_str("[[2,[[7,7],7]],[[5,8],[[9,3],[0,2]]]]", 0).0,
-                SnailfishNumber::parse_str("[[[[5,2],5],[8,[3,7]]],[[5,[7,5]],[4,4]]]", 0).0,
+                parse("[[[0,[5,8]],[[1,7],[9,6]]],[[4,[1,2]],[[1,4],2]]]"),
+                parse("[[[5,[2,8]],4],[5,[[9,9],0]]]"),
+                parse("[6,[[[6,2],[5,6]],[[7,6],[4,7]]]]"),
+                parse("[[[6,[0,7]],[0,9]],[4,[9,[9,0]]]]"),
+                parse("[[[7,[6,4]],[3,[1,3]]],[[[5,5],1],9]]"),
+                parse("[[6,[[7,3],[3,2]]],[[[3,8],[5,7]],4]]"),
+                parse("[[[[5,4],[7,7]],8],[[8,3],8]]"),
+                parse("[[9,3],[[9,9],[6,[4,9]]]]"),
+                parse("[[2,[[7,7],7]],[[5,8],[[9,3],[0,2]]]]"),
+                parse("[[[[5,2],5],[8,[3,7]]],[[5,[7,5]],[4,4]]]"),
             ]
         };
 
         assert_eq!(problem.solve_part_1(), 4140);
     }
+
+    #[test]
+    fn parse_invalid_numbers() {
+        assert_eq!(
+            SnailfishMathProblem::parse_string("[[1,2],3]\n[[1,2]3]\n".to_string()).err(),
+            Some(ParseError::new(18, 2, 7, "`,`"))
+        );
+        assert_eq!(
+            SnailfishMathProblem::parse_string("[[1,2],x]\n".to_string()).err(),
+            Some(ParseError::new(18, 1, 8, "`[` or a digit"))
+        );
+        assert_eq!(
+            SnailfishMathProblem::parse_string("[[1,2],3]]\n".to_string()).err(),
+            Some(ParseError::new(18, 1, 10, "the end of the line"))
+        );
+    }
 }
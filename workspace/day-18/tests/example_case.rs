@@ -0,0 +1,31 @@
+use aoc_common::{Answer, Solution};
+use day_18::models::SnailfishMathProblem;
+use day_18::Day18;
+
+const EXAMPLE: &str = include_str!("../dataset/example.txt");
+
+#[test]
+fn part_1_example_case() {
+    let input = SnailfishMathProblem::parse_string(EXAMPLE.to_string()).unwrap();
+
+    assert_eq!(
+        input.sum().to_string(),
+        "[[[[6,6],[7,6]],[[7,7],[7,0]]],[[[7,7],[7,7]],[[7,8],[9,9]]]]"
+    );
+    assert_eq!(input.solve_part_1(), 4140);
+}
+
+#[test]
+fn part_2_example_case() {
+    let input = SnailfishMathProblem::parse_string(EXAMPLE.to_string()).unwrap();
+
+    assert_eq!(input.solve_part_2(), 3993);
+}
+
+#[test]
+fn solution_example_case() {
+    let input = Day18::parse(EXAMPLE.to_string()).unwrap();
+
+    assert_eq!(Day18::part_1(&input), Answer::Number(4140));
+    assert_eq!(Day18::part_2(&input), Answer::Number(3993));
+}
@@ -1,5 +1,6 @@
 use ndarray::{arr2};
 
+#[cfg(test)]
 pub const EXAMPLE_CASE_STRING: &str = "--- scanner 0 ---
 404,-588,-901
 528,-643,409
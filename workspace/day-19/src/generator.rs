@@ -0,0 +1,104 @@
+use crate::consts::compute_rotation_matrixes;
+use aoc_common::Rng;
+use ndarray::arr1;
+use std::fmt::Write;
+
+/// Scanners detect the beacons at most this far on each axis
+const RANGE: i64 = 1000;
+
+/// Beacons two scanners must have in common to be matched
+const OVERLAP: usize = 12;
+
+/// Generates the reports of `size` scanners. Each scanner shares at least 12 beacons with a
+/// previous one, and reports every beacon in its range in its own random orientation.
+pub fn generate(size: usize, rng: &mut Rng) -> String {
+    let size = size.max(1);
+    let rotations = compute_rotation_matrixes();
+
+    let mut scanners: Vec<[i64; 3]> = vec![[0, 0, 0]];
+    let mut beacons = Vec::new();
+    add_beacons(
+        &mut beacons,
+        rng.range(15..=25) as usize,
+        [-RANGE; 3],
+        [RANGE; 3],
+        rng,
+    );
+    for index in 1..size {
+        let parent = scanners[rng.below(index)];
+        let scanner =
+            parent.map(|coordinate| coordinate + rng.range(-11 * RANGE / 10..=11 * RANGE / 10));
+        scanners.push(scanner);
+
+        let min = [0, 1, 2].map(|axis| parent[axis].max(scanner[axis]) - RANGE);
+        let max = [0, 1, 2].map(|axis| parent[axis].min(scanner[axis]) + RANGE);
+        add_beacons(&mut beacons, OVERLAP, min, max, rng);
+        add_beacons(
+            &mut beacons,
+            rng.range(5..=15) as usize,
+            scanner.map(|c| c - RANGE),
+            scanner.map(|c| c + RANGE),
+            rng,
+        );
+    }
+    beacons.sort();
+    beacons.dedup();
+
+    let mut content = String::new();
+    for (index, scanner) in scanners.iter().enumerate() {
+        // The first scanner gives its coordinates to the others, it is not rotated
+        let rotation = if index == 0 {
+            &rotations[0]
+        } else {
+            rng.choose(&rotations)
+        };
+        let mut report = beacons
+            .iter()
+            .filter(|beacon| (0..3).all(|axis| (beacon[axis] - scanner[axis]).abs() <= RANGE))
+            .map(|beacon| {
+                let relative = arr1(&[0, 1, 2].map(|axis| (beacon[axis] - scanner[axis]) as i32));
+                rotation.t().dot(&relative)
+            })
+            .collect::<Vec<_>>();
+        rng.shuffle(&mut report);
+
+        writeln!(content, "--- scanner {} ---", index).unwrap();
+        for beacon in report {
+            writeln!(content, "{},{},{}", beacon[0], beacon[1], beacon[2]).unwrap();
+        }
+        content.push('\n');
+    }
+
+    content
+}
+
+/// Adds `count` beacons at random in the box between `min` and `max`
+fn add_beacons(
+    beacons: &mut Vec<[i64; 3]>,
+    count: usize,
+    min: [i64; 3],
+    max: [i64; 3],
+    rng: &mut Rng,
+) {
+    for _ in 0..count {
+        beacons.push([0, 1, 2].map(|axis| rng.range(min[axis]..=max[axis])));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::generator::generate;
+    use crate::Day19;
+    use aoc_common::{Rng, Solution};
+
+    #[test]
+    fn generate_valid_input() {
+        for seed in 0..3 {
+            let input = Day19::parse(generate(4, &mut Rng::new(seed))).unwrap();
+
+            let (beacon_count, max_distance) = input.solve();
+            assert!(beacon_count >= 15);
+            assert!(max_distance > 0);
+        }
+    }
+}
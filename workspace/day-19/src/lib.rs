@@ -0,0 +1,33 @@
+use crate::models::Scanners;
+use aoc_common::{Answer, Generator, ParseError, Rng, Solution};
+
+mod consts;
+pub mod generator;
+pub mod models;
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    const DAY: u8 = 19;
+    type Input = Scanners;
+
+    fn parse(content: String) -> Result<Scanners, ParseError> {
+        Scanners::parse_string(content)
+    }
+
+    fn part_1(input: &Scanners) -> Answer {
+        input.solve().0.into()
+    }
+
+    fn part_2(input: &Scanners) -> Answer {
+        input.solve().1.into()
+    }
+}
+
+impl Generator for Day19 {
+    const DEFAULT_SIZE: usize = 30;
+
+    fn generate(size: usize, rng: &mut Rng) -> String {
+        generator::generate(size, rng)
+    }
+}
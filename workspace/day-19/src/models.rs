@@ -1,7 +1,9 @@
 use std::collections::HashSet;
-use std::error::Error;
 use ndarray::{arr1, Array1, Array2};
+use aoc_common::{input_lines, ParseError, Solution};
+use rayon::prelude::*;
 use crate::consts::{compute_rotation_matrixes};
+use crate::Day19;
 
 #[derive(Debug)]
 pub struct Scanners {
@@ -10,39 +12,44 @@ pub struct Scanners {
 }
 
 impl Scanners {
-    pub fn parse_string(content: String) -> Result<Scanners, Box<dyn Error>> {
+    pub fn parse_string(content: String) -> Result<Scanners, ParseError> {
         let mut scanners = Scanners {
             scanners: vec![],
             rotation_matrixes: compute_rotation_matrixes(),
         };
 
-        let lines = content.lines().collect::<Vec<_>>();
-        let mut current_scanner = None;
-        for i in 0..lines.len() {
-            match lines[i] {
-                l if l.starts_with("---") => {
+        let mut current_scanner: Option<ScannerData> = None;
+        for line in input_lines(Day19::DAY, &content) {
+            match line.text {
+                l if l.starts_with("--- scanner ") => {
+                    if let Some(scanner) = current_scanner.take() {
+                        scanners.scanners.push(scanner);
+                    }
                     current_scanner = Some(ScannerData::default());
                 }
-                l if l.is_empty() => {
-                    scanners.scanners.push(current_scanner.unwrap().clone());
-                    current_scanner = None;
+                "" => {
+                    if let Some(scanner) = current_scanner.take() {
+                        scanners.scanners.push(scanner);
+                    }
                 }
                 l => {
+                    let scanner = match current_scanner.as_mut() {
+                        Some(scanner) => scanner,
+                        None => return Err(line.error(l, "a `--- scanner N ---` header")),
+                    };
                     let coordinates = l.split(',')
-                        .map(|n| {
-                            n.parse::<i32>()
-                        })
+                        .map(|n| line.parse::<i32>(n, "a number"))
                         .collect::<Result<Vec<i32>, _>>()?;
-                    match current_scanner.clone() {
-                        None => {}
-                        Some(mut scanner) => {
-                            scanner.beacons.push(arr1(&[coordinates[0], coordinates[1], coordinates[2]]));
-                            current_scanner = Some(scanner);
-                        }
+                    if coordinates.len() != 3 {
+                        return Err(line.error_at_end("3 coordinates"));
                     }
+                    scanner.beacons.push(arr1(&coordinates));
                 }
             }
         }
+        if let Some(scanner) = current_scanner {
+            scanners.scanners.push(scanner);
+        }
 
         Ok(scanners)
     }
@@ -56,28 +63,30 @@ impl Scanners {
         }
 
         let mut offsets = vec![arr1(&[0, 0, 0])];
-        while remaining_scanners_to_map.len() > 0 {
-            for i in 0..remaining_scanners_to_map.len() {
-                let scanner_index = remaining_scanners_to_map[i];
-                match self.find_scanner_mapping(&unique_beacons, scanner_index) {
-                    None => {}
+        while !remaining_scanners_to_map.is_empty() {
+            // Matches all the remaining scanners against the known beacons in parallel, then maps
+            // the ones that matched in the order of the scanners
+            let mappings = remaining_scanners_to_map.par_iter()
+                .map(|&scanner_index| self.find_scanner_mapping(&unique_beacons, scanner_index))
+                .collect::<Vec<_>>();
+
+            let mut still_remaining = Vec::new();
+            for (scanner_index, mapping) in remaining_scanners_to_map.into_iter().zip(mappings) {
+                match mapping {
+                    None => still_remaining.push(scanner_index),
                     Some(mapping) => {
-                        remaining_scanners_to_map.remove(i);
-
                         for beacon in &self.scanners[scanner_index].beacons {
                             let v = self.rotation_matrixes[mapping.rotation_matrix_index].dot(beacon) + mapping.offset.clone();
                             unique_beacons.insert(v);
                         }
 
-                        offsets.push(mapping.offset.clone());
-                        break;
+                        offsets.push(mapping.offset);
                     }
                 }
             }
+            remaining_scanners_to_map = still_remaining;
         }
 
-        eprintln!("{}", unique_beacons.len());
-
         let mut max_distance = 0;
         for i in 0..offsets.len() {
             for j in 0..offsets.len() {
@@ -90,8 +99,6 @@ impl Scanners {
             }
         }
 
-        eprintln!("distance: {}", max_distance);
-
         (unique_beacons.len(), max_distance)
     }
 
@@ -101,10 +108,10 @@ impl Scanners {
         let beacons_j = &self.scanners[scanner_j].beacons;
 
         for unique_beacon in beacons_i {
-            for j in 0..beacons_j.len() {
+            for beacon_j in beacons_j {
                 // Test each rotation of each couple
                 for rotation_matrix_index in 0..self.rotation_matrixes.len() {
-                    let offset = unique_beacon - self.rotation_matrixes[rotation_matrix_index].dot(&beacons_j[j]);
+                    let offset = unique_beacon - self.rotation_matrixes[rotation_matrix_index].dot(beacon_j);
                     if self.overlaps(unique_beacons, scanner_j, rotation_matrix_index, &offset) {
                         return Some(ScannersMapping {
                             rotation_matrix_index,
@@ -153,6 +160,7 @@ mod tests {
     use crate::consts::{compute_rotation_matrixes, EXAMPLE_CASE_STRING};
     use crate::models::ScannersMapping;
     use crate::Scanners;
+    use aoc_common::ParseError;
 
     #[test]
     fn parse_example_case() {
@@ -227,4 +235,28 @@ mod tests {
         assert_eq!(beacons_count, 79);
         assert_eq!(max_distance, 3621);
     }
+
+    #[test]
+    fn parse_without_trailing_empty_line() {
+        let input = Scanners::parse_string(EXAMPLE_CASE_STRING.trim_end().to_string()).unwrap();
+
+        assert_eq!(input.scanners.len(), 5);
+        assert_eq!(input.scanners[4].beacons.len(), 26);
+    }
+
+    #[test]
+    fn parse_invalid_beacons() {
+        assert_eq!(
+            Scanners::parse_string("--- scanner 0 ---\n1,2,3\n4,5\n".to_string()).err(),
+            Some(ParseError::new(19, 3, 4, "3 coordinates"))
+        );
+        assert_eq!(
+            Scanners::parse_string("--- scanner 0 ---\n1,a,3\n".to_string()).err(),
+            Some(ParseError::new(19, 2, 3, "a number"))
+        );
+        assert_eq!(
+            Scanners::parse_string("1,2,3\n".to_string()).err(),
+            Some(ParseError::new(19, 1, 1, "a `--- scanner N ---` header"))
+        );
+    }
 }
@@ -0,0 +1,27 @@
+use aoc_common::{Answer, Solution};
+use day_19::models::Scanners;
+use day_19::Day19;
+
+const EXAMPLE: &str = include_str!("../dataset/example.txt");
+
+#[test]
+fn part_1_example_case() {
+    let input = Scanners::parse_string(EXAMPLE.to_string()).unwrap();
+
+    assert_eq!(input.solve().0, 79);
+}
+
+#[test]
+fn part_2_example_case() {
+    let input = Scanners::parse_string(EXAMPLE.to_string()).unwrap();
+
+    assert_eq!(input.solve().1, 3621);
+}
+
+#[test]
+fn solution_example_case() {
+    let input = Day19::parse(EXAMPLE.to_string()).unwrap();
+
+    assert_eq!(Day19::part_1(&input), Answer::Number(79));
+    assert_eq!(Day19::part_2(&input), Answer::Number(3621));
+}
@@ -0,0 +1,49 @@
+use aoc_common::Rng;
+use std::fmt::Write;
+
+/// Generates `size` commands, never sending the submarine above the surface
+pub fn generate(size: usize, rng: &mut Rng) -> String {
+    let mut content = String::new();
+    let mut depth = 0;
+    for _ in 0..size {
+        let value = rng.range(1..=9);
+        let command = match rng.below(3) {
+            0 => "forward",
+            1 => "down",
+            _ if value <= depth => "up",
+            _ => "down",
+        };
+        match command {
+            "down" => depth += value,
+            "up" => depth -= value,
+            _ => {}
+        }
+        writeln!(content, "{} {}", command, value).unwrap();
+    }
+
+    content
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::generator::generate;
+    use crate::models::SubmarineCommand;
+    use crate::Day2;
+    use aoc_common::{Rng, Solution};
+
+    #[test]
+    fn generate_valid_input() {
+        let input = Day2::parse(generate(200, &mut Rng::new(1))).unwrap();
+
+        assert_eq!(input.commands.len(), 200);
+        let mut depth = 0;
+        for command in &input.commands {
+            match command {
+                SubmarineCommand::Down(value) => depth += value,
+                SubmarineCommand::Up(value) => depth -= value,
+                SubmarineCommand::Forward(_) => {}
+            }
+            assert!(depth >= 0);
+        }
+    }
+}
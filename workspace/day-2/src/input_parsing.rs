@@ -1,39 +1,62 @@
 use crate::models::{Input, SubmarineCommand};
-use std::fs;
+use crate::Day2;
+use aoc_common::{input_lines, read_lines, InputError, InputLine, ParseError, Solution};
+use std::io::BufRead;
 
-pub fn parse_file(file_path: String) -> Input {
-    let file_content = fs::read_to_string(file_path).expect("Error while reading the data file");
+pub fn parse_string(content: String) -> Result<Input, ParseError> {
+    let commands = input_lines(Day2::DAY, &content)
+        .filter(|line| !line.text.is_empty())
+        .map(|line| parse_command(&line))
+        .collect::<Result<_, _>>()?;
 
-    parse_string(file_content)
+    Ok(Input { commands })
 }
 
-fn parse_string(content: String) -> Input {
-    let commands = content
-        .lines()
-        .filter_map(|line| {
-            if line.is_empty() {
-                None
-            } else {
-                let parts = line.split_ascii_whitespace().collect::<Vec<&str>>();
-                let value = parts[1].parse::<i32>().unwrap();
-                match parts[0] {
-                    "forward" => Some(SubmarineCommand::Forward(value)),
-                    "down" => Some(SubmarineCommand::Down(value)),
-                    "up" => Some(SubmarineCommand::Up(value)),
-                    _ => panic!("Invalid command: {}", line),
-                }
-            }
-        })
-        .collect();
+/// Parses the commands as they are read, without holding the whole input in memory
+pub fn parse_reader<R: BufRead>(reader: R) -> Result<Input, InputError> {
+    let mut commands = Vec::new();
+    read_lines(Day2::DAY, reader, |line| {
+        if !line.text.is_empty() {
+            commands.push(parse_command(&line)?);
+        }
+        Ok(())
+    })?;
+
+    Ok(Input { commands })
+}
 
-    Input { commands }
+fn parse_command(line: &InputLine) -> Result<SubmarineCommand, ParseError> {
+    parse_command_text(line, line.text)
+}
+
+/// Parses a primitive command from `text`, which must be a slice of the line
+pub(crate) fn parse_command_text(
+    line: &InputLine,
+    text: &str,
+) -> Result<SubmarineCommand, ParseError> {
+    let mut words = text.split_whitespace();
+    let command = words.next().unwrap_or(text);
+    let value = words
+        .next()
+        .ok_or_else(|| line.error(&text[text.len()..], "a value after the command"))?;
+    let value = line.parse::<i32>(value, "a number")?;
+    if let Some(word) = words.next() {
+        return Err(line.error(word, "the end of the line"));
+    }
+    match command {
+        "forward" => Ok(SubmarineCommand::Forward(value)),
+        "down" => Ok(SubmarineCommand::Down(value)),
+        "up" => Ok(SubmarineCommand::Up(value)),
+        _ => Err(line.error(command, "`forward`, `down` or `up`")),
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::input_parsing::parse_string;
+    use crate::input_parsing::{parse_reader, parse_string};
     use crate::models::Input;
     use crate::models::SubmarineCommand::{Down, Forward, Up};
+    use aoc_common::{InputError, ParseError};
 
     #[test]
     fn parse_example_case() {
@@ -46,7 +69,7 @@ forward 2
 "
         .to_string();
 
-        let input = parse_string(content);
+        let input = parse_string(content).unwrap();
 
         assert_eq!(
             input,
@@ -55,4 +78,38 @@ forward 2
             }
         );
     }
+
+    #[test]
+    fn parse_stream() {
+        let content = "forward 5\ndown 5\n\nup 3\n";
+
+        assert_eq!(
+            parse_reader(content.as_bytes()).unwrap(),
+            parse_string(content.to_string()).unwrap()
+        );
+        assert!(matches!(
+            parse_reader("forward 5\nbackward 2\n".as_bytes()),
+            Err(InputError::Parse(error)) if error == ParseError::new(2, 2, 1, "`forward`, `down` or `up`")
+        ));
+    }
+
+    #[test]
+    fn parse_invalid_commands() {
+        assert_eq!(
+            parse_string("forward 5\nbackward 2\n".to_string()),
+            Err(ParseError::new(2, 2, 1, "`forward`, `down` or `up`"))
+        );
+        assert_eq!(
+            parse_string("down x\n".to_string()),
+            Err(ParseError::new(2, 1, 6, "a number"))
+        );
+        assert_eq!(
+            parse_string("up\n".to_string()),
+            Err(ParseError::new(2, 1, 3, "a value after the command"))
+        );
+        assert_eq!(
+            parse_string("up 3 4\n".to_string()),
+            Err(ParseError::new(2, 1, 6, "the end of the line"))
+        );
+    }
 }
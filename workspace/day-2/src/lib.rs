@@ -0,0 +1,43 @@
+use crate::input_parsing::{parse_reader, parse_string};
+use crate::models::Input;
+use aoc_common::{Answer, Generator, InputError, ParseError, Rng, Solution};
+use std::io::BufRead;
+
+pub mod generator;
+pub mod input_parsing;
+pub mod models;
+pub mod part_1;
+pub mod part_2;
+pub mod script;
+pub mod submarine;
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    const DAY: u8 = 2;
+    type Input = Input;
+
+    fn parse(content: String) -> Result<Input, ParseError> {
+        parse_string(content)
+    }
+
+    fn parse_reader<R: BufRead>(reader: R) -> Result<Input, InputError> {
+        parse_reader(reader)
+    }
+
+    fn part_1(input: &Input) -> Answer {
+        part_1::apply_commands(input).into()
+    }
+
+    fn part_2(input: &Input) -> Answer {
+        part_2::apply_commands(input).into()
+    }
+}
+
+impl Generator for Day2 {
+    const DEFAULT_SIZE: usize = 1000;
+
+    fn generate(size: usize, rng: &mut Rng) -> String {
+        generator::generate(size, rng)
+    }
+}
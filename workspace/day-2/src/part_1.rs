@@ -1,18 +1,14 @@
-use crate::models::{Input, SubmarineCommand};
+use crate::models::Input;
+use crate::submarine::{Direct, Submarine};
 
 pub fn apply_commands(input: &Input) -> i32 {
-    let mut current_x = 0;
-    let mut current_y = 0;
+    let mut submarine = Submarine::new(Direct);
 
     for command in &input.commands {
-        match command {
-            SubmarineCommand::Forward(value) => current_x += value,
-            SubmarineCommand::Down(value) => current_y += value,
-            SubmarineCommand::Up(value) => current_y -= value,
-        }
+        submarine.execute(command);
     }
 
-    current_x * current_y
+    submarine.state().product()
 }
 
 #[cfg(test)]
@@ -1,22 +1,14 @@
-use crate::models::{Input, SubmarineCommand};
+use crate::models::Input;
+use crate::submarine::{Aim, Submarine};
 
 pub fn apply_commands(input: &Input) -> i32 {
-    let mut current_x = 0;
-    let mut current_y = 0;
-    let mut aim = 0;
+    let mut submarine = Submarine::new(Aim);
 
     for command in &input.commands {
-        match command {
-            SubmarineCommand::Forward(value) => {
-                current_x += value;
-                current_y += aim * value
-            }
-            SubmarineCommand::Down(value) => aim += value,
-            SubmarineCommand::Up(value) => aim -= value,
-        }
+        submarine.execute(command);
     }
 
-    current_x * current_y
+    submarine.state().product()
 }
 
 #[cfg(test)]
@@ -0,0 +1,396 @@
+use crate::input_parsing::parse_command_text;
+use crate::models::{Input, SubmarineCommand};
+use crate::Day2;
+use aoc_common::{input_lines, InputLine, ParseError, Solution};
+use std::collections::HashMap;
+use std::rc::Rc;
+
+/// Number of primitive commands a script may compile to, so that nested loops cannot exhaust the
+/// memory
+const MAX_COMMANDS: usize = 10_000_000;
+
+/// Words which cannot name a macro
+const KEYWORDS: [&str; 7] = ["forward", "down", "up", "back", "turn", "repeat", "macro"];
+
+/// Maneuver written in the submarine scripting language, a statement per line:
+///
+/// - `forward <n>`, `down <n>` and `up <n>`, the primitive commands
+/// - `back <n>`, which moves backwards
+/// - `turn`, which turns the submarine around: `forward` then moves backwards, and `back` forwards
+/// - `repeat <n> { ... }`, which repeats the enclosed statements
+/// - `macro <name> { ... }`, which defines a macro, then used as `<name>`. Macros must be defined
+///   before being used, so they cannot call themselves.
+/// - `# ...`, a comment up to the end of the line
+///
+/// The puzzle inputs are scripts made of primitive commands only. A script may compile to at most
+/// `MAX_COMMANDS` commands.
+#[derive(Debug, Eq, PartialEq)]
+pub struct Script {
+    statements: Vec<Statement>,
+}
+
+#[derive(Debug, Eq, PartialEq)]
+enum Statement {
+    /// Primitive command, along with the location of its value
+    Command(SubmarineCommand, Location),
+    Back(i32, Location),
+    Turn,
+    Repeat(usize, Vec<Statement>),
+    /// Call of a macro, sharing its statements with the definition
+    Macro(Rc<Vec<Statement>>),
+}
+
+/// Location of a value in the script, reported if moving by it overflows once compiled
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+struct Location {
+    line: usize,
+    column: usize,
+}
+
+impl Location {
+    fn of(line: &InputLine, token: &str) -> Self {
+        let ParseError { line, column, .. } = line.error(token, "");
+        Location { line, column }
+    }
+
+    fn overflow(self) -> ParseError {
+        ParseError::new(
+            Day2::DAY,
+            self.line,
+            self.column,
+            format!("a number above {}", i32::MIN),
+        )
+    }
+}
+
+/// Statements of a block, along with the number of primitive commands they compile to
+type Block = (Vec<Statement>, usize);
+
+impl Script {
+    pub fn parse(content: &str) -> Result<Script, ParseError> {
+        let mut lines = input_lines(Day2::DAY, content);
+        let (statements, _) = parse_block(&mut lines, &mut HashMap::new(), None)?;
+
+        Ok(Script { statements })
+    }
+
+    /// Compiles the script down to primitive commands, the submarine initially heading forward.
+    /// Fails if moving back by a value overflows.
+    pub fn compile(&self) -> Result<Input, ParseError> {
+        let mut commands = Vec::new();
+        compile_block(&self.statements, &mut true, &mut commands)?;
+
+        Ok(Input { commands })
+    }
+}
+
+/// Parses statements up to the `}` closing the block opened on the `opening` line, or up to the
+/// end of the script at the top level. The block may compile to at most `MAX_COMMANDS` commands.
+fn parse_block<'a>(
+    lines: &mut impl Iterator<Item = InputLine<'a>>,
+    macros: &mut HashMap<String, (Rc<Vec<Statement>>, usize)>,
+    opening: Option<&InputLine>,
+) -> Result<Block, ParseError> {
+    let mut statements = Vec::new();
+    let mut length = 0usize;
+
+    while let Some(line) = lines.next() {
+        let code = line.text.split('#').next().unwrap();
+        let words = code.split_whitespace().collect::<Vec<_>>();
+
+        // Statement along with the number of commands it compiles to, and the word it starts at
+        let (statement, statement_length, word) = match words[..] {
+            [] => continue,
+            ["}"] if opening.is_some() => return Ok((statements, length)),
+            ["repeat", ref rest @ ..] => {
+                let count = match rest.first() {
+                    Some(count) => line.parse::<usize>(count, "a number")?,
+                    None => return Err(line.error(end_of(code), "a number")),
+                };
+                expect_block_start(&line, code, &rest[1..])?;
+                let (body, body_length) = parse_block(lines, macros, Some(&line))?;
+                let repeated_length = count.saturating_mul(body_length);
+                (Statement::Repeat(count, body), repeated_length, rest[0])
+            }
+            ["macro", ref rest @ ..] => {
+                let name = match rest.first() {
+                    Some(name) if is_macro_name(name) => *name,
+                    Some(name) => return Err(line.error(name, "a macro name")),
+                    None => return Err(line.error(end_of(code), "a macro name")),
+                };
+                if macros.contains_key(name) {
+                    return Err(line.error(name, "a new macro name"));
+                }
+                expect_block_start(&line, code, &rest[1..])?;
+                let (body, body_length) = parse_block(lines, macros, Some(&line))?;
+                macros.insert(name.to_string(), (Rc::new(body), body_length));
+                continue;
+            }
+            ["turn", ref rest @ ..] => {
+                expect_end(&line, rest)?;
+                (Statement::Turn, 0, words[0])
+            }
+            ["back", ref rest @ ..] => {
+                let value = match rest.first() {
+                    Some(value) => line.parse(value, "a number")?,
+                    None => return Err(line.error(end_of(code), "a number")),
+                };
+                expect_end(&line, &rest[1..])?;
+                (
+                    Statement::Back(value, Location::of(&line, rest[0])),
+                    1,
+                    words[0],
+                )
+            }
+            ["forward" | "down" | "up", ..] => {
+                let command = parse_command_text(&line, code.trim())?;
+                (
+                    Statement::Command(command, Location::of(&line, words[1])),
+                    1,
+                    words[0],
+                )
+            }
+            [name] if macros.contains_key(name) => {
+                let (body, body_length) = &macros[name];
+                (Statement::Macro(body.clone()), *body_length, name)
+            }
+            [word, ..] => return Err(line.error(word, "a command or a defined macro")),
+        };
+
+        length = match length.checked_add(statement_length) {
+            Some(length) if length <= MAX_COMMANDS => length,
+            _ => {
+                let expected = format!("at most {} commands once compiled", MAX_COMMANDS);
+                return Err(line.error(word, expected));
+            }
+        };
+        statements.push(statement);
+    }
+
+    match opening {
+        Some(opening) => Err(opening.error_at_end("a block closed by `}`")),
+        None => Ok((statements, length)),
+    }
+}
+
+/// Checks that the header of a block ends with `{`, `rest` being its words after the name or count
+fn expect_block_start(line: &InputLine, code: &str, rest: &[&str]) -> Result<(), ParseError> {
+    match rest.first() {
+        Some(&"{") => expect_end(line, &rest[1..]),
+        Some(word) => Err(line.error(word, "`{`")),
+        None => Err(line.error(end_of(code), "`{`")),
+    }
+}
+
+/// Checks that no word is left on the line
+fn expect_end(line: &InputLine, rest: &[&str]) -> Result<(), ParseError> {
+    match rest.first() {
+        Some(word) => Err(line.error(word, "the end of the line")),
+        None => Ok(()),
+    }
+}
+
+/// Empty slice right after the last word of the code
+fn end_of(code: &str) -> &str {
+    &code[code.trim_end().len()..]
+}
+
+fn is_macro_name(name: &str) -> bool {
+    !KEYWORDS.contains(&name)
+        && name.starts_with(|c: char| c.is_ascii_alphabetic() || c == '_')
+        && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+fn compile_block(
+    statements: &[Statement],
+    heading_forward: &mut bool,
+    commands: &mut Vec<SubmarineCommand>,
+) -> Result<(), ParseError> {
+    // Moves forward by `value` given the heading, the opposite way if `backwards`
+    let forward = |value: i32, backwards: bool, heading_forward: bool, location: &Location| {
+        let value = if backwards == heading_forward {
+            value.checked_neg()
+        } else {
+            Some(value)
+        };
+        value
+            .map(SubmarineCommand::Forward)
+            .ok_or_else(|| location.overflow())
+    };
+
+    for statement in statements {
+        match statement {
+            Statement::Command(SubmarineCommand::Forward(value), location) => {
+                commands.push(forward(*value, false, *heading_forward, location)?)
+            }
+            Statement::Command(SubmarineCommand::Down(value), _) => {
+                commands.push(SubmarineCommand::Down(*value))
+            }
+            Statement::Command(SubmarineCommand::Up(value), _) => {
+                commands.push(SubmarineCommand::Up(*value))
+            }
+            Statement::Back(value, location) => {
+                commands.push(forward(*value, true, *heading_forward, location)?)
+            }
+            Statement::Turn => *heading_forward = !*heading_forward,
+            Statement::Repeat(count, body) => {
+                for _ in 0..*count {
+                    compile_block(body, heading_forward, commands)?;
+                }
+            }
+            Statement::Macro(body) => compile_block(body, heading_forward, commands)?,
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::input_parsing::parse_string;
+    use crate::models::SubmarineCommand::{self, Down, Forward, Up};
+    use crate::script::Script;
+    use aoc_common::ParseError;
+
+    fn compile(script: &str) -> Result<Vec<SubmarineCommand>, ParseError> {
+        Script::parse(script)?.compile().map(|input| input.commands)
+    }
+
+    #[test]
+    fn compile_puzzle_input() {
+        let content = "forward 5\ndown 5\nforward 8\nup 3\ndown 8\nforward 2\n";
+
+        assert_eq!(
+            Script::parse(content).unwrap().compile(),
+            parse_string(content.to_string())
+        );
+    }
+
+    #[test]
+    fn compile_loops_and_macros() {
+        let script = "# Zigzag down the trench
+macro zig {
+    down 2   # dive
+    forward 3
+}
+
+repeat 2 {
+    zig
+    up 1
+}
+back 4
+turn
+forward 1
+back 2
+";
+
+        assert_eq!(
+            compile(script),
+            Ok(vec![
+                Down(2),
+                Forward(3),
+                Up(1),
+                Down(2),
+                Forward(3),
+                Up(1),
+                Forward(-4),
+                Forward(-1),
+                Forward(2),
+            ])
+        );
+    }
+
+    #[test]
+    fn separate_words_with_any_whitespace() {
+        assert_eq!(
+            compile("forward  5\ndown\t2\n  up   3  # surface\n"),
+            Ok(vec![Forward(5), Down(2), Up(3)])
+        );
+    }
+
+    #[test]
+    fn turn_inside_macros() {
+        let script = "macro u_turn {\n  turn\n}\nrepeat 3 {\n  forward 1\n  u_turn\n}\n";
+
+        assert_eq!(
+            compile(script),
+            Ok(vec![Forward(1), Forward(-1), Forward(1)])
+        );
+        assert_eq!(compile("repeat 0 {\n}\n"), Ok(vec![]));
+    }
+
+    #[test]
+    fn report_errors_with_lines() {
+        assert_eq!(
+            compile("forward 1\n  sideways 2\n"),
+            Err(ParseError::new(2, 2, 3, "a command or a defined macro"))
+        );
+        assert_eq!(
+            compile("repeat x {\n}\n"),
+            Err(ParseError::new(2, 1, 8, "a number"))
+        );
+        assert_eq!(
+            compile("repeat 2\nforward 1\n"),
+            Err(ParseError::new(2, 1, 9, "`{`"))
+        );
+        assert_eq!(
+            compile("down 1\nrepeat 2 {\n  forward 1\n"),
+            Err(ParseError::new(2, 2, 11, "a block closed by `}`"))
+        );
+        assert_eq!(
+            compile("forward 1\n}\n"),
+            Err(ParseError::new(2, 2, 1, "a command or a defined macro"))
+        );
+        assert_eq!(
+            compile("  down # missing\n"),
+            Err(ParseError::new(2, 1, 7, "a value after the command"))
+        );
+        assert_eq!(
+            compile("  up x\n"),
+            Err(ParseError::new(2, 1, 6, "a number"))
+        );
+        assert_eq!(
+            compile("turn around\n"),
+            Err(ParseError::new(2, 1, 6, "the end of the line"))
+        );
+        assert_eq!(
+            compile("macro repeat {\n}\n"),
+            Err(ParseError::new(2, 1, 7, "a macro name"))
+        );
+        assert_eq!(
+            compile("macro m {\n}\nmacro m {\n}\n"),
+            Err(ParseError::new(2, 3, 7, "a new macro name"))
+        );
+        assert_eq!(
+            compile("down 1\nback -2147483648\n"),
+            Err(ParseError::new(2, 2, 6, "a number above -2147483648"))
+        );
+        assert_eq!(
+            compile("forward -2147483648\nturn\nforward -2147483648\n"),
+            Err(ParseError::new(2, 3, 9, "a number above -2147483648"))
+        );
+        assert_eq!(
+            compile("repeat 100000 {\n  repeat 100000 {\n    forward 1\n  }\n}\n"),
+            Err(ParseError::new(
+                2,
+                1,
+                8,
+                "at most 10000000 commands once compiled"
+            ))
+        );
+        assert_eq!(
+            compile("macro m {\n  repeat 6000000 {\n    up 1\n  }\n}\nm\nm\n"),
+            Err(ParseError::new(
+                2,
+                7,
+                1,
+                "at most 10000000 commands once compiled"
+            ))
+        );
+        // A macro is not defined yet within its own body
+        assert_eq!(
+            compile("macro loop {\n  loop\n}\n"),
+            Err(ParseError::new(2, 2, 3, "a command or a defined macro"))
+        );
+    }
+}
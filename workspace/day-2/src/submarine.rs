@@ -0,0 +1,168 @@
+use crate::models::SubmarineCommand;
+
+/// Position and aim of the submarine
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq)]
+pub struct State {
+    pub horizontal_position: i32,
+    pub depth: i32,
+    pub aim: i32,
+}
+
+impl State {
+    /// Horizontal position multiplied by the depth, the answer of the puzzle
+    pub fn product(&self) -> i32 {
+        self.horizontal_position * self.depth
+    }
+}
+
+/// How the commands move the submarine
+pub trait NavigationModel {
+    fn apply(&self, state: State, command: &SubmarineCommand) -> State;
+}
+
+/// First part: `down` and `up` directly change the depth
+pub struct Direct;
+
+impl NavigationModel for Direct {
+    fn apply(&self, state: State, command: &SubmarineCommand) -> State {
+        match *command {
+            SubmarineCommand::Forward(value) => State {
+                horizontal_position: state.horizontal_position + value,
+                ..state
+            },
+            SubmarineCommand::Down(value) => State {
+                depth: state.depth + value,
+                ..state
+            },
+            SubmarineCommand::Up(value) => State {
+                depth: state.depth - value,
+                ..state
+            },
+        }
+    }
+}
+
+/// Second part: `down` and `up` change the aim, and moving `forward` dives along it
+pub struct Aim;
+
+impl NavigationModel for Aim {
+    fn apply(&self, state: State, command: &SubmarineCommand) -> State {
+        match *command {
+            SubmarineCommand::Forward(value) => State {
+                horizontal_position: state.horizontal_position + value,
+                depth: state.depth + state.aim * value,
+                ..state
+            },
+            SubmarineCommand::Down(value) => State {
+                aim: state.aim + value,
+                ..state
+            },
+            SubmarineCommand::Up(value) => State {
+                aim: state.aim - value,
+                ..state
+            },
+        }
+    }
+}
+
+/// Submarine moving according to a navigation model, from the surface
+pub struct Submarine<M> {
+    model: M,
+    state: State,
+}
+
+impl<M: NavigationModel> Submarine<M> {
+    pub fn new(model: M) -> Self {
+        Submarine {
+            model,
+            state: State::default(),
+        }
+    }
+
+    pub fn state(&self) -> State {
+        self.state
+    }
+
+    /// Executes a command, and returns the new state of the submarine
+    pub fn execute(&mut self, command: &SubmarineCommand) -> State {
+        self.state = self.model.apply(self.state, command);
+        self.state
+    }
+
+    /// Executes the commands, and returns the state of the submarine after each of them
+    pub fn trajectory<'a>(
+        &mut self,
+        commands: impl IntoIterator<Item = &'a SubmarineCommand>,
+    ) -> Vec<State> {
+        commands
+            .into_iter()
+            .map(|command| self.execute(command))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::models::SubmarineCommand::{self, Down, Forward, Up};
+    use crate::submarine::{Aim, Direct, State, Submarine};
+
+    const EXAMPLE: [SubmarineCommand; 6] =
+        [Forward(5), Down(5), Forward(8), Up(3), Down(8), Forward(2)];
+
+    fn state(horizontal_position: i32, depth: i32, aim: i32) -> State {
+        State {
+            horizontal_position,
+            depth,
+            aim,
+        }
+    }
+
+    #[test]
+    fn direct_trajectory_example_case() {
+        let trajectory = Submarine::new(Direct).trajectory(&EXAMPLE);
+
+        assert_eq!(
+            trajectory,
+            vec![
+                state(5, 0, 0),
+                state(5, 5, 0),
+                state(13, 5, 0),
+                state(13, 2, 0),
+                state(13, 10, 0),
+                state(15, 10, 0),
+            ]
+        );
+        assert_eq!(trajectory[5].product(), 150);
+    }
+
+    #[test]
+    fn aim_trajectory_example_case() {
+        let mut submarine = Submarine::new(Aim);
+        let trajectory = submarine.trajectory(&EXAMPLE);
+
+        assert_eq!(
+            trajectory,
+            vec![
+                state(5, 0, 0),
+                state(5, 0, 5),
+                state(13, 40, 5),
+                state(13, 40, 2),
+                state(13, 40, 10),
+                state(15, 60, 10),
+            ]
+        );
+        assert_eq!(submarine.state().product(), 900);
+    }
+
+    #[test]
+    fn models_share_the_horizontal_position() {
+        let direct = Submarine::new(Direct).trajectory(&EXAMPLE);
+        let aim = Submarine::new(Aim).trajectory(&EXAMPLE);
+
+        for (direct, aim) in direct.iter().zip(&aim) {
+            assert_eq!(direct.horizontal_position, aim.horizontal_position);
+            // The aim of the second model is the depth of the first one
+            assert_eq!(direct.depth, aim.aim);
+        }
+    }
+}
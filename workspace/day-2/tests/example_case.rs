@@ -0,0 +1,28 @@
+use aoc_common::{Answer, Solution};
+use day_2::input_parsing::parse_string;
+use day_2::Day2;
+use day_2::{part_1, part_2};
+
+const EXAMPLE: &str = include_str!("../dataset/example.txt");
+
+#[test]
+fn part_1_example_case() {
+    let input = parse_string(EXAMPLE.to_string()).unwrap();
+
+    assert_eq!(part_1::apply_commands(&input), 150);
+}
+
+#[test]
+fn part_2_example_case() {
+    let input = parse_string(EXAMPLE.to_string()).unwrap();
+
+    assert_eq!(part_2::apply_commands(&input), 900);
+}
+
+#[test]
+fn solution_example_case() {
+    let input = Day2::parse(EXAMPLE.to_string()).unwrap();
+
+    assert_eq!(Day2::part_1(&input), Answer::Number(150));
+    assert_eq!(Day2::part_2(&input), Answer::Number(900));
+}
@@ -0,0 +1,40 @@
+use aoc_common::Rng;
+
+/// Generates an enhancement algorithm and a `size` × `size` image. When the algorithm lights the
+/// dark pixels of the infinite image, it switches them back off at the next step, otherwise the
+/// number of lit pixels would be infinite.
+pub fn generate(size: usize, rng: &mut Rng) -> String {
+    let pixel = |rng: &mut Rng| if rng.chance(0.5) { '#' } else { '.' };
+
+    let mut algorithm = (0..512).map(|_| pixel(rng)).collect::<Vec<_>>();
+    if algorithm[0] == '#' {
+        algorithm[511] = '.';
+    }
+
+    let size = size.max(1);
+    let mut content = algorithm.into_iter().collect::<String>();
+    content.push_str("\n\n");
+    for _ in 0..size {
+        content.extend((0..size).map(|_| pixel(rng)));
+        content.push('\n');
+    }
+
+    content
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::generator::generate;
+    use crate::Day20;
+    use aoc_common::{Rng, Solution};
+
+    #[test]
+    fn generate_valid_input() {
+        for seed in 0..5 {
+            let input = Day20::parse(generate(10, &mut Rng::new(seed))).unwrap();
+
+            assert_eq!(input.base_image.pixels.width(), 10);
+            Day20::part_1(&input);
+        }
+    }
+}
@@ -0,0 +1,40 @@
+use crate::models::Input;
+use aoc_common::{Answer, FrameSink, Generator, ParseError, Rng, Solution, Visualize};
+use std::io;
+
+pub mod generator;
+pub mod models;
+pub mod visualization;
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    const DAY: u8 = 20;
+    type Input = Input;
+
+    fn parse(content: String) -> Result<Input, ParseError> {
+        Input::parse_string(content)
+    }
+
+    fn part_1(input: &Input) -> Answer {
+        input.multiple_enhance(2).into()
+    }
+
+    fn part_2(input: &Input) -> Answer {
+        input.multiple_enhance(50).into()
+    }
+}
+
+impl Generator for Day20 {
+    const DEFAULT_SIZE: usize = 100;
+
+    fn generate(size: usize, rng: &mut Rng) -> String {
+        generator::generate(size, rng)
+    }
+}
+
+impl Visualize for Day20 {
+    fn visualize(input: &Input, frames: &mut dyn FrameSink) -> io::Result<()> {
+        visualization::visualize(input, frames)
+    }
+}
@@ -1,5 +1,6 @@
-use std::collections::{HashSet};
-use std::error::Error;
+use std::collections::HashSet;
+use aoc_common::{input_lines, Grid, InputLine, ParseError, Solution};
+use crate::Day20;
 
 #[derive(Debug, PartialEq, Eq)]
 pub struct Input {
@@ -12,37 +13,44 @@ pub struct ImageEnhancer {
     algorithm: Vec<i32>
 }
 
+/// Finite part of an infinite image, all the pixels outside of it having the `infinite_value`
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Image {
-    pub data: HashSet<(i32, i32)>,
-    pub x_range: [i32; 2],
-    pub y_range: [i32; 2],
-    pub infinite_value: i32,
+    pub pixels: Grid<bool>,
+    /// Coordinates of the top left pixel of the grid
+    pub origin: (i32, i32),
+    pub infinite_value: bool,
 }
 
 
 impl Input {
-    pub fn parse_string(content: String) -> Result<Input, Box<dyn Error>> {
-        let lines = content.lines().collect::<Vec<_>>();
+    pub fn parse_string(content: String) -> Result<Input, ParseError> {
+        let mut lines = input_lines(Day20::DAY, &content);
 
-        let algorithm = lines[0].chars().map(|c| if c == '#' { 1 } else { 0 }).collect();
+        let algorithm_line = lines.next().unwrap_or(InputLine::new(Day20::DAY, 0, ""));
+        let algorithm = parse_pixels(&algorithm_line)?;
+        if algorithm.len() != 512 {
+            return Err(algorithm_line.error_at_end("512 pixels"));
+        }
 
-        let mut data = HashSet::new();
-        for y in 2..lines.len() {
-            for (x, c) in lines[y].chars().enumerate() {
-                if c == '#' {
-                    data.insert((x as i32, y as i32 - 2));
-                }
+        if let Some(line) = lines.next() {
+            if !line.text.is_empty() {
+                return Err(line.error(line.text, "an empty line"));
             }
         }
 
+        let pixels = Grid::parse_chars(lines.filter(|line| !line.text.is_empty()), "pixels", "`#` or `.`", |c| match c {
+            '#' => Some(true),
+            '.' => Some(false),
+            _ => None,
+        })?;
+
         Ok(Input {
             image_enhancer: ImageEnhancer { algorithm },
             base_image: Image {
-                data,
-                x_range: [-1, lines[2].len() as i32],
-                y_range: [-1, lines.len() as i32 - 2],
-                infinite_value: 0,
+                pixels,
+                origin: (0, 0),
+                infinite_value: false,
             }
         })
     }
@@ -54,58 +62,71 @@ impl Input {
             image = self.image_enhancer.enhance(&image);
         }
 
-        image.data.len()
+        image.pixels.values().filter(|&&pixel| pixel).count()
     }
 }
 
 impl ImageEnhancer {
 
-    fn enhance(&self, image: &Image) -> Image {
-        let mut result_image = Image {
-            data: HashSet::with_capacity(image.data.len()),
-            x_range: [image.x_range[0] - 1, image.x_range[1] + 1],
-            y_range: [image.y_range[0] - 1, image.y_range[1] + 1],
-            infinite_value: if image.infinite_value == 0 { self.algorithm[0] } else { self.algorithm[511] }
-        };
-
-        // For each pixel of the image that is not in the infinite space, compute its value
-        for y in image.y_range[0]..=image.y_range[1] {
-            for x in image.x_range[0]..=image.x_range[1] {
-                // Compute the 9 bits
-                let index = image.get_pixel(x - 1, y - 1) << 8 | image.get_pixel(x, y - 1) << 7 | image.get_pixel(x + 1, y - 1) << 6 |
-                    image.get_pixel(x - 1, y) << 5 | image.get_pixel(x, y) << 4 | image.get_pixel(x + 1, y) << 3 |
-                    image.get_pixel(x - 1, y + 1 ) << 2 | image.get_pixel(x, y + 1) << 1 | image.get_pixel(x + 1, y + 1);
-
-                if self.algorithm[index as usize] == 1 {
-                    result_image.data.insert((x, y));
+    pub(crate) fn enhance(&self, image: &Image) -> Image {
+        let infinite_index = if image.infinite_value { 511 } else { 0 };
+
+        // The enhanced image grows by one pixel on each side, further pixels only see the infinite space
+        let (width, height) = (image.pixels.width() + 2, image.pixels.height() + 2);
+        let mut pixels = Vec::with_capacity(width * height);
+        for y in 0..height as i64 {
+            for x in 0..width as i64 {
+                // Compute the 9 bits, (x - 1, y - 1) being the same pixel in the original image
+                let mut index = 0;
+                for dy in -2..=0 {
+                    for dx in -2..=0 {
+                        index = index << 1 | image.get_pixel(x + dx, y + dy) as usize;
+                    }
                 }
+
+                pixels.push(self.algorithm[index] == 1);
             }
         }
 
-        result_image
+        Image {
+            pixels: Grid::new(width, height, pixels),
+            origin: (image.origin.0 - 1, image.origin.1 - 1),
+            infinite_value: self.algorithm[infinite_index] == 1
+        }
     }
 }
 
 impl Image {
-    fn get_pixel(&self, x: i32, y: i32) -> i32 {
-        if x <= self.x_range[0] || x >= self.x_range[1] || y <= self.y_range[0] || y >= self.y_range[1] {
-            self.infinite_value
-        }
-        else {
-            if self.data.contains(&(x, y)) {
-                1
-            }
-            else {
-                0
-            }
-        }
+    /// Value of a pixel, given its position relative to the top left pixel of the grid
+    fn get_pixel(&self, x: i64, y: i64) -> bool {
+        *self.pixels.get_signed(x, y).unwrap_or(&self.infinite_value)
     }
+
+    /// Coordinates of the lit pixels of the grid
+    pub fn lit_pixels(&self) -> HashSet<(i32, i32)> {
+        self.pixels.iter()
+            .filter(|(_, &pixel)| pixel)
+            .map(|((x, y), _)| (self.origin.0 + x as i32, self.origin.1 + y as i32))
+            .collect()
+    }
+}
+
+/// Parses a line of `#` (lit) and `.` (dark) pixels
+fn parse_pixels(line: &InputLine) -> Result<Vec<i32>, ParseError> {
+    line.text.char_indices()
+        .map(|(i, c)| match c {
+            '#' => Ok(1),
+            '.' => Ok(0),
+            _ => Err(line.error_at(i, "`#` or `.`")),
+        })
+        .collect()
 }
 
 #[cfg(test)]
 mod tests {
     use std::collections::HashSet;
     use crate::models::Input;
+    use aoc_common::ParseError;
 
     #[test]
     fn parse_example_case() {
@@ -123,7 +144,7 @@ mod tests {
         assert_eq!(input.image_enhancer.algorithm[2], 1);
         assert_eq!(input.image_enhancer.algorithm[511], 1);
 
-        assert_eq!(input.base_image.data, HashSet::from([
+        assert_eq!(input.base_image.lit_pixels(), HashSet::from([
             (0, 0),
             (3, 0),
             (0, 1),
@@ -135,9 +156,9 @@ mod tests {
             (3, 4),
             (4, 4),
         ]));
-        assert_eq!(input.base_image.x_range, [-1, 5]);
-        assert_eq!(input.base_image.y_range, [-1, 5]);
-        assert_eq!(input.base_image.infinite_value, 0);
+        assert_eq!((input.base_image.pixels.width(), input.base_image.pixels.height()), (5, 5));
+        assert_eq!(input.base_image.origin, (0, 0));
+        assert!(!input.base_image.infinite_value);
     }
 
     #[test]
@@ -153,10 +174,10 @@ mod tests {
 
         let image = input.image_enhancer.enhance(&input.base_image);
 
-        assert_eq!(image.x_range, [-2, 6]);
-        assert_eq!(image.y_range, [-2, 6]);
-        assert_eq!(image.infinite_value, 0);
-        assert_eq!(image.data, HashSet::from([
+        assert_eq!((image.pixels.width(), image.pixels.height()), (7, 7));
+        assert_eq!(image.origin, (-1, -1));
+        assert!(!image.infinite_value);
+        assert_eq!(image.lit_pixels(), HashSet::from([
             (0, -1),
             (1, -1),
             (3, -1),
@@ -211,4 +232,21 @@ mod tests {
 
         assert_eq!(input.multiple_enhance(50), 3351);
     }
+
+    #[test]
+    fn parse_invalid_images() {
+        let algorithm = "#".repeat(512);
+        assert_eq!(
+            Input::parse_string("#.#\n\n#.\n".to_string()).err(),
+            Some(ParseError::new(20, 1, 4, "512 pixels"))
+        );
+        assert_eq!(
+            Input::parse_string(format!("{}\n\n#..\n#x.\n", algorithm)).err(),
+            Some(ParseError::new(20, 4, 2, "`#` or `.`"))
+        );
+        assert_eq!(
+            Input::parse_string(format!("{}\n\n#..\n#.\n", algorithm)).err(),
+            Some(ParseError::new(20, 4, 3, "3 pixels"))
+        );
+    }
 }
@@ -0,0 +1,60 @@
+use crate::models::Input;
+use aoc_common::{FrameSink, Grid};
+use std::io;
+
+/// Enhancements shown, as many as for the second part
+const STEPS: usize = 50;
+
+/// Shows the image after each enhancement, lit pixels being white. The image grows by one pixel
+/// on each side at every step, so all the frames have the size of the last image, the input image
+/// being in their middle.
+pub fn visualize(input: &Input, frames: &mut dyn FrameSink) -> io::Result<()> {
+    let pixels = &input.base_image.pixels;
+    let (width, height) = (pixels.width() + 2 * STEPS, pixels.height() + 2 * STEPS);
+    let mut image = input.base_image.clone();
+
+    for step in 0..=STEPS {
+        if step > 0 {
+            image = input.image_enhancer.enhance(&image);
+        }
+
+        // Position of the top left pixel of the frame, relative to the top left pixel of the image
+        let offset = step as i64 - STEPS as i64;
+        let frame = (0..height as i64)
+            .flat_map(|y| (0..width as i64).map(move |x| (x, y)))
+            .map(|(x, y)| {
+                let pixel = image.pixels.get_signed(x + offset, y + offset);
+                if *pixel.unwrap_or(&image.infinite_value) {
+                    255
+                } else {
+                    0
+                }
+            })
+            .collect();
+        frames.frame(&Grid::new(width, height, frame))?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::models::Input;
+    use crate::visualization::visualize;
+
+    #[test]
+    fn visualize_example_case() {
+        let input =
+            Input::parse_string(include_str!("../dataset/example.txt").to_string()).unwrap();
+        let mut frames = Vec::new();
+        visualize(&input, &mut frames).unwrap();
+
+        let lit_pixels = |step: usize| frames[step].values().filter(|&&level| level == 255).count();
+        assert_eq!(frames.len(), 51);
+        assert_eq!((frames[0].width(), frames[0].height()), (105, 105));
+        assert_eq!(
+            (lit_pixels(0), lit_pixels(2), lit_pixels(50)),
+            (10, 35, 3351)
+        );
+    }
+}
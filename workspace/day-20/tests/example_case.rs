@@ -0,0 +1,27 @@
+use aoc_common::{Answer, Solution};
+use day_20::models::Input;
+use day_20::Day20;
+
+const EXAMPLE: &str = include_str!("../dataset/example.txt");
+
+#[test]
+fn part_1_example_case() {
+    let input = Input::parse_string(EXAMPLE.to_string()).unwrap();
+
+    assert_eq!(input.multiple_enhance(2), 35);
+}
+
+#[test]
+fn part_2_example_case() {
+    let input = Input::parse_string(EXAMPLE.to_string()).unwrap();
+
+    assert_eq!(input.multiple_enhance(50), 3351);
+}
+
+#[test]
+fn solution_example_case() {
+    let input = Day20::parse(EXAMPLE.to_string()).unwrap();
+
+    assert_eq!(Day20::part_1(&input), Answer::Number(35));
+    assert_eq!(Day20::part_2(&input), Answer::Number(3351));
+}
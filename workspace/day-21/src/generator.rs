@@ -0,0 +1,27 @@
+use aoc_common::Rng;
+
+/// Generates the starting positions of both players. The input is always 2 lines, so the size is
+/// ignored.
+pub fn generate(_size: usize, rng: &mut Rng) -> String {
+    format!(
+        "Player 1 starting position: {}\nPlayer 2 starting position: {}\n",
+        rng.range(1..=10),
+        rng.range(1..=10)
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::generator::generate;
+    use crate::Day21;
+    use aoc_common::{Rng, Solution};
+
+    #[test]
+    fn generate_valid_input() {
+        let inputs = (0..20)
+            .map(|seed| Day21::parse(generate(2, &mut Rng::new(seed))).unwrap())
+            .collect::<Vec<_>>();
+
+        assert!(inputs.iter().any(|input| *input != inputs[0]));
+    }
+}
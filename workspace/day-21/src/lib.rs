@@ -0,0 +1,32 @@
+use crate::models::{Game, PawnPositions, QuanticGame};
+use aoc_common::{Answer, Generator, ParseError, Rng, Solution};
+
+pub mod generator;
+pub mod models;
+
+pub struct Day21;
+
+impl Solution for Day21 {
+    const DAY: u8 = 21;
+    type Input = PawnPositions;
+
+    fn parse(content: String) -> Result<PawnPositions, ParseError> {
+        PawnPositions::parse_string(content)
+    }
+
+    fn part_1(input: &PawnPositions) -> Answer {
+        Game::new(*input).play().into()
+    }
+
+    fn part_2(input: &PawnPositions) -> Answer {
+        QuanticGame::default().play(*input).into()
+    }
+}
+
+impl Generator for Day21 {
+    const DEFAULT_SIZE: usize = 10;
+
+    fn generate(size: usize, rng: &mut Rng) -> String {
+        generator::generate(size, rng)
+    }
+}
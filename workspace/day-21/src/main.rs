@@ -1,13 +1,7 @@
-use crate::models::{Game, PawnPositions, QuanticGame};
+use aoc_common::run;
+use day_21::Day21;
+use std::error::Error;
 
-mod models;
-
-fn main() {
-    let input = PawnPositions::new(10, 4);
-    let mut game = Game::new(input);
-
-    println!("part 1: {}", game.play());
-
-    let mut game = QuanticGame::default();
-    println!("part 2: {}", game.play(input));
+fn main() -> Result<(), Box<dyn Error>> {
+    run::<Day21>()
 }
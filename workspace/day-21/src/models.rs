@@ -1,5 +1,8 @@
 
-#[derive(Debug, Clone, Copy)]
+use aoc_common::{input_lines, InputLine, ParseError, Solution};
+use crate::Day21;
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
 pub struct PawnPositions {
     player_1: u32,
     player_2: u32,
@@ -12,6 +15,24 @@ impl PawnPositions {
             player_2: player_2 - 1
         }
     }
+
+    pub fn parse_string(content: String) -> Result<PawnPositions, ParseError> {
+        let mut lines = input_lines(Day21::DAY, &content);
+        let mut starting_position = |player: usize| {
+            let line = lines.next().unwrap_or(InputLine::new(Day21::DAY, player - 1, ""));
+            let text = line.strip_prefix(&format!("Player {} starting position: ", player))?;
+            let position = line.parse::<u32>(text, "a position between 1 and 10")?;
+            if !(1..=10).contains(&position) {
+                return Err(line.error(text, "a position between 1 and 10"));
+            }
+            Ok(position)
+        };
+
+        let player_1 = starting_position(1)?;
+        let player_2 = starting_position(2)?;
+
+        Ok(PawnPositions::new(player_1, player_2))
+    }
 }
 
 #[derive(Debug, Default)]
@@ -29,7 +50,7 @@ impl DeterministicDie {
             self.current_value = 1;
             return 1;
         }
-        return self.current_value
+        self.current_value
     }
 }
 
@@ -97,7 +118,7 @@ impl QuanticGame {
 
         self.play_turn(state, 1);
 
-        return self.player_1_wins.max(self.player_2_wins);
+        self.player_1_wins.max(self.player_2_wins)
     }
 
     pub fn play_turn(&mut self, state: QuanticGameState, game_count: usize) {
@@ -109,13 +130,13 @@ impl QuanticGame {
         }
         else {
             // Try all possible rollouts
-            self.play_turn(state.play_turn(3), game_count * 1);
+            self.play_turn(state.play_turn(3), game_count);
             self.play_turn(state.play_turn(4), game_count * 3);
             self.play_turn(state.play_turn(5), game_count * 6);
             self.play_turn(state.play_turn(6), game_count * 7);
             self.play_turn(state.play_turn(7), game_count * 6);
             self.play_turn(state.play_turn(8), game_count * 3);
-            self.play_turn(state.play_turn(9), game_count * 1);
+            self.play_turn(state.play_turn(9), game_count);
         }
     }
 }
@@ -154,6 +175,7 @@ impl Game {
 mod tests {
     use crate::models::{Game, QuanticGame};
     use crate::PawnPositions;
+    use aoc_common::ParseError;
 
     #[test]
     fn example_case_play_turns() {
@@ -192,4 +214,25 @@ mod tests {
 
         assert_eq!(game.play(starting_positions), 444356092776315);
     }
+
+    #[test]
+    fn parse_example_case() {
+        let positions = PawnPositions::parse_string("Player 1 starting position: 4
+Player 2 starting position: 8
+".to_string()).unwrap();
+
+        assert_eq!(positions, PawnPositions::new(4, 8));
+    }
+
+    #[test]
+    fn parse_invalid_positions() {
+        assert_eq!(
+            PawnPositions::parse_string("Player 1 starting position: 4\n".to_string()).err(),
+            Some(ParseError::new(21, 2, 1, "`Player 2 starting position:`"))
+        );
+        assert_eq!(
+            PawnPositions::parse_string("Player 1 starting position: 4\nPlayer 2 starting position: 11\n".to_string()).err(),
+            Some(ParseError::new(21, 2, 29, "a position between 1 and 10"))
+        );
+    }
 }
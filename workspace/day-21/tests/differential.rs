@@ -0,0 +1,94 @@
+use aoc_common::Rng;
+use day_21::models::{Game, PawnPositions, QuanticGame};
+use std::collections::HashMap;
+
+/// Moves the pawn from its position between 1 and 10, and returns its new position
+fn advance(position: u32, rolls: u32) -> u32 {
+    (position - 1 + rolls) % 10 + 1
+}
+
+/// Naive reference rolling the deterministic die one value at a time
+fn reference_deterministic(positions: [u32; 2]) -> u32 {
+    let mut positions = positions;
+    let mut scores = [0; 2];
+    let mut rolls = 0;
+    let mut player = 0;
+    while scores[0] < 1000 && scores[1] < 1000 {
+        let moves = (0..3)
+            .map(|_| {
+                rolls += 1;
+                (rolls - 1) % 100 + 1
+            })
+            .sum();
+        positions[player] = advance(positions[player], moves);
+        scores[player] += positions[player];
+        player = 1 - player;
+    }
+
+    scores[0].min(scores[1]) * rolls
+}
+
+/// Naive reference splitting the universe on each of the 27 roll sequences of a turn, returning
+/// the wins of the player about to play and of the other one
+fn reference_quantic_wins(
+    positions: [u32; 2],
+    scores: [u32; 2],
+    known_wins: &mut HashMap<([u32; 2], [u32; 2]), [usize; 2]>,
+) -> [usize; 2] {
+    if let Some(wins) = known_wins.get(&(positions, scores)) {
+        return *wins;
+    }
+
+    let mut wins = [0; 2];
+    for first in 1..=3 {
+        for second in 1..=3 {
+            for third in 1..=3 {
+                let position = advance(positions[0], first + second + third);
+                let score = scores[0] + position;
+                if score >= 21 {
+                    wins[0] += 1;
+                } else {
+                    let [other_wins, own_wins] = reference_quantic_wins(
+                        [positions[1], position],
+                        [scores[1], score],
+                        known_wins,
+                    );
+                    wins[0] += own_wins;
+                    wins[1] += other_wins;
+                }
+            }
+        }
+    }
+
+    known_wins.insert((positions, scores), wins);
+    wins
+}
+
+#[test]
+fn deterministic_game_matches_reference() {
+    for player_1 in 1..=10 {
+        for player_2 in 1..=10 {
+            let mut game = Game::new(PawnPositions::new(player_1, player_2));
+
+            assert_eq!(game.play(), reference_deterministic([player_1, player_2]));
+        }
+    }
+}
+
+#[test]
+fn quantic_game_matches_reference() {
+    let mut rng = Rng::new(0);
+    let mut known_wins = HashMap::new();
+    for _ in 0..3 {
+        let positions = [rng.range(1..=10) as u32, rng.range(1..=10) as u32];
+        let wins = reference_quantic_wins(positions, [0, 0], &mut known_wins);
+
+        let mut game = QuanticGame::default();
+        assert_eq!(
+            game.play(PawnPositions::new(positions[0], positions[1])),
+            wins[0].max(wins[1]),
+            "positions {:?}",
+            positions
+        );
+    }
+}
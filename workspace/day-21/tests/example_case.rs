@@ -0,0 +1,29 @@
+use aoc_common::{Answer, Solution};
+use day_21::models::{Game, PawnPositions, QuanticGame};
+use day_21::Day21;
+
+const EXAMPLE: &str = include_str!("../dataset/example.txt");
+
+#[test]
+fn part_1_example_case() {
+    let input = PawnPositions::parse_string(EXAMPLE.to_string()).unwrap();
+    let mut game = Game::new(input);
+
+    assert_eq!(game.play(), 739785);
+}
+
+#[test]
+fn part_2_example_case() {
+    let input = PawnPositions::parse_string(EXAMPLE.to_string()).unwrap();
+    let mut game = QuanticGame::default();
+
+    assert_eq!(game.play(input), 444356092776315);
+}
+
+#[test]
+fn solution_example_case() {
+    let input = Day21::parse(EXAMPLE.to_string()).unwrap();
+
+    assert_eq!(Day21::part_1(&input), Answer::Number(739785));
+    assert_eq!(Day21::part_2(&input), Answer::Number(444356092776315));
+}
@@ -0,0 +1,60 @@
+use aoc_common::Rng;
+use std::fmt::Write;
+
+/// The initialization procedure only considers the cubes up to this far from the origin
+const INITIALIZATION_RANGE: i64 = 50;
+
+/// Coordinates of the whole reactor
+const REACTOR_RANGE: i64 = 100_000;
+
+/// Generates a reboot procedure of `size` steps, the first 5% of them in the initialization region
+/// like in the puzzle inputs
+pub fn generate(size: usize, rng: &mut Rng) -> String {
+    let initialization_steps = (size / 20).max(1);
+
+    let mut content = String::new();
+    for step in 0..size {
+        let (range, max_length) = if step < initialization_steps {
+            (INITIALIZATION_RANGE, INITIALIZATION_RANGE)
+        } else {
+            (REACTOR_RANGE, REACTOR_RANGE / 2)
+        };
+        let state = if step == 0 || rng.chance(0.7) {
+            "on"
+        } else {
+            "off"
+        };
+        let ranges = ["x", "y", "z"].map(|axis| {
+            let length = rng.range(0..=max_length);
+            let start = rng.range(-range..=range - length);
+            format!("{}={}..{}", axis, start, start + length)
+        });
+        writeln!(content, "{} {}", state, ranges.join(",")).unwrap();
+    }
+
+    content
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::generator::generate;
+    use crate::Day22;
+    use aoc_common::{Answer, Generator, Rng, Solution};
+
+    #[test]
+    fn generate_valid_input() {
+        let input = Day22::parse(generate(20, &mut Rng::new(1))).unwrap();
+
+        assert_ne!(Day22::part_1(&input), Answer::Number(0));
+        Day22::part_2(&input);
+    }
+
+    #[test]
+    fn solve_default_size() {
+        let content = Day22::generate(Day22::DEFAULT_SIZE, &mut Rng::new(3));
+        assert_eq!(content.lines().count(), Day22::DEFAULT_SIZE);
+
+        let input = Day22::parse(content).unwrap();
+        assert_eq!(Day22::part_2(&input), Answer::Number(625292771852069));
+    }
+}
@@ -0,0 +1,39 @@
+use crate::models::BootProcedure;
+use aoc_common::{Answer, Generator, InputError, ParseError, Rng, Solution};
+use std::io::BufRead;
+
+pub mod generator;
+pub mod models;
+
+pub struct Day22;
+
+impl Solution for Day22 {
+    const DAY: u8 = 22;
+    type Input = BootProcedure;
+
+    fn parse(content: String) -> Result<BootProcedure, ParseError> {
+        BootProcedure::parse_string(content)
+    }
+
+    fn parse_reader<R: BufRead>(reader: R) -> Result<BootProcedure, InputError> {
+        BootProcedure::parse_reader(reader)
+    }
+
+    fn part_1(input: &BootProcedure) -> Answer {
+        input.part_1_count_on().into()
+    }
+
+    fn part_2(input: &BootProcedure) -> Answer {
+        input.part_2_count_on().into()
+    }
+}
+
+impl Generator for Day22 {
+    // Far below the 420 steps of the real input: the time of part 2 grows about as the fourth
+    // power of the number of steps, reaching minutes on the real input size
+    const DEFAULT_SIZE: usize = 60;
+
+    fn generate(size: usize, rng: &mut Rng) -> String {
+        generator::generate(size, rng)
+    }
+}
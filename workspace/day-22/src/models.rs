@@ -1,3 +1,7 @@
+use aoc_common::{input_lines, read_lines, InputError, InputLine, ParseError, Solution};
+use std::io::BufRead;
+use crate::Day22;
+
 
 #[derive(Debug, Eq, PartialEq)]
 pub struct BootProcedure {
@@ -11,28 +15,30 @@ pub enum BootInstruction {
 }
 
 impl BootProcedure {
-    pub fn parse_string(content: String) -> BootProcedure {
-        let boot_procedure = content
-            .lines()
-            .map(|line| {
-                let parts = line.split(&[' ', 'x', '=', ',', 'y', 'z', '.'][..]).collect::<Vec<_>>();
-
-                let x_range = [parts[3].parse::<i32>().unwrap(), parts[5].parse::<i32>().unwrap()];
-                let y_range = [parts[8].parse::<i32>().unwrap(), parts[10].parse::<i32>().unwrap()];
-                let z_range = [parts[13].parse::<i32>().unwrap(), parts[15].parse::<i32>().unwrap()];
-
-                match parts[0] {
-                    "on" => BootInstruction::On(x_range, y_range, z_range),
-                    _ => BootInstruction::Off(x_range, y_range, z_range),
-                }
+    pub fn parse_string(content: String) -> Result<BootProcedure, ParseError> {
+        let boot_procedure = input_lines(Day22::DAY, &content)
+            .filter(|line| !line.text.is_empty())
+            .map(|line| BootInstruction::parse_str(&line))
+            .collect::<Result<_, _>>()?;
 
-            })
-            .collect();
+        Ok(BootProcedure {
+            boot_procedure
+        })
+    }
 
+    /// Parses the instructions as they are read, without holding the whole input in memory
+    pub fn parse_reader<R: BufRead>(reader: R) -> Result<BootProcedure, InputError> {
+        let mut boot_procedure = Vec::new();
+        read_lines(Day22::DAY, reader, |line| {
+            if !line.text.is_empty() {
+                boot_procedure.push(BootInstruction::parse_str(&line)?);
+            }
+            Ok(())
+        })?;
 
-        BootProcedure {
+        Ok(BootProcedure {
             boot_procedure
-        }
+        })
     }
 
     pub fn part_1_count_on(&self) -> usize {
@@ -63,19 +69,15 @@ impl BootProcedure {
 
     pub fn part_2_count_on(&self) -> usize {
         let mut sub_spaces : Vec<SubSpace> = vec![];
-        for (index, instruction) in self.boot_procedure.iter().enumerate() {
-            eprintln!("{}", index);
+        for instruction in &self.boot_procedure {
             let mut new_sub_spaces = Vec::new();
 
-            match instruction {
-                BootInstruction::On(x_range, y_range, z_range) => {
-                    new_sub_spaces.push(SubSpace {
-                        x_range: *x_range,
-                        y_range: *y_range,
-                        z_range: *z_range,
-                    });
-                }
-                _ => {}
+            if let BootInstruction::On(x_range, y_range, z_range) = instruction {
+                new_sub_spaces.push(SubSpace {
+                    x_range: *x_range,
+                    y_range: *y_range,
+                    z_range: *z_range,
+                });
             }
 
             let ranges = instruction.get_ranges_and_state();
@@ -182,9 +184,44 @@ impl BootInstruction {
     }
 }
 
+/// Parses an `axis=min..max` range
+impl BootInstruction {
+    fn parse_str(line: &InputLine) -> Result<BootInstruction, ParseError> {
+        let (state, cuboid) = match line.text.split_once(' ') {
+            Some(parts) => parts,
+            None => return Err(line.error_at_end("a cuboid after the state")),
+        };
+
+        let ranges = cuboid.split(',').collect::<Vec<_>>();
+        if ranges.len() != 3 {
+            return Err(line.error_at_end("3 ranges"));
+        }
+        let x_range = parse_range(line, ranges[0], "x")?;
+        let y_range = parse_range(line, ranges[1], "y")?;
+        let z_range = parse_range(line, ranges[2], "z")?;
+
+        match state {
+            "on" => Ok(BootInstruction::On(x_range, y_range, z_range)),
+            "off" => Ok(BootInstruction::Off(x_range, y_range, z_range)),
+            _ => Err(line.error(state, "`on` or `off`")),
+        }
+    }
+}
+
+fn parse_range(line: &InputLine, text: &str, axis: &str) -> Result<[i32; 2], ParseError> {
+    let range = text.strip_prefix(axis)
+        .and_then(|range| range.strip_prefix('='))
+        .ok_or_else(|| line.error(text, format!("`{}=`", axis)))?;
+    let (min, max) = range.split_once("..")
+        .ok_or_else(|| line.error(range, "`..`"))?;
+
+    Ok([line.parse(min, "a number")?, line.parse(max, "a number")?])
+}
+
 #[cfg(test)]
 mod tests {
     use crate::models::{BootInstruction, BootProcedure};
+    use aoc_common::{InputError, ParseError};
 
     #[test]
     fn parse_example_case() {
@@ -192,7 +229,7 @@ mod tests {
 on x=11..13,y=11..13,z=11..13
 off x=9..11,y=9..11,z=9..11
 on x=10..10,y=10..10,z=10..10
-".to_string());
+".to_string()).unwrap();
 
         assert_eq!(input, BootProcedure {
             boot_procedure: vec![
@@ -210,7 +247,7 @@ on x=10..10,y=10..10,z=10..10
 on x=11..13,y=11..13,z=11..13
 off x=9..11,y=9..11,z=9..11
 on x=10..10,y=10..10,z=10..10
-".to_string());
+".to_string()).unwrap();
 
         assert_eq!(input.part_1_count_on(), 39);
     }
@@ -277,8 +314,44 @@ off x=-27365..46395,y=31009..98017,z=15428..76570
 off x=-70369..-16548,y=22648..78696,z=-1892..86821
 on x=-53470..21291,y=-120233..-33476,z=-44150..38147
 off x=-93533..-4276,y=-16170..68771,z=-104985..-24507
-".to_string());
+".to_string()).unwrap();
 
         assert_eq!(input.part_2_count_on(), 2758514936282235);
     }
+
+    #[test]
+    fn parse_stream() {
+        let content = "on x=10..12,y=10..12,z=10..12\n\noff x=9..11,y=9..11,z=9..11\n";
+
+        assert_eq!(BootProcedure::parse_reader(content.as_bytes()).unwrap(),
+                   BootProcedure::parse_string(content.to_string()).unwrap());
+        assert!(matches!(
+            BootProcedure::parse_reader("on x=1..2,y=1..2,z=1..2\nof x=1..2,y=1..2,z=1..2\n".as_bytes()),
+            Err(InputError::Parse(error)) if error == ParseError::new(22, 2, 1, "`on` or `off`")
+        ));
+    }
+
+    #[test]
+    fn parse_invalid_instructions() {
+        assert_eq!(
+            BootProcedure::parse_string("of x=1..2,y=1..2,z=1..2\n".to_string()).err(),
+            Some(ParseError::new(22, 1, 1, "`on` or `off`"))
+        );
+        assert_eq!(
+            BootProcedure::parse_string("on x=1..2,z=1..2,z=1..2\n".to_string()).err(),
+            Some(ParseError::new(22, 1, 11, "`y=`"))
+        );
+        assert_eq!(
+            BootProcedure::parse_string("on x=1..2,y=1.2,z=1..2\n".to_string()).err(),
+            Some(ParseError::new(22, 1, 13, "`..`"))
+        );
+        assert_eq!(
+            BootProcedure::parse_string("on x=1..2,y=1..2,z=a..2\n".to_string()).err(),
+            Some(ParseError::new(22, 1, 20, "a number"))
+        );
+        assert_eq!(
+            BootProcedure::parse_string("on x=1..2,y=1..2\n".to_string()).err(),
+            Some(ParseError::new(22, 1, 17, "3 ranges"))
+        );
+    }
 }
@@ -0,0 +1,86 @@
+use aoc_common::Rng;
+use day_22::models::BootProcedure;
+use std::collections::HashSet;
+
+/// A step of a reboot procedure: whether it turns the cubes on, and its x, y and z ranges
+type Step = (bool, [[i32; 2]; 3]);
+
+/// Generates up to 10 random steps whose cuboids stay within `reach` of the origin
+fn random_steps(rng: &mut Rng, reach: i32, max_side: i32) -> Vec<Step> {
+    (0..rng.range(1..=10))
+        .map(|_| {
+            let ranges = [0; 3].map(|_| {
+                let side = rng.range(0..=max_side as i64) as i32;
+                let start = rng.range(-reach as i64..=(reach - side) as i64) as i32;
+                [start, start + side]
+            });
+            (rng.chance(0.7), ranges)
+        })
+        .collect()
+}
+
+fn parse(steps: &[Step]) -> BootProcedure {
+    let content = steps
+        .iter()
+        .map(|(on, [x, y, z])| {
+            format!(
+                "{} x={}..{},y={}..{},z={}..{}\n",
+                if *on { "on" } else { "off" },
+                x[0],
+                x[1],
+                y[0],
+                y[1],
+                z[0],
+                z[1]
+            )
+        })
+        .collect::<String>();
+
+    BootProcedure::parse_string(content).unwrap()
+}
+
+/// Naive reference turning each cube on and off one by one
+fn reference_count_on(steps: &[Step]) -> usize {
+    let mut cubes = HashSet::new();
+    for (on, [x, y, z]) in steps {
+        for cube_x in x[0]..=x[1] {
+            for cube_y in y[0]..=y[1] {
+                for cube_z in z[0]..=z[1] {
+                    if *on {
+                        cubes.insert((cube_x, cube_y, cube_z));
+                    } else {
+                        cubes.remove(&(cube_x, cube_y, cube_z));
+                    }
+                }
+            }
+        }
+    }
+
+    cubes.len()
+}
+
+#[test]
+fn cuboid_splitting_matches_reference() {
+    for seed in 0..200 {
+        let steps = random_steps(&mut Rng::new(seed), 12, 10);
+
+        assert_eq!(
+            parse(&steps).part_2_count_on(),
+            reference_count_on(&steps),
+            "seed {}",
+            seed
+        );
+    }
+}
+
+#[test]
+fn voxel_scanning_matches_cuboid_splitting() {
+    for seed in 0..20 {
+        let steps = random_steps(&mut Rng::new(seed), 50, 30);
+        let procedure = parse(&steps);
+
+        let count = procedure.part_1_count_on();
+        assert_eq!(count, procedure.part_2_count_on(), "seed {}", seed);
+        assert_eq!(count, reference_count_on(&steps), "seed {}", seed);
+    }
+}
@@ -0,0 +1,27 @@
+use aoc_common::{Answer, Solution};
+use day_22::models::BootProcedure;
+use day_22::Day22;
+
+const EXAMPLE: &str = include_str!("../dataset/example.txt");
+
+#[test]
+fn part_1_example_case() {
+    let input = BootProcedure::parse_string(EXAMPLE.to_string()).unwrap();
+
+    assert_eq!(input.part_1_count_on(), 474140);
+}
+
+#[test]
+fn part_2_example_case() {
+    let input = BootProcedure::parse_string(EXAMPLE.to_string()).unwrap();
+
+    assert_eq!(input.part_2_count_on(), 2758514936282235);
+}
+
+#[test]
+fn solution_example_case() {
+    let input = Day22::parse(EXAMPLE.to_string()).unwrap();
+
+    assert_eq!(Day22::part_1(&input), Answer::Number(474140));
+    assert_eq!(Day22::part_2(&input), Answer::Number(2758514936282235));
+}
@@ -0,0 +1,36 @@
+use aoc_common::Rng;
+
+/// Generates a burrow with the 8 amphipods shuffled in the rooms. The burrow always has the same
+/// shape, so the size is ignored.
+pub fn generate(_size: usize, rng: &mut Rng) -> String {
+    let mut amphipods = "AABBCCDD".chars().collect::<Vec<_>>();
+    rng.shuffle(&mut amphipods);
+    let row = |row: &[char]| {
+        row.iter()
+            .map(|amphipod| amphipod.to_string())
+            .collect::<Vec<_>>()
+            .join("#")
+    };
+
+    format!(
+        "#############\n#...........#\n###{}###\n  #{}#\n  #########\n",
+        row(&amphipods[..4]),
+        row(&amphipods[4..])
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::generator::generate;
+    use crate::Day23;
+    use aoc_common::{Answer, Rng, Solution};
+
+    #[test]
+    fn generate_valid_input() {
+        for seed in 0..3 {
+            let input = Day23::parse(generate(1, &mut Rng::new(seed))).unwrap();
+
+            assert_ne!(Day23::part_1(&input), Answer::Number(0));
+        }
+    }
+}
@@ -0,0 +1,189 @@
+use crate::models_part_1::AmphipodState::LastMoved;
+use crate::{models_part_1, models_part_2, Burrow, Day23};
+use aoc_common::{input_lines, ParseError, Solution};
+
+/// Rows folded in the part 1 diagram, inserted between its two room rows in part 2
+const FOLDED_ROWS: [[usize; 4]; 2] = [
+    [3, 2, 1, 0],
+    [3, 1, 0, 2],
+];
+
+/// Parses the diagram of the burrow, whose rooms are unfolded for part 2
+pub fn parse_string(content: String) -> Result<Burrow, ParseError> {
+    let lines = input_lines(Day23::DAY, &content).collect::<Vec<_>>();
+    let nth_line = |index: usize, expected: &str| {
+        lines.get(index).ok_or_else(|| ParseError::new(Day23::DAY, index + 1, 1, expected))
+    };
+
+    let hallway = nth_line(1, "the hallway")?;
+    if hallway.text != "#...........#" {
+        return Err(hallway.error(hallway.text, "an empty hallway `#...........#`"));
+    }
+
+    // Amphipod types (A = 0 to D = 3) of each room, from the top row to the bottom one
+    let mut rows = [[0; 4]; 2];
+    let mut counts = [0; 4];
+    for (depth, row) in rows.iter_mut().enumerate() {
+        let line = nth_line(2 + depth, "a room line")?;
+        for (room, amphipod) in row.iter_mut().enumerate() {
+            let column = 3 + 2 * room;
+            *amphipod = match line.text.as_bytes().get(column) {
+                Some(c @ b'A'..=b'D') => (c - b'A') as usize,
+                _ => return Err(line.error_at(column.min(line.text.len()), "an amphipod from `A` to `D`")),
+            };
+            counts[*amphipod] += 1;
+        }
+    }
+    if counts != [2; 4] {
+        return Err(lines[3].error_at_end("2 amphipods of each type"));
+    }
+
+    Ok(Burrow {
+        part_1: folded_state(&rows),
+        part_2: unfolded_state(&[rows[0], FOLDED_ROWS[0], FOLDED_ROWS[1], rows[1]]),
+    })
+}
+
+/// Amphipods of the given room rows as (room, depth, type, already in its final place), in the
+/// positions order of the game states
+fn amphipods(rows: &[[usize; 4]]) -> impl Iterator<Item = (usize, usize, usize, bool)> + '_ {
+    (0..4).flat_map(move |room| (0..rows.len()).map(move |depth| {
+        let amphipod = rows[depth][room];
+        let in_place = rows[depth..].iter().all(|row| row[room] == room);
+        (room, depth, amphipod, in_place)
+    }))
+}
+
+fn folded_state(rows: &[[usize; 4]; 2]) -> models_part_1::GameState {
+    let mut state = models_part_1::GameState::default();
+    let mut positions = [0u16; 4];
+    let mut counts = [0; 4];
+    for (room, depth, amphipod, in_place) in amphipods(rows) {
+        positions[amphipod] |= 1 << (2 + 3 * room + depth);
+        if in_place {
+            state.amphipod_states[2 * amphipod + counts[amphipod]] = LastMoved;
+        }
+        counts[amphipod] += 1;
+    }
+
+    state.amphipods_position_a = positions[0];
+    state.amphipods_position_b = positions[1];
+    state.amphipods_position_c = positions[2];
+    state.amphipods_position_d = positions[3];
+    state
+}
+
+fn unfolded_state(rows: &[[usize; 4]; 4]) -> models_part_2::GameState {
+    let mut state = models_part_2::GameState::default();
+    let mut positions = [[0u32; 4]; 4];
+    let mut counts = [0; 4];
+    for (room, depth, amphipod, in_place) in amphipods(rows) {
+        positions[amphipod][counts[amphipod]] = 1 << (2 + 5 * room + depth);
+        if in_place {
+            state.amphipod_states[4 * amphipod + counts[amphipod]] = LastMoved;
+        }
+        counts[amphipod] += 1;
+    }
+
+    state.amphipods_position_a = positions[0];
+    state.amphipods_position_b = positions[1];
+    state.amphipods_position_c = positions[2];
+    state.amphipods_position_d = positions[3];
+    state
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::models_part_1::AmphipodState::{LastMoved, NotMoved};
+    use crate::input_parsing::parse_string;
+    use crate::{models_part_1, models_part_2};
+    use aoc_common::ParseError;
+
+    #[test]
+    fn parse_burrow() {
+        let burrow = parse_string("#############
+#...........#
+###B#A#A#D###
+  #D#C#B#C#
+  #########
+".to_string()).unwrap();
+
+        assert_eq!(burrow.part_1, models_part_1::GameState {
+            amphipods_position_a: (1 << 5) | (1 << 8),
+            amphipods_position_b: (1 << 2) | (1 << 9),
+            amphipods_position_c: (1 << 6) | (1 << 12),
+            amphipods_position_d: (1 << 3) | (1 << 11),
+            amphipod_states: [
+                NotMoved, NotMoved,
+                NotMoved, NotMoved,
+                NotMoved, NotMoved,
+                NotMoved, NotMoved,
+            ],
+            energy_used: 0,
+        });
+        assert_eq!(burrow.part_2, models_part_2::GameState {
+            amphipods_position_a: [(1 << 7), (1 << 12), (1 << 14), (1 << 18)],
+            amphipods_position_b: [(1 << 2), (1 << 9), (1 << 13), (1 << 15)],
+            amphipods_position_c: [(1 << 8), (1 << 10), (1 << 19), (1 << 20)],
+            amphipods_position_d: [(1 << 3), (1 << 4), (1 << 5), (1 << 17)],
+            amphipod_states: [
+                NotMoved, NotMoved, NotMoved, NotMoved,
+                NotMoved, NotMoved, NotMoved, NotMoved,
+                NotMoved, NotMoved, NotMoved, NotMoved,
+                NotMoved, NotMoved, NotMoved, NotMoved,
+            ],
+            energy_used: 0,
+        });
+    }
+
+    #[test]
+    fn parse_example_burrow() {
+        let burrow = parse_string("#############
+#...........#
+###B#C#B#D###
+  #A#D#C#A#
+  #########
+".to_string()).unwrap();
+
+        // Amphipods already at the bottom of their own room never need to move
+        assert_eq!(burrow.part_1.amphipod_states, [
+            LastMoved, NotMoved,
+            NotMoved, NotMoved,
+            NotMoved, LastMoved,
+            NotMoved, NotMoved,
+        ]);
+        assert_eq!(burrow.part_2, models_part_2::GameState {
+            amphipods_position_a: [(1 << 5), (1 << 14), (1 << 18), (1 << 20)],
+            amphipods_position_b: [(1 << 2), (1 << 9), (1 << 12), (1 << 13)],
+            amphipods_position_c: [(1 << 7), (1 << 8), (1 << 15), (1 << 19)],
+            amphipods_position_d: [(1 << 3), (1 << 4), (1 << 10), (1 << 17)],
+            amphipod_states: [
+                LastMoved, NotMoved, NotMoved, NotMoved,
+                NotMoved, NotMoved, NotMoved, NotMoved,
+                NotMoved, NotMoved, LastMoved, NotMoved,
+                NotMoved, NotMoved, NotMoved, NotMoved,
+            ],
+            energy_used: 0,
+        });
+    }
+
+    #[test]
+    fn parse_invalid_burrow() {
+        assert_eq!(
+            parse_string("#############\n#.....A.....#\n".to_string()).err(),
+            Some(ParseError::new(23, 2, 1, "an empty hallway `#...........#`"))
+        );
+        assert_eq!(
+            parse_string("#############\n#...........#\n###B#A#E#D###\n".to_string()).err(),
+            Some(ParseError::new(23, 3, 8, "an amphipod from `A` to `D`"))
+        );
+        assert_eq!(
+            parse_string("#############\n#...........#\n###B#A#A#D###\n".to_string()).err(),
+            Some(ParseError::new(23, 4, 1, "a room line"))
+        );
+        assert_eq!(
+            parse_string("#############\n#...........#\n###B#A#A#D###\n  #D#C#B#A#\n".to_string()).err(),
+            Some(ParseError::new(23, 4, 12, "2 amphipods of each type"))
+        );
+    }
+}
@@ -0,0 +1,42 @@
+use crate::input_parsing::parse_string;
+use aoc_common::{Answer, Generator, ParseError, Rng, Solution};
+
+pub mod generator;
+pub mod input_parsing;
+pub mod models_part_1;
+mod consts;
+pub mod models_part_2;
+
+/// Initial state of the burrow, for both the folded (part 1) and unfolded (part 2) diagrams
+#[derive(Debug, Eq, PartialEq)]
+pub struct Burrow {
+    part_1: models_part_1::GameState,
+    part_2: models_part_2::GameState,
+}
+
+pub struct Day23;
+
+impl Solution for Day23 {
+    const DAY: u8 = 23;
+    type Input = Burrow;
+
+    fn parse(content: String) -> Result<Burrow, ParseError> {
+        parse_string(content)
+    }
+
+    fn part_1(input: &Burrow) -> Answer {
+        models_part_1::Optimizer::new().optimize(input.part_1).into()
+    }
+
+    fn part_2(input: &Burrow) -> Answer {
+        models_part_2::Optimizer::new().optimize(input.part_2).into()
+    }
+}
+
+impl Generator for Day23 {
+    const DEFAULT_SIZE: usize = 1;
+
+    fn generate(size: usize, rng: &mut Rng) -> String {
+        generator::generate(size, rng)
+    }
+}
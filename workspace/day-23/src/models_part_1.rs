@@ -1,7 +1,7 @@
 use crate::consts::DISTANCES;
 use crate::models_part_1::AmphipodState::{FirstMoved, LastMoved, NotMoved};
 
-#[derive(Debug, Copy, Clone)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
 pub struct GameState {
     /// In order, positions of A1, A2, B1, B2, C1, C2, D1, D2
     ///
@@ -33,6 +33,7 @@ impl Default for GameState {
 }
 
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[allow(clippy::enum_variant_names)]
 pub enum AmphipodState {
     NotMoved,
     FirstMoved,
@@ -69,13 +70,6 @@ fn get_amphipod_box_mask(amphipod_index: usize) -> u16 {
 }
 
 impl GameState {
-    fn print_debug(&self) {
-        for i in 0..8 {
-            eprint!("{}={}, ", i, self.current_position(i));
-        }
-        eprintln!("energy={}", self.energy_used);
-    }
-
     fn amphipod_positions(&self, amphipod_index: usize) -> u16 {
         match amphipod_index {
             0 | 1 => self.amphipods_position_a,
@@ -87,7 +81,7 @@ impl GameState {
     }
 
     fn current_position(&self, amphipod_index: usize) -> u32 {
-        if amphipod_index % 2 == 0 {
+        if amphipod_index.is_multiple_of(2) {
             get_first_amphipod_position(self.amphipod_positions(amphipod_index))
         }
         else {
@@ -103,10 +97,9 @@ impl GameState {
                 let current_position = self.current_position(amphipod_index);
                 let all_amphipods = self.amphipods_position_a | self.amphipods_position_b | self.amphipods_position_c | self.amphipods_position_d;
                 // First, check if we are stuck
-                if current_position == 3 || current_position == 6 ||current_position == 9 || current_position == 12 {
-                    if all_amphipods & (1 << current_position - 1) != 0 {
-                        return 0;
-                    }
+                if (current_position == 3 || current_position == 6 || current_position == 9 || current_position == 12)
+                    && all_amphipods & (1 << (current_position - 1)) != 0 {
+                    return 0;
                 }
 
                 // First possible moves are all the hallway + the amphipod final destination
@@ -200,12 +193,15 @@ pub struct Optimizer {
     nodes: Vec<GameState>,
 }
 
+impl Default for Optimizer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl Optimizer {
     pub fn new() -> Self {
-        let mut nodes: Vec<GameState> = Vec::with_capacity(500_000);
-        unsafe {
-            nodes.set_len(500_000);
-        }
+        let nodes = vec![GameState::default(); 500_000];
 
         Optimizer {
             nodes
@@ -315,10 +311,10 @@ mod tests {
 
     #[test]
     fn example_case() {
-        /// ---------------------------------
-        /// | 0  1     4     7    10    13 14|
-        ///       | 2|  | 5|  | 8|  |11|
-        ///       | 3|  | 6|  | 9|  |12|
+        // ---------------------------------
+        // | 0  1     4     7    10    13 14|
+        //       | 2|  | 5|  | 8|  |11|
+        //       | 3|  | 6|  | 9|  |12|
         let initial_state = GameState {
             amphipods_position_a: (1 << 3) | (1 << 12),
             amphipods_position_b: (1 << 2) | (1 << 8),
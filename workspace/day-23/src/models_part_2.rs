@@ -1,9 +1,11 @@
-use std::collections::BinaryHeap;
+// Bit masks are grouped by hallway spots and rooms rather than by nibbles
+#![allow(clippy::unusual_byte_groupings)]
+
 use crate::consts::{DISTANCES_2};
 use crate::models_part_1::AmphipodState;
 use crate::models_part_1::AmphipodState::{FirstMoved, LastMoved, NotMoved};
 
-#[derive(Debug, Copy, Clone)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
 pub struct GameState {
     /// In order, positions of A1, A2, B1, B2, C1, C2, D1, D2
     ///
@@ -44,23 +46,23 @@ const BIT_MASK_DESERT_BOX :u32 = 0b000000000_00_1111_0_0000_0_0000_0_0000_00;
 
 fn get_amphipod_box_mask(amphipod_index: usize) -> u32 {
     match amphipod_index {
-        0  | 1  | 2  | 3  => BIT_MASK_AMBER_BOX,
-        4  | 5  | 6  | 7  => BIT_MASK_BRONZE_BOX,
-        8  | 9  | 10 | 11 => BIT_MASK_COPPER_BOX,
-        12 | 13 | 14 | 15 => BIT_MASK_DESERT_BOX,
+        0..=3  => BIT_MASK_AMBER_BOX,
+        4..=7  => BIT_MASK_BRONZE_BOX,
+        8..=11 => BIT_MASK_COPPER_BOX,
+        12..=15 => BIT_MASK_DESERT_BOX,
         _ => panic!()
     }
 }
 
 fn is_stuck(all_amphipods: u32, current_position: u32) -> bool {
     if current_position == 3 || current_position == 8 || current_position == 13 || current_position == 18 {
-        all_amphipods & (1 << current_position - 1) != 0
+        all_amphipods & (1 << (current_position - 1)) != 0
     }
     else if current_position == 4 || current_position == 9 || current_position == 14 || current_position == 19 {
-        all_amphipods & (1 << current_position - 1) != 0 || all_amphipods & (1 << current_position - 2) != 0
+        all_amphipods & (1 << (current_position - 1)) != 0 || all_amphipods & (1 << (current_position - 2)) != 0
     }
     else if current_position == 5 || current_position == 10 || current_position == 15 || current_position == 20 {
-        all_amphipods & (1 << current_position - 1) != 0 || all_amphipods & (1 << current_position - 2) != 0 || all_amphipods & (1 << current_position - 3) != 0
+        all_amphipods & (1 << (current_position - 1)) != 0 || all_amphipods & (1 << (current_position - 2)) != 0 || all_amphipods & (1 << (current_position - 3)) != 0
     }
     else {
         false
@@ -68,19 +70,12 @@ fn is_stuck(all_amphipods: u32, current_position: u32) -> bool {
 }
 
 impl GameState {
-    fn print_debug(&self) {
-        for i in 0..16 {
-            eprint!("{}={}, ", i, self.current_position(i));
-        }
-        eprintln!("energy={}", self.energy_used);
-    }
-
     fn amphipod_positions(&self, amphipod_index: usize) -> u32 {
         match amphipod_index {
-            0  | 1  | 2  | 3  => self.amphipods_position_a[0] | self.amphipods_position_a[1] | self.amphipods_position_a[2] | self.amphipods_position_a[3],
-            4  | 5  | 6  | 7  => self.amphipods_position_b[0] | self.amphipods_position_b[1] | self.amphipods_position_b[2] | self.amphipods_position_b[3],
-            8  | 9  | 10 | 11 => self.amphipods_position_c[0] | self.amphipods_position_c[1] | self.amphipods_position_c[2] | self.amphipods_position_c[3],
-            12 | 13 | 14 | 15 => self.amphipods_position_d[0] | self.amphipods_position_d[1] | self.amphipods_position_d[2] | self.amphipods_position_d[3],
+            0..=3  => self.amphipods_position_a[0] | self.amphipods_position_a[1] | self.amphipods_position_a[2] | self.amphipods_position_a[3],
+            4..=7  => self.amphipods_position_b[0] | self.amphipods_position_b[1] | self.amphipods_position_b[2] | self.amphipods_position_b[3],
+            8..=11 => self.amphipods_position_c[0] | self.amphipods_position_c[1] | self.amphipods_position_c[2] | self.amphipods_position_c[3],
+            12..=15 => self.amphipods_position_d[0] | self.amphipods_position_d[1] | self.amphipods_position_d[2] | self.amphipods_position_d[3],
             _ => panic!()
         }
     }
@@ -177,10 +172,10 @@ impl GameState {
 
                 // If there is an other kind of amphipod in its box, do not allow entrance to the box
                 let other_amphipods = match amphipod_index {
-                    0  | 1  | 2  | 3  => self.amphipod_positions(4) | self.amphipod_positions(8) | self.amphipod_positions(12),
-                    4  | 5  | 6  | 7  => self.amphipod_positions(0) | self.amphipod_positions(8) | self.amphipod_positions(12),
-                    8  | 9  | 10 | 11 => self.amphipod_positions(0) | self.amphipod_positions(4) | self.amphipod_positions(12),
-                    12 | 13 | 14 | 15 => self.amphipod_positions(0) | self.amphipod_positions(4) | self.amphipod_positions(8),
+                    0..=3  => self.amphipod_positions(4) | self.amphipod_positions(8) | self.amphipod_positions(12),
+                    4..=7  => self.amphipod_positions(0) | self.amphipod_positions(8) | self.amphipod_positions(12),
+                    8..=11 => self.amphipod_positions(0) | self.amphipod_positions(4) | self.amphipod_positions(12),
+                    12..=15 => self.amphipod_positions(0) | self.amphipod_positions(4) | self.amphipod_positions(8),
                     _ => panic!()
                 };
                 if other_amphipods & box_mask != 0 {
@@ -189,18 +184,18 @@ impl GameState {
                 else {
                     // Keep only the move that goes deepest in the box
                     let offset = match amphipod_index {
-                        0  | 1  | 2  | 3  => 2,
-                        4  | 5  | 6  | 7  => 7,
-                        8  | 9  | 10 | 11 => 12,
-                        12 | 13 | 14 | 15 => 17,
+                        0..=3  => 2,
+                        4..=7  => 7,
+                        8..=11 => 12,
+                        12..=15 => 17,
                         _ => panic!()
                     };
 
                     if all_amphipods & (1 << (offset + 3)) == 0 {
-                        possible_moves &= !((1 << offset) | (1 << offset + 1) | (1 << offset + 2));
+                        possible_moves &= !((1 << offset) | (1 << (offset + 1)) | (1 << (offset + 2)));
                     }
                     else if all_amphipods & (1 << (offset + 2)) == 0 {
-                        possible_moves &= !((1 << offset) | (1 << offset + 1));
+                        possible_moves &= !((1 << offset) | (1 << (offset + 1)));
                     }
                     else if all_amphipods & (1 << (offset + 1)) == 0 {
                         possible_moves &= !(1 << offset);
@@ -239,7 +234,7 @@ fn energy_for_move(initial_position: u32, final_position: u32) -> usize {
 }
 
 fn is_box(position: u32) -> bool {
-    (position >= 2 && position <= 5) || (position >= 7 && position <= 10) || (position >= 12 && position <= 15) || (position >= 17 && position <= 20)
+    (2..=5).contains(&position) || (7..=10).contains(&position) || (12..=15).contains(&position) || (17..=20).contains(&position)
 }
 
 #[derive(Default)]
@@ -250,10 +245,7 @@ pub struct Optimizer {
 impl Optimizer {
 
     pub fn new() -> Self {
-        let mut nodes: Vec<GameState> = Vec::with_capacity(500_000);
-        unsafe {
-            nodes.set_len(500_000);
-        }
+        let nodes = vec![GameState::default(); 500_000];
 
         Optimizer {
             nodes
@@ -286,7 +278,7 @@ impl Optimizer {
 
                         self.nodes[current_node_index] = current_state;
                         match i {
-                            0 | 1 | 2 | 3 => {
+                            0..=3 => {
                                 self.nodes[current_node_index].energy_used += energy_for_move(current_position, possible_move);
                                 if self.nodes[current_node_index].energy_used < best_score {
                                     self.nodes[current_node_index].update_position(i,1 << possible_move);
@@ -299,7 +291,7 @@ impl Optimizer {
                                     current_node_index += 1;
                                 }
                             }
-                            4 | 5 | 6 | 7 => {
+                            4..=7 => {
                                 self.nodes[current_node_index].energy_used += 10 * energy_for_move(current_position, possible_move);
                                 if self.nodes[current_node_index].energy_used < best_score {
                                     self.nodes[current_node_index].update_position(i,1 << possible_move);
@@ -312,7 +304,7 @@ impl Optimizer {
                                     current_node_index += 1;
                                 }
                             }
-                            8 | 9 | 10 | 11 => {
+                            8..=11 => {
                                 self.nodes[current_node_index].energy_used += 100 * energy_for_move(current_position, possible_move);
                                 if self.nodes[current_node_index].energy_used < best_score {
                                     self.nodes[current_node_index].update_position(i,1 << possible_move);
@@ -325,7 +317,7 @@ impl Optimizer {
                                     current_node_index += 1;
                                 }
                             }
-                            12 | 13 | 14 | 15 => {
+                            12..=15 => {
                                 self.nodes[current_node_index].energy_used += 1000 * energy_for_move(current_position, possible_move);
                                 if self.nodes[current_node_index].energy_used < best_score {
                                     self.nodes[current_node_index].update_position(i,1 << possible_move);
@@ -355,7 +347,7 @@ impl Optimizer {
 #[cfg(test)]
 mod tests {
     use crate::models_part_2::AmphipodState::{FirstMoved, LastMoved, NotMoved};
-    use crate::models_part_2::{BIT_MASK_HALLWAY, GameState, Optimizer};
+    use crate::models_part_2::{GameState, Optimizer};
 
     #[test]
     fn example_case() {
@@ -477,6 +469,6 @@ mod tests {
             energy_used: 0,
         };
 
-        assert_eq!(initial_state.is_finished(), true);
+        assert!(initial_state.is_finished());
     }
 }
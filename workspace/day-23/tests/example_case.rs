@@ -0,0 +1,52 @@
+use aoc_common::{Answer, Solution};
+use day_23::models_part_1::AmphipodState::{LastMoved, NotMoved};
+use day_23::{models_part_1, models_part_2, Day23};
+
+const EXAMPLE: &str = include_str!("../dataset/example.txt");
+
+#[test]
+fn part_1_example_case() {
+    let initial_state = models_part_1::GameState {
+        amphipods_position_a: (1 << 3) | (1 << 12),
+        amphipods_position_b: (1 << 2) | (1 << 8),
+        amphipods_position_c: (1 << 5) | (1 << 9),
+        amphipods_position_d: (1 << 6) | (1 << 11),
+        amphipod_states: [
+            LastMoved, NotMoved, NotMoved, NotMoved, NotMoved, LastMoved, NotMoved, NotMoved,
+        ],
+        energy_used: 0,
+    };
+
+    assert_eq!(
+        models_part_1::Optimizer::new().optimize(initial_state),
+        12521
+    );
+}
+
+#[test]
+fn part_2_example_case() {
+    let initial_state = models_part_2::GameState {
+        amphipods_position_a: [(1 << 5), (1 << 14), (1 << 18), (1 << 20)],
+        amphipods_position_b: [(1 << 2), (1 << 9), (1 << 12), (1 << 13)],
+        amphipods_position_c: [(1 << 7), (1 << 8), (1 << 15), (1 << 19)],
+        amphipods_position_d: [(1 << 3), (1 << 4), (1 << 10), (1 << 17)],
+        amphipod_states: [
+            LastMoved, NotMoved, NotMoved, NotMoved, NotMoved, NotMoved, NotMoved, NotMoved,
+            NotMoved, NotMoved, LastMoved, NotMoved, NotMoved, NotMoved, NotMoved, NotMoved,
+        ],
+        energy_used: 0,
+    };
+
+    assert_eq!(
+        models_part_2::Optimizer::new().optimize(initial_state),
+        44169
+    );
+}
+
+#[test]
+fn solution_example_case() {
+    let input = Day23::parse(EXAMPLE.to_string()).unwrap();
+
+    assert_eq!(Day23::part_1(&input), Answer::Number(12521));
+    assert_eq!(Day23::part_2(&input), Answer::Number(44169));
+}
@@ -0,0 +1,63 @@
+use crate::input_parsing::DIGIT_BLOCK;
+use aoc_common::Rng;
+use std::fmt::Write;
+
+/// Generates a MONAD program accepting some model numbers. Like in the puzzle inputs, each digit
+/// either pushes itself onto z as a base 26 digit, or pops the digit pushed by its matching one and
+/// requires a fixed difference with it. The program always checks 14 digits, so the size is
+/// ignored.
+pub fn generate(_size: usize, rng: &mut Rng) -> String {
+    let mut parameters = [[0; 3]; 14];
+    let mut pushed = Vec::new();
+    let mut pushes_left = 7;
+    for digit in 0..14 {
+        if pushes_left > 0 && (pushed.is_empty() || rng.chance(0.5)) {
+            parameters[digit] = [1, rng.range(10..=16), rng.range(0..=16)];
+            pushed.push(digit);
+            pushes_left -= 1;
+        } else {
+            let pushed_c = parameters[pushed.pop().unwrap()][2];
+            let difference = rng.range(-8..=8);
+            parameters[digit] = [26, difference - pushed_c, rng.range(0..=16)];
+        }
+    }
+
+    let mut content = String::new();
+    for digit_parameters in &parameters {
+        for (instruction, parameter) in &DIGIT_BLOCK {
+            match parameter {
+                Some(index) => {
+                    writeln!(content, "{}{}", instruction, digit_parameters[*index]).unwrap()
+                }
+                None => writeln!(content, "{}", instruction).unwrap(),
+            }
+        }
+    }
+
+    content
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::generator::generate;
+    use crate::models::Solver;
+    use crate::Day24;
+    use aoc_common::{Rng, Solution};
+
+    #[test]
+    fn generate_valid_input() {
+        for seed in 0..5 {
+            let input = Day24::parse(generate(14, &mut Rng::new(seed))).unwrap();
+
+            assert_eq!(
+                input
+                    .iter()
+                    .filter(|parameters| parameters[0] == 26)
+                    .count(),
+                7
+            );
+            assert!(Solver::new(input).maximize(0, 0) > 0);
+            assert!(Solver::new(input).minimize(0, 0) > 0);
+        }
+    }
+}
@@ -0,0 +1,127 @@
+use aoc_common::{input_lines, ParseError, Solution};
+use crate::Day24;
+use crate::models::Parameters;
+
+/// Instructions checking each digit, `None` standing for the a, b and c parameters
+pub(crate) const DIGIT_BLOCK: [(&str, Option<usize>); 18] = [
+    ("inp w", None),
+    ("mul x 0", None),
+    ("add x z", None),
+    ("mod x 26", None),
+    ("div z ", Some(0)),
+    ("add x ", Some(1)),
+    ("eql x w", None),
+    ("eql x 0", None),
+    ("mul y 0", None),
+    ("add y 25", None),
+    ("mul y x", None),
+    ("add y 1", None),
+    ("mul z y", None),
+    ("mul y 0", None),
+    ("add y w", None),
+    ("add y ", Some(2)),
+    ("mul y x", None),
+    ("add z y", None),
+];
+
+/// Extracts the parameters of each digit from the MONAD program
+pub fn parse_string(content: String) -> Result<Parameters, ParseError> {
+    let mut parameters = [[0; 3]; 14];
+    let mut lines = input_lines(Day24::DAY, &content).filter(|line| !line.text.is_empty());
+
+    for (digit, digit_parameters) in parameters.iter_mut().enumerate() {
+        for (offset, (instruction, parameter)) in DIGIT_BLOCK.iter().enumerate() {
+            let line = lines.next()
+                .ok_or_else(|| ParseError::new(Day24::DAY, 18 * digit + offset + 1, 1, format!("`{}`", instruction.trim_end())))?;
+
+            match parameter {
+                Some(index) => {
+                    let value = line.strip_prefix(instruction)?;
+                    digit_parameters[*index] = line.parse(value, "a number")?;
+                    if *index == 0 && digit_parameters[0] != 1 && digit_parameters[0] != 26 {
+                        return Err(line.error(value, "`1` or `26`"));
+                    }
+                }
+                None => {
+                    if line.text != *instruction {
+                        return Err(line.error(line.text, format!("`{}`", instruction)));
+                    }
+                }
+            }
+        }
+    }
+
+    if let Some(line) = lines.next() {
+        return Err(line.error(line.text, "the end of the program"));
+    }
+
+    Ok(parameters)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::input_parsing::parse_string;
+    use aoc_common::ParseError;
+
+    const FIRST_DIGIT: &str = "inp w
+mul x 0
+add x z
+mod x 26
+div z 1
+add x 11
+eql x w
+eql x 0
+mul y 0
+add y 25
+mul y x
+add y 1
+mul z y
+mul y 0
+add y w
+add y 6
+mul y x
+add z y
+";
+
+    #[test]
+    fn parse_program() {
+        let parameters = parse_string(include_str!("../dataset/data.txt").to_string()).unwrap();
+
+        assert_eq!(parameters, [
+            [1, 11, 6],
+            [1, 11, 14],
+            [1, 15, 13],
+            [26, -14, 1],
+            [1, 10, 6],
+            [26, 0, 13],
+            [26, -6, 6],
+            [1, 13, 3],
+            [26, -3, 8],
+            [1, 13, 14],
+            [1, 15, 4],
+            [26, -2, 7],
+            [26, -9, 15],
+            [26, -2, 1],
+        ]);
+    }
+
+    #[test]
+    fn parse_invalid_program() {
+        assert_eq!(
+            parse_string(FIRST_DIGIT.replace("add x z", "add x y")).err(),
+            Some(ParseError::new(24, 3, 1, "`add x z`"))
+        );
+        assert_eq!(
+            parse_string(FIRST_DIGIT.replace("div z 1", "div z 2")).err(),
+            Some(ParseError::new(24, 5, 7, "`1` or `26`"))
+        );
+        assert_eq!(
+            parse_string(FIRST_DIGIT.replace("add y 6", "add y six")).err(),
+            Some(ParseError::new(24, 16, 7, "a number"))
+        );
+        assert_eq!(
+            parse_string(FIRST_DIGIT.to_string()).err(),
+            Some(ParseError::new(24, 19, 1, "`inp w`"))
+        );
+    }
+}
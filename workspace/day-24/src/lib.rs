@@ -0,0 +1,36 @@
+use crate::input_parsing::parse_string;
+use crate::models::{Parameters, Solver};
+use aoc_common::{Answer, Generator, ParseError, Rng, Solution};
+
+pub mod generator;
+pub mod input_parsing;
+pub mod models;
+
+pub struct Day24;
+
+impl Solution for Day24 {
+    const DAY: u8 = 24;
+    type Input = Parameters;
+
+    fn parse(content: String) -> Result<Parameters, ParseError> {
+        parse_string(content)
+    }
+
+    fn part_1(input: &Parameters) -> Answer {
+        let mut solver = Solver::new(*input);
+        solver.maximize(0, 0).into()
+    }
+
+    fn part_2(input: &Parameters) -> Answer {
+        let mut solver = Solver::new(*input);
+        solver.minimize(0, 0).into()
+    }
+}
+
+impl Generator for Day24 {
+    const DEFAULT_SIZE: usize = 14;
+
+    fn generate(size: usize, rng: &mut Rng) -> String {
+        generator::generate(size, rng)
+    }
+}
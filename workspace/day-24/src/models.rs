@@ -0,0 +1,96 @@
+use std::collections::HashMap;
+
+/// The list of parameters a, b and c of each digit
+pub type Parameters = [[i64; 3]; 14];
+
+/// w is the input number
+/// a is the value from line 5
+/// b is the value from line 6
+/// c is the value from line 16
+///
+/// This function is a high level version of the assembly code
+pub fn check_digit(w: i64, a: i64, b: i64, c: i64, previous_z: i64) -> i64 {
+    if previous_z % 26 + b != w { // x = 1
+        26 * previous_z / a + w + c
+    }
+    else { // x = 0
+        previous_z / a
+    }
+}
+
+pub struct Solver {
+    intermediate_steps: HashMap<(usize, i64), i64>,
+    parameters: Parameters,
+    /// z must be below 26 ^ (remaining divisions by 26) at each depth to get back to 0, as only
+    /// these steps can shrink it
+    z_limits: [i64; 15],
+}
+
+impl Solver {
+    pub fn new(parameters: Parameters) -> Self {
+        let mut z_limits = [1; 15];
+        for depth in (0..14).rev() {
+            z_limits[depth] = z_limits[depth + 1] * if parameters[depth][0] == 26 { 26 } else { 1 };
+        }
+
+        Solver {
+            intermediate_steps: Default::default(),
+            parameters,
+            z_limits,
+        }
+    }
+
+    /// Returns the largest digits accepted by MONAD from `depth` given the previous z, or -1 if none
+    pub fn maximize(&mut self, depth: usize, pz: i64) -> i64 {
+        if pz >= self.z_limits[depth] {
+            -1
+        }
+        else if depth == 14 {
+            0
+        }
+        else if let Some(n) = self.intermediate_steps.get(&(depth, pz)) {
+            *n
+        }
+        else {
+            let mut best_n = -1;
+            for digit in (1..=9).rev() {
+                let z = check_digit(digit, self.parameters[depth][0], self.parameters[depth][1], self.parameters[depth][2], pz);
+                let n = self.maximize(depth + 1, z);
+
+                if n >= 0 {
+                    best_n = 10i64.pow(13 - depth as u32) * digit + n;
+                    break;
+                }
+            }
+            self.intermediate_steps.insert((depth, pz), best_n);
+            best_n
+        }
+    }
+
+    /// Returns the smallest digits accepted by MONAD from `depth` given the previous z, or -1 if none
+    pub fn minimize(&mut self, depth: usize, pz: i64) -> i64 {
+        if pz >= self.z_limits[depth] {
+            -1
+        }
+        else if depth == 14 {
+            0
+        }
+        else if let Some(n) = self.intermediate_steps.get(&(depth, pz)) {
+            *n
+        }
+        else {
+            let mut best_n = -1;
+            for digit in 1..=9 {
+                let z = check_digit(digit, self.parameters[depth][0], self.parameters[depth][1], self.parameters[depth][2], pz);
+                let n = self.minimize(depth + 1, z);
+
+                if n >= 0 {
+                    best_n = 10i64.pow(13 - depth as u32) * digit + n;
+                    break;
+                }
+            }
+            self.intermediate_steps.insert((depth, pz), best_n);
+            best_n
+        }
+    }
+}
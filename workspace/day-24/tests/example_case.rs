@@ -0,0 +1,58 @@
+use aoc_common::{Answer, Solution};
+use day_24::models::{check_digit, Parameters, Solver};
+use day_24::Day24;
+
+/// ALU program of the `paired_digits([0, 8, 0, 0, 0, 0, 3])` parameters
+const EXAMPLE: &str = include_str!("../dataset/example.txt");
+
+/// MONAD-like program made of 7 push/pop pairs: each popped digit must be equal to the pushed one
+/// minus the offset of its pair
+fn paired_digits(offsets: [i64; 7]) -> Parameters {
+    let mut parameters = [[0; 3]; 14];
+    for (pair, offset) in offsets.iter().enumerate() {
+        parameters[2 * pair] = [1, 10, 0];
+        parameters[2 * pair + 1] = [26, -offset, 0];
+    }
+    parameters
+}
+
+#[test]
+fn check_digit_push_pop() {
+    let z = check_digit(9, 1, 10, 0, 0);
+    assert_eq!(z, 9);
+    assert_eq!(check_digit(1, 26, -8, 0, z), 0);
+    assert_ne!(check_digit(2, 26, -8, 0, z), 0);
+}
+
+#[test]
+fn part_1_example_case() {
+    let mut solver = Solver::new(paired_digits([0, 8, 0, 0, 0, 0, 3]));
+
+    assert_eq!(solver.maximize(0, 0), 99919999999996);
+}
+
+#[test]
+fn part_2_example_case() {
+    let mut solver = Solver::new(paired_digits([0, 8, 0, 0, 0, 0, 3]));
+
+    assert_eq!(solver.minimize(0, 0), 11911111111141);
+}
+
+#[test]
+fn solution_example_case() {
+    let input = Day24::parse(EXAMPLE.to_string()).unwrap();
+
+    assert_eq!(input, paired_digits([0, 8, 0, 0, 0, 0, 3]));
+    assert_eq!(Day24::part_1(&input), Answer::Number(99919999999996));
+    assert_eq!(Day24::part_2(&input), Answer::Number(11911111111141));
+}
+
+#[test]
+fn puzzle_input() {
+    // Without pruning the z values which can no longer get back to 0, the search of the actual
+    // puzzle runs out of memory
+    let input = Day24::parse(include_str!("../dataset/data.txt").to_string()).unwrap();
+
+    assert_eq!(Day24::part_1(&input), Answer::Number(51983999947999));
+    assert_eq!(Day24::part_2(&input), Answer::Number(11211791111365));
+}
@@ -0,0 +1,39 @@
+use crate::models::SeaCucumbers;
+use aoc_common::Rng;
+
+/// Generates a `size` × `size` sea floor, half of it covered by sea cucumbers. Some herds move
+/// forever around the toroidal sea floor, so floors that do not stop in a reasonable number of
+/// steps are generated again.
+pub fn generate(size: usize, rng: &mut Rng) -> String {
+    let size = size.max(1);
+    loop {
+        let mut content = String::new();
+        for _ in 0..size {
+            content.extend((0..size).map(|_| match rng.below(4) {
+                0 => '>',
+                1 => 'v',
+                _ => '.',
+            }));
+            content.push('\n');
+        }
+
+        let sea_cucumbers = SeaCucumbers::parse_string(content.clone()).unwrap();
+        if sea_cucumbers.steps_until_stable(10 * size).is_some() {
+            return content;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::generator::generate;
+    use crate::Day25;
+    use aoc_common::{Rng, Solution};
+
+    #[test]
+    fn generate_valid_input() {
+        let input = Day25::parse(generate(20, &mut Rng::new(1))).unwrap();
+
+        assert!(input.steps_until_stable(200).is_some());
+    }
+}
@@ -0,0 +1,41 @@
+pub mod generator;
+pub mod models;
+pub mod visualization;
+
+use crate::models::SeaCucumbers;
+use aoc_common::{Answer, FrameSink, Generator, ParseError, Rng, Solution, Visualize};
+use std::io;
+
+pub struct Day25;
+
+impl Solution for Day25 {
+    const DAY: u8 = 25;
+    type Input = SeaCucumbers;
+
+    fn parse(content: String) -> Result<SeaCucumbers, ParseError> {
+        SeaCucumbers::parse_string(content)
+    }
+
+    fn part_1(input: &SeaCucumbers) -> Answer {
+        input.part_1_stable_sea_cucumbers().into()
+    }
+
+    fn part_2(_input: &SeaCucumbers) -> Answer {
+        // The last day of the calendar only has a single puzzle
+        Answer::Empty
+    }
+}
+
+impl Generator for Day25 {
+    const DEFAULT_SIZE: usize = 137;
+
+    fn generate(size: usize, rng: &mut Rng) -> String {
+        generator::generate(size, rng)
+    }
+}
+
+impl Visualize for Day25 {
+    fn visualize(input: &SeaCucumbers, frames: &mut dyn FrameSink) -> io::Result<()> {
+        visualization::visualize(input, frames)
+    }
+}
@@ -1,19 +1,7 @@
-mod models;
+use aoc_common::run_visualized;
+use day_25::Day25;
+use std::error::Error;
 
-use std::{env, fs};
-use std::process::exit;
-use crate::models::SeaCucumbers;
-
-fn main() {
-    let args: Vec<String> = env::args().collect();
-
-    if args.len() != 2 {
-        eprintln!("Invalid arguments count");
-        exit(1);
-    }
-    let file_content =
-        fs::read_to_string(args[1].clone()).expect("Error while reading the data file");
-    let input = SeaCucumbers::parse_string(file_content);
-
-    println!("part 1: {}", input.part_1_stable_sea_cucumbers());
+fn main() -> Result<(), Box<dyn Error>> {
+    run_visualized::<Day25>()
 }
@@ -1,88 +1,88 @@
-use std::collections::{HashSet};
+use aoc_common::{input_lines, Grid, ParseError, Solution};
+use crate::Day25;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Location {
+    Empty,
+    East,
+    South,
+}
 
 pub struct SeaCucumbers {
-    east_sea_cucumbers: HashSet<(usize, usize)>,
-    south_sea_cucumbers: HashSet<(usize, usize)>,
-    width: usize,
-    height: usize,
+    pub(crate) floor: Grid<Location>,
 }
 
 impl SeaCucumbers {
-    pub fn parse_string(content: String) -> SeaCucumbers {
-        let lines = content.lines().collect::<Vec<_>>();
-        let height = lines.len();
-        let width = lines[0].len();
-
-        let mut east_sea_cucumbers = HashSet::new();
-        let mut south_sea_cucumbers = HashSet::new();
-        for (y, line) in lines.iter().enumerate() {
-            for (x, c) in line.chars().enumerate() {
-                match c {
-                    '>' => { east_sea_cucumbers.insert((x, y)); },
-                    'v' => { south_sea_cucumbers.insert((x, y)); },
-                    _ => {}
-                };
-            }
-        }
-
-        SeaCucumbers {
-            east_sea_cucumbers,
-            south_sea_cucumbers,
-            width,
-            height
-        }
+    pub fn parse_string(content: String) -> Result<SeaCucumbers, ParseError> {
+        let lines = input_lines(Day25::DAY, &content).filter(|line| !line.text.is_empty());
+        let floor = Grid::parse_chars(lines, "locations", "`>`, `v` or `.`", |c| match c {
+            '>' => Some(Location::East),
+            'v' => Some(Location::South),
+            '.' => Some(Location::Empty),
+            _ => None,
+        })?;
+
+        Ok(SeaCucumbers {
+            floor: floor.wrapping()
+        })
     }
 
     pub fn part_1_stable_sea_cucumbers(&self) -> usize {
-        let mut east_sea_cucumbers = self.east_sea_cucumbers.clone();
-        let mut south_sea_cucumbers = self.south_sea_cucumbers.clone();
+        self.steps_until_stable(usize::MAX).unwrap()
+    }
+
+    /// Returns the first step on which no sea cucumber moves, or None if they still move after
+    /// `max_steps`
+    pub fn steps_until_stable(&self, max_steps: usize) -> Option<usize> {
+        let mut floor = self.floor.clone();
 
         let mut step = 0;
         loop {
-            let mut sea_cucumber_moved = false;
-
-            let mut new_east_sea_cucumbers = HashSet::with_capacity(east_sea_cucumbers.len());
-            let mut new_south_sea_cucumbers = HashSet::with_capacity(south_sea_cucumbers.len());
-
-            for &position in &east_sea_cucumbers {
-                let new_position = ((position.0 + 1) % self.width, position.1);
-                if east_sea_cucumbers.contains(&new_position) || south_sea_cucumbers.contains(&new_position) {
-                    new_east_sea_cucumbers.insert(position);
-                }
-                else {
-                    new_east_sea_cucumbers.insert(new_position);
-                    sea_cucumber_moved = true;
-                }
-            }
-
-            for &position in &south_sea_cucumbers {
-                let new_position = (position.0, (position.1 + 1) % self.height);
-                if new_east_sea_cucumbers.contains(&new_position) || south_sea_cucumbers.contains(&new_position) {
-                    new_south_sea_cucumbers.insert(position);
-                }
-                else {
-                    new_south_sea_cucumbers.insert(new_position);
-                    sea_cucumber_moved = true;
-                }
-            }
-
-            east_sea_cucumbers = new_east_sea_cucumbers;
-            south_sea_cucumbers = new_south_sea_cucumbers;
+            let sea_cucumber_moved = move_herds(&mut floor);
 
             step += 1;
 
             if !sea_cucumber_moved {
-                break;
+                return Some(step);
+            }
+            if step == max_steps {
+                return None;
             }
         }
+    }
+}
+
+/// Moves the east facing herd, then the south facing one. Returns whether any sea cucumber moved.
+pub(crate) fn move_herds(floor: &mut Grid<Location>) -> bool {
+    let east_moved = move_herd(floor, Location::East, (1, 0));
+    let south_moved = move_herd(floor, Location::South, (0, 1));
+
+    east_moved || south_moved
+}
 
-        step
+/// Moves all the sea cucumbers of a herd at once, each one only if the location it faces is empty.
+/// Returns whether any of them moved.
+fn move_herd(floor: &mut Grid<Location>, herd: Location, direction: (i64, i64)) -> bool {
+    let moves = floor.iter()
+        .filter(|&(_, &location)| location == herd)
+        .filter_map(|(position, _)| {
+            let new_position = floor.step(position, direction)?;
+            if floor[new_position] == Location::Empty { Some((position, new_position)) } else { None }
+        })
+        .collect::<Vec<_>>();
+
+    for &(position, new_position) in &moves {
+        floor[position] = Location::Empty;
+        floor[new_position] = herd;
     }
+
+    !moves.is_empty()
 }
 
 #[cfg(test)]
 mod tests {
     use crate::SeaCucumbers;
+    use aoc_common::ParseError;
 
     #[test]
     fn example_case() {
@@ -95,8 +95,20 @@ v>v.vv.v..
 .vv..>.>v.
 v.v..>>v.v
 ....v..v.>
-".to_string());
+".to_string()).unwrap();
 
         assert_eq!(input.part_1_stable_sea_cucumbers(), 58);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn parse_invalid_sea_floor() {
+        assert_eq!(
+            SeaCucumbers::parse_string("v..\n.<.\n".to_string()).err(),
+            Some(ParseError::new(25, 2, 2, "`>`, `v` or `.`"))
+        );
+        assert_eq!(
+            SeaCucumbers::parse_string("v..\n.>\n".to_string()).err(),
+            Some(ParseError::new(25, 2, 3, "3 locations"))
+        );
+    }
+}
@@ -0,0 +1,56 @@
+use crate::models::{move_herds, Location, SeaCucumbers};
+use aoc_common::{FrameSink, Grid};
+use std::io;
+
+/// Steps shown at most when the sea cucumbers never stop moving
+const MAX_STEPS: usize = 10_000;
+
+/// Shows the sea floor step by step, until the sea cucumbers stop moving. East facing sea
+/// cucumbers are white, south facing ones are gray.
+pub fn visualize(sea_cucumbers: &SeaCucumbers, frames: &mut dyn FrameSink) -> io::Result<()> {
+    let mut floor = sea_cucumbers.floor.clone();
+
+    frames.frame(&frame(&floor))?;
+    for _ in 0..MAX_STEPS {
+        if !move_herds(&mut floor) {
+            break;
+        }
+        frames.frame(&frame(&floor))?;
+    }
+
+    Ok(())
+}
+
+fn frame(floor: &Grid<Location>) -> Grid<u8> {
+    floor.map(|location| match location {
+        Location::Empty => 0,
+        Location::East => 255,
+        Location::South => 128,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::models::SeaCucumbers;
+    use crate::visualization::visualize;
+
+    #[test]
+    fn visualize_example_case() {
+        let content = include_str!("../dataset/example.txt");
+        let sea_cucumbers = SeaCucumbers::parse_string(content.to_string()).unwrap();
+        let mut frames = Vec::new();
+        visualize(&sea_cucumbers, &mut frames).unwrap();
+
+        let render = |step: usize| {
+            frames[step].render(|level| match level {
+                255 => '>',
+                128 => 'v',
+                _ => '.',
+            })
+        };
+        // The initial state, then each of the 57 steps in which sea cucumbers move
+        assert_eq!(frames.len(), 58);
+        assert_eq!(render(0), content);
+        assert_eq!(render(57).lines().next(), Some("..>>v>vv.."));
+    }
+}
@@ -0,0 +1,20 @@
+use aoc_common::{Answer, Solution};
+use day_25::models::SeaCucumbers;
+use day_25::Day25;
+
+const EXAMPLE: &str = include_str!("../dataset/example.txt");
+
+#[test]
+fn part_1_example_case() {
+    let input = SeaCucumbers::parse_string(EXAMPLE.to_string()).unwrap();
+
+    assert_eq!(input.part_1_stable_sea_cucumbers(), 58);
+}
+
+#[test]
+fn solution_example_case() {
+    let input = Day25::parse(EXAMPLE.to_string()).unwrap();
+
+    assert_eq!(Day25::part_1(&input), Answer::Number(58));
+    assert_eq!(Day25::part_2(&input), Answer::Empty);
+}
@@ -0,0 +1,35 @@
+use aoc_common::Rng;
+use std::fmt::Write;
+
+/// Generates `size` distinct binary numbers (at most 2^16), with enough bits for them to stay
+/// sparse. Numbers have to be distinct for the life support ratings to single out one of them.
+pub fn generate(size: usize, rng: &mut Rng) -> String {
+    let size = size.clamp(1, 1 << u16::BITS);
+    let bit_count = (usize::BITS - (size - 1).leading_zeros() + 2).clamp(5, u16::BITS) as usize;
+
+    let mut numbers = (0..1usize << bit_count).collect::<Vec<_>>();
+    rng.shuffle(&mut numbers);
+
+    let mut content = String::new();
+    for number in &numbers[..size] {
+        writeln!(content, "{:01$b}", number, bit_count).unwrap();
+    }
+
+    content
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::generator::generate;
+    use crate::Day3;
+    use aoc_common::{Rng, Solution};
+    use std::collections::HashSet;
+
+    #[test]
+    fn generate_valid_input() {
+        let input = Day3::parse(generate(1000, &mut Rng::new(1))).unwrap();
+
+        assert_eq!(input.bit_count, 12);
+        assert_eq!(input.data.iter().collect::<HashSet<_>>().len(), 1000);
+    }
+}
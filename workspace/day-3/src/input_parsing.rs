@@ -1,39 +1,56 @@
-use crate::models::Input;
-use std::fs;
+use crate::models::{Bits, Input};
+use crate::Day3;
+use aoc_common::{input_lines, read_lines, InputError, InputLine, ParseError, Solution};
+use std::io::BufRead;
 
-pub fn parse_file(file_path: String) -> Input {
-    let file_content = fs::read_to_string(file_path).expect("Error while reading the data file");
+pub fn parse_string(content: String) -> Result<Input, ParseError> {
+    let mut bit_count = 0;
+    let data = input_lines(Day3::DAY, &content)
+        .filter(|line| !line.text.is_empty())
+        .map(|line| parse_number(&line, &mut bit_count))
+        .collect::<Result<_, _>>()?;
 
-    parse_string(file_content)
+    Ok(Input { bit_count, data })
 }
 
-fn parse_string(content: String) -> Input {
+/// Parses the numbers as they are read, without holding the whole input in memory
+pub fn parse_reader<R: BufRead>(reader: R) -> Result<Input, InputError> {
     let mut bit_count = 0;
-    let data = content
-        .lines()
-        .filter_map(|line| {
-            if line.is_empty() {
-                None
-            } else {
-                bit_count = line.len();
-                let mut value = 0;
-                for (i, c) in line.chars().enumerate() {
-                    if c == '1' {
-                        value |= 0b1 << (bit_count - 1 - i);
-                    }
-                }
-                Some(value)
-            }
-        })
-        .collect();
+    let mut data = Vec::new();
+    read_lines(Day3::DAY, reader, |line| {
+        if !line.text.is_empty() {
+            data.push(parse_number(&line, &mut bit_count)?);
+        }
+        Ok(())
+    })?;
+
+    Ok(Input { bit_count, data })
+}
 
-    Input { bit_count, data }
+/// Parses a binary number of any width, which must have as many bits as the previous ones. The
+/// bit count is set by the first number.
+fn parse_number(line: &InputLine, bit_count: &mut usize) -> Result<Bits, ParseError> {
+    if *bit_count != 0 && line.text.len() != *bit_count {
+        let offset = line.text.len().min(*bit_count);
+        return Err(line.error_at(offset, format!("{} bits", bit_count)));
+    }
+    *bit_count = line.text.len();
+    let mut value = Bits::zeros(*bit_count);
+    for (i, c) in line.text.char_indices() {
+        match c {
+            '0' => {}
+            '1' => value.set(*bit_count - 1 - i, true),
+            _ => return Err(line.error_at(i, "`0` or `1`")),
+        }
+    }
+    Ok(value)
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::input_parsing::parse_string;
-    use crate::models::Input;
+    use crate::input_parsing::{parse_reader, parse_string};
+    use crate::models::{Bits, Input};
+    use aoc_common::{InputError, ParseError};
 
     #[test]
     fn parse_example_case() {
@@ -52,17 +69,51 @@ mod tests {
 "
         .to_string();
 
-        let input = parse_string(content);
+        let input = parse_string(content).unwrap();
 
         assert_eq!(
             input,
             Input {
                 bit_count: 5,
-                data: vec![
+                data: [
                     0b00100, 0b11110, 0b10110, 0b10111, 0b10101, 0b01111, 0b00111, 0b11100,
                     0b10000, 0b11001, 0b00010, 0b01010,
                 ]
+                .map(|value| Bits::from_u64(value, 5))
+                .to_vec()
             }
         );
     }
+
+    #[test]
+    fn parse_wide_numbers() {
+        let number = format!("1{}1", "0".repeat(98));
+        let input = parse_string(format!("{}\n{}\n", number, "0".repeat(100))).unwrap();
+
+        assert_eq!(input.bit_count, 100);
+        assert_eq!(input.data[0].to_string(), number);
+        assert!(input.data[0].bit(99) && input.data[0].bit(0) && !input.data[0].bit(50));
+    }
+
+    #[test]
+    fn parse_stream() {
+        let content = "00100\n11110\n\n10110\n";
+
+        assert_eq!(
+            parse_reader(content.as_bytes()).unwrap(),
+            parse_string(content.to_string()).unwrap()
+        );
+        assert!(matches!(
+            parse_reader("00100\n1110\n".as_bytes()),
+            Err(InputError::Parse(error)) if error == ParseError::new(3, 2, 5, "5 bits")
+        ));
+    }
+
+    #[test]
+    fn parse_invalid_bits() {
+        assert_eq!(
+            parse_string("00100\n11210\n".to_string()),
+            Err(ParseError::new(3, 2, 3, "`0` or `1`"))
+        );
+    }
 }
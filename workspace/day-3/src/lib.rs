@@ -0,0 +1,51 @@
+pub mod generator;
+pub mod input_parsing;
+pub mod models;
+pub mod part_1;
+pub mod part_2;
+pub mod trie;
+
+use crate::input_parsing::{parse_reader, parse_string};
+use crate::models::Input;
+use aoc_common::{Answer, Generator, InputError, ParseError, Rng, Solution};
+use num_bigint::BigUint;
+use std::io::BufRead;
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    const DAY: u8 = 3;
+    type Input = Input;
+
+    fn parse(content: String) -> Result<Input, ParseError> {
+        parse_string(content)
+    }
+
+    fn parse_reader<R: BufRead>(reader: R) -> Result<Input, InputError> {
+        parse_reader(reader)
+    }
+
+    fn part_1(input: &Input) -> Answer {
+        big_answer(part_1::diagnostic_report(input))
+    }
+
+    fn part_2(input: &Input) -> Answer {
+        big_answer(part_2::diagnostic_report(input))
+    }
+}
+
+/// Answers beyond 128 bits are given as text
+fn big_answer(value: BigUint) -> Answer {
+    match i128::try_from(&value) {
+        Ok(value) => Answer::Number(value),
+        Err(_) => Answer::Text(value.to_string()),
+    }
+}
+
+impl Generator for Day3 {
+    const DEFAULT_SIZE: usize = 1000;
+
+    fn generate(size: usize, rng: &mut Rng) -> String {
+        generator::generate(size, rng)
+    }
+}
@@ -1,5 +1,131 @@
+use num_bigint::BigUint;
+use std::fmt::{self, Display, Formatter};
+
 #[derive(Debug, Eq, PartialEq)]
 pub struct Input {
     pub bit_count: usize,
-    pub data: Vec<u16>,
+    pub data: Vec<Bits>,
+}
+
+/// Binary number of any width, stored as a bitset. Bit positions count from the least significant
+/// bit, and the leading zeros are part of the number.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct Bits {
+    words: Vec<u64>,
+    len: usize,
+}
+
+impl Bits {
+    pub fn zeros(len: usize) -> Self {
+        Bits {
+            words: vec![0; len.div_ceil(64)],
+            len,
+        }
+    }
+
+    /// The `len` least significant bits of `value`
+    pub fn from_u64(value: u64, len: usize) -> Self {
+        let mut bits = Bits::zeros(len);
+        for position in 0..len.min(64) {
+            bits.set(position, value >> position & 1 == 1);
+        }
+        bits
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn bit(&self, position: usize) -> bool {
+        assert!(
+            position < self.len,
+            "bit {} out of {} bits",
+            position,
+            self.len
+        );
+        self.words[position / 64] >> (position % 64) & 1 == 1
+    }
+
+    pub fn set(&mut self, position: usize, value: bool) {
+        assert!(
+            position < self.len,
+            "bit {} out of {} bits",
+            position,
+            self.len
+        );
+        let mask = 1 << (position % 64);
+        if value {
+            self.words[position / 64] |= mask;
+        } else {
+            self.words[position / 64] &= !mask;
+        }
+    }
+
+    /// The number with all its bits flipped
+    pub fn complement(&self) -> Bits {
+        let mut complement = Bits::zeros(self.len);
+        for position in 0..self.len {
+            complement.set(position, !self.bit(position));
+        }
+        complement
+    }
+
+    pub fn to_biguint(&self) -> BigUint {
+        let digits = self
+            .words
+            .iter()
+            .flat_map(|&word| [word as u32, (word >> 32) as u32])
+            .collect();
+        BigUint::new(digits)
+    }
+}
+
+/// Binary form, most significant bit first
+impl Display for Bits {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        for position in (0..self.len).rev() {
+            write!(f, "{}", if self.bit(position) { '1' } else { '0' })?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::models::Bits;
+    use num_bigint::BigUint;
+
+    #[test]
+    fn small_numbers() {
+        let bits = Bits::from_u64(0b10110, 5);
+
+        assert!(bits.bit(1) && !bits.bit(0) && bits.bit(4));
+        assert_eq!(bits.to_string(), "10110");
+        assert_eq!(bits.complement().to_string(), "01001");
+        assert_eq!(bits.to_biguint(), BigUint::from(22u32));
+        assert_eq!(Bits::zeros(0).to_string(), "");
+    }
+
+    #[test]
+    fn wide_numbers() {
+        let mut bits = Bits::zeros(130);
+        bits.set(129, true);
+        bits.set(64, true);
+        bits.set(0, true);
+
+        assert_eq!(
+            bits.to_biguint(),
+            (BigUint::from(1u32) << 129) + (BigUint::from(1u32) << 64) + 1u32
+        );
+        assert_eq!(bits.to_string().len(), 130);
+        assert_eq!(bits.complement().complement(), bits);
+        assert_eq!(
+            bits.complement().to_biguint(),
+            (BigUint::from(1u32) << 130) - 1u32 - bits.to_biguint()
+        );
+    }
 }
@@ -1,44 +1,59 @@
-use crate::models::Input;
+use crate::models::{Bits, Input};
+use num_bigint::BigUint;
 
-pub fn diagnostic_report(input: &Input) -> u32 {
+pub fn diagnostic_report(input: &Input) -> BigUint {
+    let gamma_rate = gamma_rate(input);
+    let epsilon_rate = gamma_rate.complement();
+
+    gamma_rate.to_biguint() * epsilon_rate.to_biguint()
+}
+
+/// Most common bit of each position
+pub fn gamma_rate(input: &Input) -> Bits {
     let mut set_count = vec![0; input.bit_count];
     for n in input.data.iter() {
-        for bit_position in 0..input.bit_count {
-            if n & 0b1 << bit_position != 0 {
-                set_count[bit_position] += 1;
+        for (bit_position, count) in set_count.iter_mut().enumerate() {
+            if n.bit(bit_position) {
+                *count += 1;
             }
         }
     }
 
-    let mut bit_mask = 0;
-    let mut gamma_rate = 0;
-    for bit_position in 0..input.bit_count {
-        bit_mask |= 0b1 << bit_position;
-        if set_count[bit_position] > input.data.len() - set_count[bit_position] {
-            gamma_rate |= 0b1 << bit_position;
+    let mut gamma_rate = Bits::zeros(input.bit_count);
+    for (bit_position, &count) in set_count.iter().enumerate() {
+        if count > input.data.len() - count {
+            gamma_rate.set(bit_position, true);
         }
     }
 
-    let epsilon_rate = (!gamma_rate) & bit_mask;
+    gamma_rate
+}
 
-    gamma_rate * epsilon_rate
+/// Least common bit of each position
+pub fn epsilon_rate(input: &Input) -> Bits {
+    gamma_rate(input).complement()
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::models::Input;
-    use crate::part_1::diagnostic_report;
+    use crate::models::{Bits, Input};
+    use crate::part_1::{diagnostic_report, epsilon_rate, gamma_rate};
+    use num_bigint::BigUint;
 
     #[test]
     fn part_1_example_case() {
         let input = Input {
             bit_count: 5,
-            data: vec![
+            data: [
                 0b00100, 0b11110, 0b10110, 0b10111, 0b10101, 0b01111, 0b00111, 0b11100, 0b10000,
                 0b11001, 0b00010, 0b01010,
-            ],
+            ]
+            .map(|value| Bits::from_u64(value, 5))
+            .to_vec(),
         };
 
-        assert_eq!(198, diagnostic_report(&input));
+        assert_eq!(gamma_rate(&input).to_string(), "10110");
+        assert_eq!(epsilon_rate(&input).to_string(), "01001");
+        assert_eq!(BigUint::from(198u32), diagnostic_report(&input));
     }
 }
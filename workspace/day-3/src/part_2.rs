@@ -1,101 +1,73 @@
-use crate::models::Input;
+use crate::models::{Bits, Input};
+use crate::trie::ReportTrie;
+use num_bigint::BigUint;
 
-pub fn diagnostic_report(input: &Input) -> u32 {
-    oxygen_generator_rating(input) * co2_scrubber_rating(input)
-}
-
-fn oxygen_generator_rating(input: &Input) -> u32 {
-    let mut values = input.data.clone();
-
-    let mut current_bit_position = input.bit_count as i32 - 1;
-    while values.len() > 1 {
-        // Compute the bit count at that position
-        let total_count = values.len();
-        let set_count: usize = values
-            .iter()
-            .map(|&value| {
-                if (value & 0b1 << current_bit_position) != 0 {
-                    1
-                } else {
-                    0
-                }
-            })
-            .sum();
-        // Remove all non matching values
-        values.retain(|&value| {
-            if set_count >= total_count - set_count {
-                (value & 0b1 << current_bit_position) != 0
-            } else {
-                (value & 0b1 << current_bit_position) == 0
-            }
-        });
+pub fn diagnostic_report(input: &Input) -> BigUint {
+    let trie = ReportTrie::new(input);
 
-        current_bit_position -= 1;
-    }
-
-    values[0] as u32
+    trie.oxygen_generator_rating().to_biguint() * trie.co2_scrubber_rating().to_biguint()
 }
 
-fn co2_scrubber_rating(input: &Input) -> u32 {
-    let mut values = input.data.clone();
-
-    let mut current_bit_position = input.bit_count as i32 - 1;
-    while values.len() > 1 {
-        // Compute the bit count at that position
-        let total_count = values.len();
-        let set_count: usize = values
-            .iter()
-            .map(|&value| {
-                if (value & 0b1 << current_bit_position) != 0 {
-                    1
-                } else {
-                    0
-                }
-            })
-            .sum();
-        // Remove all non matching values
-        values.retain(|&value| {
-            if set_count < total_count - set_count {
-                (value & 0b1 << current_bit_position) != 0
-            } else {
-                (value & 0b1 << current_bit_position) == 0
-            }
-        });
-
-        current_bit_position -= 1;
-    }
+pub fn oxygen_generator_rating(input: &Input) -> Bits {
+    ReportTrie::new(input).oxygen_generator_rating()
+}
 
-    values[0] as u32
+pub fn co2_scrubber_rating(input: &Input) -> Bits {
+    ReportTrie::new(input).co2_scrubber_rating()
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::models::Input;
-    use crate::part_2::{co2_scrubber_rating, oxygen_generator_rating};
+    use crate::input_parsing::parse_string;
+    use crate::models::{Bits, Input};
+    use crate::part_2::{co2_scrubber_rating, diagnostic_report, oxygen_generator_rating};
+    use num_bigint::BigUint;
 
-    #[test]
-    fn part_2_oxygen_generator_rating_example_case() {
-        let input = Input {
+    fn example() -> Input {
+        Input {
             bit_count: 5,
-            data: vec![
+            data: [
                 0b00100, 0b11110, 0b10110, 0b10111, 0b10101, 0b01111, 0b00111, 0b11100, 0b10000,
                 0b11001, 0b00010, 0b01010,
-            ],
-        };
+            ]
+            .map(|value| Bits::from_u64(value, 5))
+            .to_vec(),
+        }
+    }
+
+    #[test]
+    fn part_2_oxygen_generator_rating_example_case() {
+        let rating = oxygen_generator_rating(&example());
 
-        assert_eq!(23, oxygen_generator_rating(&input));
+        assert_eq!(rating.to_string(), "10111");
+        assert_eq!(rating.to_biguint(), BigUint::from(23u32));
     }
 
     #[test]
     fn part_2_co2_scrubber_rating_example_case() {
-        let input = Input {
-            bit_count: 5,
-            data: vec![
-                0b00100, 0b11110, 0b10110, 0b10111, 0b10101, 0b01111, 0b00111, 0b11100, 0b10000,
-                0b11001, 0b00010, 0b01010,
-            ],
-        };
+        let rating = co2_scrubber_rating(&example());
+
+        assert_eq!(rating.to_string(), "01010");
+        assert_eq!(rating.to_biguint(), BigUint::from(10u32));
+    }
+
+    #[test]
+    fn ratings_beyond_64_bits() {
+        // The example numbers, followed by 95 bits telling them apart in the same way
+        let content = example()
+            .data
+            .iter()
+            .map(|value| format!("{}{}\n", value, value.to_string().repeat(19)))
+            .collect::<String>();
+        let input = parse_string(content).unwrap();
 
-        assert_eq!(10, co2_scrubber_rating(&input));
+        let oxygen_generator_rating = oxygen_generator_rating(&input);
+        assert_eq!(oxygen_generator_rating.to_string(), "10111".repeat(20));
+        assert_eq!(co2_scrubber_rating(&input).to_string(), "01010".repeat(20));
+        assert_eq!(
+            diagnostic_report(&input),
+            oxygen_generator_rating.to_biguint() * co2_scrubber_rating(&input).to_biguint()
+        );
+        assert!(diagnostic_report(&input) > BigUint::from(u128::MAX));
     }
 }
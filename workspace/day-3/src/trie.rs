@@ -0,0 +1,260 @@
+use crate::models::{Bits, Input};
+use std::cmp::Ordering;
+
+/// Binary prefix tree of the numbers of a report, most significant bit first. Each node counts
+/// the numbers starting with its prefix, so the life support ratings are found in a single walk
+/// down the tree.
+#[derive(Debug)]
+pub struct ReportTrie {
+    /// Nodes of the tree, the root first
+    nodes: Vec<Node>,
+    bit_count: usize,
+}
+
+#[derive(Debug, Default)]
+struct Node {
+    /// Numbers under the node
+    count: usize,
+    /// Indices of the nodes of the prefixes followed by a 0 and by a 1, 0 when there are none
+    children: [usize; 2],
+}
+
+/// Numbers having a 0 and a 1 at a position
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq)]
+pub struct BitCounts {
+    pub zeros: usize,
+    pub ones: usize,
+}
+
+impl BitCounts {
+    /// Most common bit, if not tied
+    pub fn most_common(&self) -> Option<bool> {
+        match self.ones.cmp(&self.zeros) {
+            Ordering::Greater => Some(true),
+            Ordering::Less => Some(false),
+            Ordering::Equal => None,
+        }
+    }
+}
+
+impl ReportTrie {
+    pub fn new(input: &Input) -> Self {
+        let mut trie = ReportTrie {
+            nodes: vec![Node::default()],
+            bit_count: input.bit_count,
+        };
+        for number in &input.data {
+            trie.insert(number);
+        }
+        trie
+    }
+
+    fn insert(&mut self, number: &Bits) {
+        let mut node = 0;
+        self.nodes[node].count += 1;
+        for position in (0..self.bit_count).rev() {
+            let bit = number.bit(position) as usize;
+            if self.nodes[node].children[bit] == 0 {
+                self.nodes.push(Node::default());
+                self.nodes[node].children[bit] = self.nodes.len() - 1;
+            }
+            node = self.nodes[node].children[bit];
+            self.nodes[node].count += 1;
+        }
+    }
+
+    /// Number of numbers in the report
+    pub fn len(&self) -> usize {
+        self.nodes[0].count
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Number of numbers starting with `prefix`, a binary string
+    pub fn count(&self, prefix: &str) -> usize {
+        self.find(prefix).map_or(0, |node| self.nodes[node].count)
+    }
+
+    /// Numbers starting with `prefix` having a 0 and a 1 at `index`, the index of the bit in the
+    /// binary strings (0 for the most significant bit). The bit following the prefix is counted
+    /// in constant time, further ones by walking the part of the tree in between.
+    pub fn bit_counts(&self, prefix: &str, index: usize) -> BitCounts {
+        assert!(
+            index < self.bit_count,
+            "bit {} out of {} bits",
+            index,
+            self.bit_count
+        );
+        assert!(index >= prefix.len(), "bit {} is part of the prefix", index);
+
+        let mut counts = BitCounts::default();
+        let mut nodes = self.find(prefix).into_iter().collect::<Vec<_>>();
+        for _ in prefix.len()..index {
+            nodes = nodes
+                .iter()
+                .flat_map(|&node| self.nodes[node].children)
+                .filter(|&child| child != 0)
+                .collect();
+        }
+        for node in nodes {
+            counts.zeros += self.child_count(node, false);
+            counts.ones += self.child_count(node, true);
+        }
+
+        counts
+    }
+
+    /// Most common bit at `index` among the numbers starting with `prefix`, if not tied
+    pub fn most_common(&self, prefix: &str, index: usize) -> Option<bool> {
+        self.bit_counts(prefix, index).most_common()
+    }
+
+    /// Keeps the numbers with the most common bit at each position, 1 on ties
+    pub fn oxygen_generator_rating(&self) -> Bits {
+        self.rating(|zeros, ones| ones >= zeros)
+    }
+
+    /// Keeps the numbers with the least common bit at each position, 0 on ties
+    pub fn co2_scrubber_rating(&self) -> Bits {
+        self.rating(|zeros, ones| ones < zeros)
+    }
+
+    /// Walks down the tree, following the bit chosen by `keep_ones` from the counts of zeros and
+    /// ones. A bit shared by all the remaining numbers keeps them all, so a single number left is
+    /// followed down to its last bit.
+    fn rating(&self, keep_ones: impl Fn(usize, usize) -> bool) -> Bits {
+        assert!(!self.is_empty(), "no rating in an empty report");
+
+        let mut rating = Bits::zeros(self.bit_count);
+        let mut node = 0;
+        for position in (0..self.bit_count).rev() {
+            let (zeros, ones) = (self.child_count(node, false), self.child_count(node, true));
+            let bit = if zeros == 0 || ones == 0 {
+                ones > 0
+            } else {
+                keep_ones(zeros, ones)
+            };
+            rating.set(position, bit);
+            node = self.nodes[node].children[bit as usize];
+        }
+
+        rating
+    }
+
+    fn child_count(&self, node: usize, bit: bool) -> usize {
+        match self.nodes[node].children[bit as usize] {
+            0 => 0,
+            child => self.nodes[child].count,
+        }
+    }
+
+    /// Node of the prefix, if any number starts with it
+    fn find(&self, prefix: &str) -> Option<usize> {
+        assert!(
+            prefix.len() <= self.bit_count,
+            "prefix longer than the numbers"
+        );
+
+        prefix.chars().try_fold(0, |node, c| {
+            let bit = match c {
+                '0' => 0,
+                '1' => 1,
+                _ => panic!("`{}` is not a binary digit", c),
+            };
+            match self.nodes[node].children[bit] {
+                0 => None,
+                child => Some(child),
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::input_parsing::parse_string;
+    use crate::models::{Bits, Input};
+    use crate::trie::{BitCounts, ReportTrie};
+    use aoc_common::Rng;
+
+    fn example() -> Input {
+        Input {
+            bit_count: 5,
+            data: [
+                0b00100, 0b11110, 0b10110, 0b10111, 0b10101, 0b01111, 0b00111, 0b11100, 0b10000,
+                0b11001, 0b00010, 0b01010,
+            ]
+            .map(|value| Bits::from_u64(value, 5))
+            .to_vec(),
+        }
+    }
+
+    /// Rating found by filtering the numbers bit after bit, as in the puzzle statement
+    fn filtered_rating(input: &Input, keep_most_common: bool) -> Bits {
+        let mut values = input.data.clone();
+        for position in (0..input.bit_count).rev() {
+            if values.len() == 1 {
+                break;
+            }
+            let ones = values.iter().filter(|value| value.bit(position)).count();
+            if ones == 0 || ones == values.len() {
+                continue;
+            }
+            let most_common = ones >= values.len() - ones;
+            values.retain(|value| value.bit(position) == (most_common == keep_most_common));
+        }
+        values[0].clone()
+    }
+
+    #[test]
+    fn ratings_example_case() {
+        let trie = ReportTrie::new(&example());
+
+        assert_eq!(trie.len(), 12);
+        assert_eq!(trie.oxygen_generator_rating().to_string(), "10111");
+        assert_eq!(trie.co2_scrubber_rating().to_string(), "01010");
+    }
+
+    #[test]
+    fn query_example_case() {
+        let trie = ReportTrie::new(&example());
+
+        assert_eq!(trie.count(""), 12);
+        assert_eq!(trie.count("10"), 4);
+        assert_eq!(trie.count("000"), 1);
+        assert_eq!(trie.count("01011"), 0);
+        assert_eq!(trie.bit_counts("1", 1), BitCounts { zeros: 4, ones: 3 });
+        assert_eq!(trie.most_common("1", 1), Some(false));
+        assert_eq!(trie.most_common("10", 2), Some(true));
+        // Bits further than the one right after the prefix
+        assert_eq!(trie.bit_counts("", 4), BitCounts { zeros: 7, ones: 5 });
+        assert_eq!(trie.bit_counts("0", 3), BitCounts { zeros: 1, ones: 4 });
+        assert_eq!(trie.most_common("01", 4), None);
+        assert_eq!(trie.bit_counts("0110", 4), BitCounts::default());
+    }
+
+    #[test]
+    fn ratings_match_filtering() {
+        let mut rng = Rng::new(3);
+        for _ in 0..50 {
+            let bit_count = rng.range(1..=70) as usize;
+            let content = (0..rng.range(1..=30))
+                .map(|_| {
+                    let bits = (0..bit_count)
+                        .map(|_| if rng.chance(0.5) { '1' } else { '0' })
+                        .collect::<String>();
+                    bits + "\n"
+                })
+                .collect::<String>();
+            let input = parse_string(content).unwrap();
+            let trie = ReportTrie::new(&input);
+
+            assert_eq!(
+                trie.oxygen_generator_rating(),
+                filtered_rating(&input, true)
+            );
+            assert_eq!(trie.co2_scrubber_rating(), filtered_rating(&input, false));
+        }
+    }
+}
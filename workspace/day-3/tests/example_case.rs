@@ -0,0 +1,31 @@
+use aoc_common::{Answer, Solution};
+use day_3::input_parsing::parse_string;
+use day_3::Day3;
+use day_3::{part_1, part_2};
+use num_bigint::BigUint;
+
+const EXAMPLE: &str = include_str!("../dataset/example.txt");
+
+#[test]
+fn part_1_example_case() {
+    let input = parse_string(EXAMPLE.to_string()).unwrap();
+
+    assert_eq!(part_1::diagnostic_report(&input), BigUint::from(198u32));
+}
+
+#[test]
+fn part_2_example_case() {
+    let input = parse_string(EXAMPLE.to_string()).unwrap();
+
+    assert_eq!(part_2::oxygen_generator_rating(&input).to_string(), "10111");
+    assert_eq!(part_2::co2_scrubber_rating(&input).to_string(), "01010");
+    assert_eq!(part_2::diagnostic_report(&input), BigUint::from(230u32));
+}
+
+#[test]
+fn solution_example_case() {
+    let input = Day3::parse(EXAMPLE.to_string()).unwrap();
+
+    assert_eq!(Day3::part_1(&input), Answer::Number(198));
+    assert_eq!(Day3::part_2(&input), Answer::Number(230));
+}
@@ -0,0 +1,46 @@
+use aoc_common::Rng;
+use std::fmt::Write;
+
+/// Numbers are drawn from 0 to 99
+const NUMBER_COUNT: u8 = 100;
+
+/// Generates `size` boards, all of them winning as every number is drawn
+pub fn generate(size: usize, rng: &mut Rng) -> String {
+    let mut numbers = (0..NUMBER_COUNT).collect::<Vec<_>>();
+    rng.shuffle(&mut numbers);
+    let mut content = numbers
+        .iter()
+        .map(|number| number.to_string())
+        .collect::<Vec<_>>()
+        .join(",");
+    content.push('\n');
+
+    for _ in 0..size {
+        rng.shuffle(&mut numbers);
+        content.push('\n');
+        for row in numbers[..25].chunks(5) {
+            let row = row
+                .iter()
+                .map(|number| format!("{:>2}", number))
+                .collect::<Vec<_>>();
+            writeln!(content, "{}", row.join(" ")).unwrap();
+        }
+    }
+
+    content
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::generator::generate;
+    use crate::Day4;
+    use aoc_common::{Rng, Solution};
+
+    #[test]
+    fn generate_valid_input() {
+        let input = Day4::parse(generate(20, &mut Rng::new(1))).unwrap();
+
+        assert_eq!(input.draw_numbers.len(), 100);
+        assert_eq!(input.boards.len(), 20);
+    }
+}
@@ -1,35 +1,55 @@
 use crate::models::{Board, Input};
-use std::error::Error;
-use std::fs;
+use crate::Day4;
+use aoc_common::{input_lines, InputLine, ParseError, Solution};
 
-pub fn parse_file(file_path: String) -> Result<Input, Box<dyn Error>> {
-    let file_content = fs::read_to_string(file_path).expect("Error while reading the data file");
-
-    parse_string(file_content)
-}
-
-fn parse_string(content: String) -> Result<Input, Box<dyn Error>> {
-    let lines: Vec<&str> = content.lines().collect();
+pub fn parse_string(content: String) -> Result<Input, ParseError> {
+    let mut lines = input_lines(Day4::DAY, &content).collect::<Vec<_>>();
+    while lines.last().is_some_and(|line| line.text.is_empty()) {
+        lines.pop();
+    }
 
-    let draw_numbers = lines[0]
+    let draw_line = lines
+        .first()
+        .ok_or_else(|| ParseError::new(Day4::DAY, 1, 1, "the draw numbers"))?;
+    let draw_numbers = draw_line
+        .text
         .split(',')
-        .map(|number| number.parse::<u8>())
+        .map(|number| draw_line.parse::<u8>(number, "a number"))
         .collect::<Result<Vec<u8>, _>>()?;
 
-    let board_count = (lines.len() - 2) / 6 + 1;
     let mut boards = Vec::new();
-    let mut numbers: [u8; 25] = [0; 25];
-    for n in 0..board_count {
-        for i in 0..5 {
-            // Parse the line
-            let ref mut line_numbers = lines[2 + n * 6 + i]
-                .split_ascii_whitespace()
-                .map(|number| number.parse::<u8>())
-                .collect::<Result<Vec<u8>, _>>()?;
-            // Inject it inside the array
-            numbers[i * 5..i * 5 + 5].copy_from_slice(line_numbers);
+    let mut index = 1;
+    while index < lines.len() {
+        if !lines[index].text.is_empty() {
+            return Err(lines[index].error(lines[index].text, "an empty line"));
+        }
+        index += 1;
+
+        // The board has as many rows as numbers on its first row
+        let size = match lines.get(index) {
+            Some(line) if !line.text.is_empty() => parse_row(line)?.len(),
+            _ => 0,
+        };
+        let rows = lines[index..]
+            .iter()
+            .take(size)
+            .take_while(|line| !line.text.is_empty())
+            .count();
+        if rows < size.max(1) {
+            let next_line = index + rows + 1;
+            return Err(ParseError::new(Day4::DAY, next_line, 1, "a board line"));
+        }
+
+        let mut numbers = Vec::with_capacity(size * size);
+        for line in &lines[index..index + size] {
+            let row = parse_row(line)?;
+            if row.len() != size {
+                return Err(line.error_at_end(format!("{} numbers", size)));
+            }
+            numbers.extend(row);
         }
         boards.push(Board::new(numbers));
+        index += size;
     }
 
     Ok(Input {
@@ -38,10 +58,24 @@ fn parse_string(content: String) -> Result<Input, Box<dyn Error>> {
     })
 }
 
+/// Parses the numbers of a row of a board, which has at least one
+fn parse_row(line: &InputLine) -> Result<Vec<u8>, ParseError> {
+    let row = line
+        .text
+        .split_ascii_whitespace()
+        .map(|number| line.parse::<u8>(number, "a number"))
+        .collect::<Result<Vec<u8>, _>>()?;
+    if row.is_empty() {
+        return Err(line.error_at_end("a number"));
+    }
+    Ok(row)
+}
+
 #[cfg(test)]
 mod tests {
     use crate::input_parsing::parse_string;
     use crate::models::{Board, Input};
+    use aoc_common::ParseError;
 
     #[test]
     fn parse_example_case() {
@@ -93,4 +127,51 @@ mod tests {
             }
         );
     }
+
+    #[test]
+    fn parse_invalid_boards() {
+        assert_eq!(
+            parse_string("7,4,x\n".to_string()),
+            Err(ParseError::new(4, 1, 5, "a number"))
+        );
+        assert_eq!(
+            parse_string("7,4\n\n22 13 17 11  0\n 8  2 23  4\n".to_string()),
+            Err(ParseError::new(4, 5, 1, "a board line"))
+        );
+        assert_eq!(
+            parse_string(
+                "7,4\n\n22 13 17 11  0\n 8  2 23  4\n 1 2 3 4 5\n 1 2 3 4 5\n 1 2 3 4 5\n"
+                    .to_string()
+            ),
+            Err(ParseError::new(4, 4, 12, "5 numbers"))
+        );
+        assert_eq!(
+            parse_string("7,4\n\n1 2\n3 4\n5 6\n".to_string()),
+            Err(ParseError::new(4, 5, 1, "an empty line"))
+        );
+        assert_eq!(
+            parse_string("7,4\n\n1 2\n\n3 4\n".to_string()),
+            Err(ParseError::new(4, 4, 1, "a board line"))
+        );
+        assert_eq!(
+            parse_string("7,4\n\n  \n".to_string()),
+            Err(ParseError::new(4, 3, 3, "a number"))
+        );
+    }
+
+    #[test]
+    fn parse_boards_of_any_size() {
+        let input =
+            parse_string("7,4\n\n1 2\n3 4\n\n9\n\n1 2 3\n4 5 6\n7 8 9\n".to_string()).unwrap();
+
+        assert_eq!(
+            input.boards,
+            vec![
+                Board::new([1, 2, 3, 4]),
+                Board::new([9]),
+                Board::new([1, 2, 3, 4, 5, 6, 7, 8, 9])
+            ]
+        );
+        assert_eq!(input.boards[2].size(), 3);
+    }
 }
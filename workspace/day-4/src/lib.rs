@@ -0,0 +1,39 @@
+pub mod generator;
+pub mod input_parsing;
+pub mod models;
+pub mod part_1;
+pub mod part_2;
+pub mod rules;
+pub mod solver;
+pub mod timeline;
+
+use crate::input_parsing::parse_string;
+use crate::models::Input;
+use aoc_common::{Answer, Generator, ParseError, Rng, Solution};
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    const DAY: u8 = 4;
+    type Input = Input;
+
+    fn parse(content: String) -> Result<Input, ParseError> {
+        parse_string(content)
+    }
+
+    fn part_1(input: &Input) -> Answer {
+        part_1::find_first_winning_board(input).into()
+    }
+
+    fn part_2(input: &Input) -> Answer {
+        part_2::find_last_winning_board(input).into()
+    }
+}
+
+impl Generator for Day4 {
+    const DEFAULT_SIZE: usize = 100;
+
+    fn generate(size: usize, rng: &mut Rng) -> String {
+        generator::generate(size, rng)
+    }
+}
@@ -1,68 +1,187 @@
+use crate::rules::{Patterns, WinRule, STANDARD_RULES};
+use aoc_common::Grid;
+use std::sync::Arc;
+
 #[derive(Debug, Eq, PartialEq)]
 pub struct Input {
     pub draw_numbers: Vec<u8>,
     pub boards: Vec<Board>,
 }
 
+impl Input {
+    /// The same draws, every board being won by the given rules instead
+    pub fn with_rules(self, rules: &[WinRule]) -> Self {
+        Input {
+            draw_numbers: self.draw_numbers,
+            boards: self
+                .boards
+                .into_iter()
+                .map(|board| board.with_rules(rules))
+                .collect(),
+        }
+    }
+}
+
+/// Square board of any size, won by rows and columns unless given other rules
 #[derive(Debug, Clone, Eq, PartialEq)]
 pub struct Board {
-    numbers: [u8; 25],
-    board_state: [bool; 25],
+    numbers: Grid<u8>,
+    board_state: Grid<bool>,
+    patterns: Arc<Patterns>,
+    /// Number of marked cells of each pattern
+    marked_counts: Vec<usize>,
     is_won: bool,
 }
 
 impl Board {
-    pub fn new(numbers: [u8; 25]) -> Self {
+    /// Board of the given numbers, row by row, whose count must be a square
+    pub fn new(numbers: impl Into<Vec<u8>>) -> Self {
+        let numbers = numbers.into();
+        let size = numbers.len().isqrt();
+        assert_eq!(size * size, numbers.len(), "not a square board");
+
+        let patterns = Arc::new(Patterns::new(&STANDARD_RULES, size));
         Board {
-            numbers,
-            board_state: [false; 25],
+            numbers: Grid::new(size, size, numbers),
+            board_state: Grid::filled(size, size, false),
+            marked_counts: vec![0; patterns.cells.len()],
+            patterns,
             is_won: false,
         }
     }
 
-    pub fn set(&mut self, number: u8) -> bool {
-        for (index, &board_number) in self.numbers.iter().enumerate() {
-            // Check if the number matches a cell of the board
-            if number == board_number {
-                // It matches !
-                self.board_state[index] = true;
-
-                // Check for a winning state
-                let col = index % 5;
-                let row = (index - col) / 5;
-
-                // Winning Row || Winning Col ?
-                self.is_won |= (self.board_state[row * 5 + 0]
-                    && self.board_state[row * 5 + 1]
-                    && self.board_state[row * 5 + 2]
-                    && self.board_state[row * 5 + 3]
-                    && self.board_state[row * 5 + 4])
-                    || (self.board_state[5 * 0 + col]
-                        && self.board_state[5 * 1 + col]
-                        && self.board_state[5 * 2 + col]
-                        && self.board_state[5 * 3 + col]
-                        && self.board_state[5 * 4 + col]);
-                return self.is_won;
+    /// The same board won by the given rules, keeping its marked cells
+    pub fn with_rules(self, rules: &[WinRule]) -> Self {
+        let patterns = Arc::new(Patterns::new(rules, self.size()));
+        let mut marked_counts = vec![0; patterns.cells.len()];
+        for (position, _) in self.board_state.iter().filter(|(_, &marked)| marked) {
+            for &pattern in &patterns.by_cell[position] {
+                marked_counts[pattern] += 1;
             }
         }
 
-        // No win
-        false
+        Board {
+            is_won: marked_counts
+                .iter()
+                .zip(&patterns.cells)
+                .any(|(&marked, cells)| marked == cells.len()),
+            marked_counts,
+            patterns,
+            ..self
+        }
     }
 
-    pub fn compute_score(&self) -> u32 {
-        let mut score: u32 = 0;
+    /// Number of rows and of columns
+    pub fn size(&self) -> usize {
+        self.numbers.width()
+    }
+
+    pub fn numbers(&self) -> &Grid<u8> {
+        &self.numbers
+    }
+
+    /// Positions of the cells of each winning pattern of the board
+    pub fn patterns(&self) -> &[Vec<(usize, usize)>] {
+        &self.patterns.cells
+    }
+
+    pub fn is_marked(&self, position: (usize, usize)) -> bool {
+        self.board_state[position]
+    }
+
+    /// Marks the cells of the number, returning whether the board is won if it has the number
+    pub fn set(&mut self, number: u8) -> bool {
+        let mut found = false;
+        for (position, _) in self.numbers.iter().filter(|(_, &n)| n == number) {
+            found = true;
+            if self.board_state[position] {
+                continue;
+            }
+            self.board_state[position] = true;
 
-        for i in 0..25 {
-            if self.board_state[i] == false {
-                score += self.numbers[i] as u32;
+            // Only the patterns going through the cell may have been completed
+            for &pattern in &self.patterns.by_cell[position] {
+                self.marked_counts[pattern] += 1;
+                self.is_won |= self.marked_counts[pattern] == self.patterns.cells[pattern].len();
             }
         }
 
-        score
+        found && self.is_won
+    }
+
+    /// Sum of the unmarked numbers
+    pub fn compute_score(&self) -> u32 {
+        self.numbers
+            .values()
+            .zip(self.board_state.values())
+            .filter(|(_, &marked)| !marked)
+            .map(|(&number, _)| number as u32)
+            .sum()
     }
 
     pub fn is_won(&self) -> bool {
         self.is_won
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::models::Board;
+    use crate::rules::WinRule;
+
+    fn board() -> Board {
+        Board::new([1, 2, 3, 4, 5, 6, 7, 8, 9])
+    }
+
+    fn wins_after(mut board: Board, numbers: &[u8]) -> Option<usize> {
+        numbers.iter().position(|&number| board.set(number))
+    }
+
+    #[test]
+    fn win_rules() {
+        let draws = [5, 1, 3, 7, 9, 2, 8];
+
+        assert_eq!(wins_after(board(), &draws), Some(5));
+        assert_eq!(
+            wins_after(board().with_rules(&[WinRule::Diagonals]), &draws),
+            Some(3)
+        );
+        assert_eq!(
+            wins_after(board().with_rules(&[WinRule::FourCorners]), &draws),
+            Some(4)
+        );
+        assert_eq!(
+            wins_after(board().with_rules(&[WinRule::Blackout]), &draws),
+            None
+        );
+        assert_eq!(
+            wins_after(
+                board().with_rules(&[WinRule::Mask(vec![(0, 0), (2, 0)])]),
+                &draws
+            ),
+            Some(2)
+        );
+    }
+
+    #[test]
+    fn score_and_rules_change() {
+        let mut board = board();
+        board.set(1);
+        board.set(5);
+        board.set(42);
+
+        assert!(!board.is_won());
+        assert_eq!(board.compute_score(), 39);
+        assert!(board.is_marked((1, 1)));
+
+        let board = board.with_rules(&[WinRule::Mask(vec![(0, 0), (1, 1)])]);
+        assert!(board.is_won());
+        assert_eq!(board.compute_score(), 39);
+    }
+
+    #[test]
+    #[should_panic(expected = "not a square board")]
+    fn board_not_square() {
+        Board::new([1, 2, 3]);
+    }
+}
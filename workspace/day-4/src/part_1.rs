@@ -1,28 +1,15 @@
 use crate::models::Input;
+use crate::timeline::Timeline;
 
 pub fn find_first_winning_board(input: &Input) -> u32 {
-    let ref mut boards = input.boards.clone();
-
-    let mut winning_board_and_number = None;
-    'outer: for &n in &input.draw_numbers {
-        for i in 0..boards.len() {
-            if boards[i].set(n) {
-                winning_board_and_number = Some((boards[i].clone(), n));
-                break 'outer;
-            }
-        }
-    }
-
-    match winning_board_and_number {
-        Some((board, n)) => board.compute_score() * n as u32,
-        None => 0,
-    }
+    Timeline::new(input).first().map_or(0, |win| win.score)
 }
 
 #[cfg(test)]
 mod tests {
     use crate::models::{Board, Input};
     use crate::part_1::find_first_winning_board;
+    use crate::rules::WinRule;
 
     #[test]
     fn part_1_example_case() {
@@ -49,4 +36,21 @@ mod tests {
 
         assert_eq!(4512, find_first_winning_board(&input));
     }
+
+    #[test]
+    fn boards_of_any_size_and_rules() {
+        let input = Input {
+            draw_numbers: vec![5, 1, 9, 4, 2],
+            boards: vec![
+                Board::new([1, 2, 3, 4, 5, 6, 7, 8, 9]),
+                Board::new([2, 7, 9, 1]),
+            ],
+        };
+        // 1 and 9 complete the second row of the second board first
+        assert_eq!(find_first_winning_board(&input), 9 * 9);
+
+        // The diagonal 5, 1, 9 of the first board completes first
+        let input = input.with_rules(&[WinRule::Diagonals]);
+        assert_eq!(find_first_winning_board(&input), 9 * (45 - 15));
+    }
 }
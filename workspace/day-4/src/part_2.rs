@@ -1,26 +1,10 @@
 use crate::models::Input;
+use crate::timeline::Timeline;
 
 pub fn find_last_winning_board(input: &Input) -> u32 {
-    let ref mut boards = input.boards.clone();
-
-    let mut winning_board_count = 0;
-    let mut winning_board_and_number = None;
-    'outer: for &n in &input.draw_numbers {
-        for i in 0..boards.len() {
-            if boards[i].is_won() == false && boards[i].set(n) {
-                winning_board_count += 1;
-                if winning_board_count == boards.len() {
-                    winning_board_and_number = Some((boards[i].clone(), n));
-                    break 'outer;
-                }
-            }
-        }
-    }
-
-    match winning_board_and_number {
-        Some((board, n)) => board.compute_score() * n as u32,
-        None => 0,
-    }
+    Timeline::new(input)
+        .last_of_all()
+        .map_or(0, |win| win.score)
 }
 
 #[cfg(test)]
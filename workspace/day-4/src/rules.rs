@@ -0,0 +1,129 @@
+use aoc_common::Grid;
+
+/// Rules of the puzzle: a board wins with a complete row or column
+pub const STANDARD_RULES: [WinRule; 2] = [WinRule::Rows, WinRule::Columns];
+
+/// Way of winning a board: each rule is a set of patterns, a board wins as soon as all the cells
+/// of any pattern of any of its rules are marked
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum WinRule {
+    /// Any complete row
+    Rows,
+    /// Any complete column
+    Columns,
+    /// Any of the two complete diagonals
+    Diagonals,
+    /// The four corners of the board
+    FourCorners,
+    /// Every cell of the board
+    Blackout,
+    /// The given `(x, y)` positions, which must all be on the board
+    Mask(Vec<(usize, usize)>),
+}
+
+impl WinRule {
+    /// Positions of the cells of each pattern of the rule on a board of `size` by `size` cells
+    fn patterns(&self, size: usize) -> Vec<Vec<(usize, usize)>> {
+        let last = size - 1;
+        match self {
+            WinRule::Rows => (0..size)
+                .map(|y| (0..size).map(|x| (x, y)).collect())
+                .collect(),
+            WinRule::Columns => (0..size)
+                .map(|x| (0..size).map(|y| (x, y)).collect())
+                .collect(),
+            WinRule::Diagonals => vec![
+                (0..size).map(|i| (i, i)).collect(),
+                (0..size).map(|i| (last - i, i)).collect(),
+            ],
+            WinRule::FourCorners => vec![vec![(0, 0), (last, 0), (0, last), (last, last)]],
+            WinRule::Blackout => vec![Grid::filled(size, size, ()).positions().collect()],
+            WinRule::Mask(positions) => {
+                assert!(!positions.is_empty(), "a mask needs at least a cell");
+                assert!(
+                    positions.iter().all(|&(x, y)| x < size && y < size),
+                    "mask out of a {}x{} board",
+                    size,
+                    size
+                );
+                vec![positions.clone()]
+            }
+        }
+    }
+}
+
+/// Winning patterns of a set of rules on boards of a given size, shared by all these boards
+#[derive(Debug, Eq, PartialEq)]
+pub(crate) struct Patterns {
+    /// Positions of the cells of each pattern
+    pub(crate) cells: Vec<Vec<(usize, usize)>>,
+    /// Indices of the patterns each cell is part of
+    pub(crate) by_cell: Grid<Vec<usize>>,
+}
+
+impl Patterns {
+    pub(crate) fn new(rules: &[WinRule], size: usize) -> Self {
+        assert!(size > 0, "boards must have at least a cell");
+
+        let mut patterns = Patterns {
+            cells: Vec::new(),
+            by_cell: Grid::filled(size, size, Vec::new()),
+        };
+        for mut pattern in rules.iter().flat_map(|rule| rule.patterns(size)) {
+            // Corners or masks may name a cell twice, which would then never be complete
+            pattern.sort_unstable();
+            pattern.dedup();
+            for &position in &pattern {
+                patterns.by_cell[position].push(patterns.cells.len());
+            }
+            patterns.cells.push(pattern);
+        }
+
+        patterns
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::rules::{Patterns, WinRule, STANDARD_RULES};
+
+    #[test]
+    fn patterns_of_each_rule() {
+        assert_eq!(
+            WinRule::Rows.patterns(2),
+            vec![vec![(0, 0), (1, 0)], vec![(0, 1), (1, 1)]]
+        );
+        assert_eq!(
+            WinRule::Columns.patterns(2),
+            vec![vec![(0, 0), (0, 1)], vec![(1, 0), (1, 1)]]
+        );
+        assert_eq!(
+            WinRule::Diagonals.patterns(3),
+            vec![vec![(0, 0), (1, 1), (2, 2)], vec![(2, 0), (1, 1), (0, 2)]]
+        );
+        assert_eq!(WinRule::Blackout.patterns(3)[0].len(), 9);
+        assert_eq!(
+            WinRule::Mask(vec![(1, 0), (0, 1)]).patterns(2),
+            vec![vec![(1, 0), (0, 1)]]
+        );
+    }
+
+    #[test]
+    fn shared_cells() {
+        let patterns = Patterns::new(&STANDARD_RULES, 5);
+
+        assert_eq!(patterns.cells.len(), 10);
+        assert!(patterns.cells.iter().all(|cells| cells.len() == 5));
+        assert_eq!(patterns.by_cell[(3, 1)], vec![1, 8]);
+
+        // The four corners of a single cell board are that cell
+        let patterns = Patterns::new(&[WinRule::FourCorners], 1);
+        assert_eq!(patterns.cells, vec![vec![(0, 0)]]);
+    }
+
+    #[test]
+    #[should_panic(expected = "mask out of a 3x3 board")]
+    fn mask_out_of_the_board() {
+        Patterns::new(&[WinRule::Mask(vec![(3, 0)])], 3);
+    }
+}
@@ -0,0 +1,244 @@
+use crate::models::{Board, Input};
+use std::collections::HashSet;
+use std::fmt::{self, Display, Formatter};
+
+/// Reason why a winning pattern of the target board cannot be completed first
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum Obstacle {
+    /// A number of the pattern is never drawn
+    Undrawn(u8),
+    /// Drawing the numbers of the pattern also wins this board
+    BeatenBy(usize),
+}
+
+/// A winning pattern of the target board, and what prevents it from winning first
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Blocked {
+    /// Positions of the cells of the pattern
+    pub pattern: Vec<(usize, usize)>,
+    pub obstacle: Obstacle,
+}
+
+/// Proof that no order of the draws makes the target board win first: every one of its winning
+/// patterns is blocked
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Impossible {
+    pub blocked: Vec<Blocked>,
+}
+
+/// Shortest sequence of the draw numbers making the `target` board win strictly before any other
+/// board, at its last draw.
+///
+/// Marking more numbers never unmarks a cell, so once a board has won it stays won: a sequence
+/// makes the target win first if and only if the target has won and no other board has after all
+/// its numbers are drawn. The numbers of any winning sequence then cover a winning pattern of the
+/// target, and drawing only the numbers of that pattern still wins first. The shortest sequence
+/// is thus the numbers of a pattern with the fewest distinct numbers among those winning first,
+/// and if no pattern does, no sequence does.
+pub fn shortest_winning_draws(input: &Input, target: usize) -> Result<Vec<u8>, Impossible> {
+    assert!(
+        target < input.boards.len(),
+        "no board {} among {} boards",
+        target,
+        input.boards.len()
+    );
+
+    let drawn = input.draw_numbers.iter().copied().collect::<HashSet<_>>();
+    let board = &input.boards[target];
+    let mut shortest: Option<Vec<u8>> = None;
+    let mut blocked = Vec::new();
+
+    for pattern in board.patterns() {
+        let mut numbers = Vec::with_capacity(pattern.len());
+        for &position in pattern {
+            let number = board.numbers()[position];
+            if !numbers.contains(&number) {
+                numbers.push(number);
+            }
+        }
+        if shortest
+            .as_ref()
+            .is_some_and(|shortest| shortest.len() <= numbers.len())
+        {
+            continue;
+        }
+
+        let obstacle = match numbers.iter().find(|number| !drawn.contains(number)) {
+            Some(&number) => Some(Obstacle::Undrawn(number)),
+            None => first_other_winner(input, target, &numbers).map(Obstacle::BeatenBy),
+        };
+        match obstacle {
+            Some(obstacle) => blocked.push(Blocked {
+                pattern: pattern.clone(),
+                obstacle,
+            }),
+            None => shortest = Some(numbers),
+        }
+    }
+
+    shortest.ok_or(Impossible { blocked })
+}
+
+/// Reordering of all the draw numbers making the `target` board win first: the shortest winning
+/// sequence, followed by the other draws in their original order
+pub fn reorder_draws(input: &Input, target: usize) -> Result<Vec<u8>, Impossible> {
+    let mut draws = shortest_winning_draws(input, target)?;
+    let mut remaining = input.draw_numbers.clone();
+    for number in &draws {
+        let index = remaining.iter().position(|n| n == number).unwrap();
+        remaining.remove(index);
+    }
+    draws.extend(remaining);
+
+    Ok(draws)
+}
+
+/// First board other than `target` won once all the numbers are drawn
+fn first_other_winner(input: &Input, target: usize, numbers: &[u8]) -> Option<usize> {
+    input
+        .boards
+        .iter()
+        .enumerate()
+        .filter(|&(index, _)| index != target)
+        .find(|(_, board)| wins(board, numbers))
+        .map(|(index, _)| index)
+}
+
+fn wins(board: &Board, numbers: &[u8]) -> bool {
+    let mut board = board.clone();
+    for &number in numbers {
+        board.set(number);
+    }
+    board.is_won()
+}
+
+impl Display for Impossible {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        for blocked in &self.blocked {
+            write!(f, "pattern {:?}: ", blocked.pattern)?;
+            match blocked.obstacle {
+                Obstacle::Undrawn(number) => writeln!(f, "{} is never drawn", number)?,
+                Obstacle::BeatenBy(board) => writeln!(f, "board {} wins too", board)?,
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::input_parsing::parse_string;
+    use crate::models::{Board, Input};
+    use crate::solver::{reorder_draws, shortest_winning_draws, wins, Blocked, Obstacle};
+    use crate::timeline::Timeline;
+    use aoc_common::Rng;
+
+    const EXAMPLE: &str = include_str!("../dataset/example.txt");
+
+    /// Whether the draws make the target win strictly before the other boards
+    fn wins_first(input: &Input, target: usize, draws: Vec<u8>) -> bool {
+        let timeline = Timeline::new(&Input {
+            draw_numbers: draws,
+            boards: input.boards.clone(),
+        });
+        let first = timeline.first().map(|win| (win.board, win.draw_index));
+        let second = timeline.wins.get(1).map(|win| win.draw_index);
+        matches!(first, Some((board, draw)) if board == target && second != Some(draw))
+    }
+
+    #[test]
+    fn example_case() {
+        let input = parse_string(EXAMPLE.to_string()).unwrap();
+
+        for target in 0..3 {
+            let draws = shortest_winning_draws(&input, target).unwrap();
+            assert_eq!(draws.len(), 5);
+            assert!(wins_first(&input, target, draws));
+
+            let reordered = reorder_draws(&input, target).unwrap();
+            let (mut sorted, mut expected) = (reordered.clone(), input.draw_numbers.clone());
+            sorted.sort_unstable();
+            expected.sort_unstable();
+            assert_eq!(sorted, expected);
+            assert!(wins_first(&input, target, reordered));
+        }
+    }
+
+    #[test]
+    fn impossible_targets() {
+        // Every line of the first board is a line of the second one
+        let input = Input {
+            draw_numbers: vec![1, 2, 3, 4, 5],
+            boards: vec![Board::new([1, 2, 3, 4]), Board::new([1, 2, 3, 4])],
+        };
+        let impossible = shortest_winning_draws(&input, 1).unwrap_err();
+        assert_eq!(impossible.blocked.len(), 4);
+        assert_eq!(
+            impossible.blocked[0],
+            Blocked {
+                pattern: vec![(0, 0), (1, 0)],
+                obstacle: Obstacle::BeatenBy(0),
+            }
+        );
+
+        let mut input = Input {
+            draw_numbers: vec![4, 1],
+            boards: vec![Board::new([1, 2, 3, 4])],
+        };
+        let impossible = reorder_draws(&input, 0).unwrap_err();
+        assert_eq!(impossible.blocked[1].obstacle, Obstacle::Undrawn(3));
+        assert_eq!(
+            impossible.to_string().lines().next(),
+            Some("pattern [(0, 0), (1, 0)]: 2 is never drawn")
+        );
+
+        input.draw_numbers = vec![4, 3, 1];
+        assert_eq!(shortest_winning_draws(&input, 0), Ok(vec![3, 4]));
+        assert_eq!(reorder_draws(&input, 0), Ok(vec![3, 4, 1]));
+    }
+
+    #[test]
+    fn shortest_draws_match_exhaustive_search() {
+        let mut rng = Rng::new(4);
+        for _ in 0..100 {
+            let draw_numbers = (0..8).filter(|_| rng.chance(0.8)).collect::<Vec<u8>>();
+            let boards = (0..rng.range(1..=4))
+                .map(|_| Board::new((0..4).map(|_| rng.range(0..=7) as u8).collect::<Vec<_>>()))
+                .collect::<Vec<_>>();
+            let input = Input {
+                draw_numbers,
+                boards,
+            };
+            let target = rng.below(input.boards.len());
+
+            // Smallest set of drawn numbers winning the target and no other board
+            let exhaustive = (0u32..1 << input.draw_numbers.len())
+                .map(|subset| {
+                    input
+                        .draw_numbers
+                        .iter()
+                        .enumerate()
+                        .filter(|&(i, _)| subset & 1 << i != 0)
+                        .map(|(_, &number)| number)
+                        .collect::<Vec<_>>()
+                })
+                .filter(|numbers| {
+                    input
+                        .boards
+                        .iter()
+                        .enumerate()
+                        .all(|(index, board)| wins(board, numbers) == (index == target))
+                })
+                .map(|numbers| numbers.len())
+                .min();
+
+            match shortest_winning_draws(&input, target) {
+                Ok(draws) => {
+                    assert_eq!(Some(draws.len()), exhaustive);
+                    assert!(wins_first(&input, target, draws));
+                }
+                Err(_) => assert_eq!(exhaustive, None),
+            }
+        }
+    }
+}
@@ -0,0 +1,183 @@
+use crate::models::Input;
+use std::fmt::{self, Display, Formatter};
+
+/// A board completing one of its winning patterns
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct Win {
+    /// Index of the draw in the draw numbers
+    pub draw_index: usize,
+    pub number: u8,
+    /// Index of the board in the input
+    pub board: usize,
+    /// Sum of the numbers of the board still unmarked
+    pub unmarked_sum: u32,
+    pub score: u32,
+}
+
+/// Wins of a whole game, in the order they happen: by draw, then by board for boards winning on
+/// the same draw. Each board wins at most once, later draws being ignored.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Timeline {
+    pub wins: Vec<Win>,
+    board_count: usize,
+}
+
+impl Timeline {
+    /// Plays all the draws on all the boards
+    pub fn new(input: &Input) -> Self {
+        let mut boards = input.boards.clone();
+        let mut wins = Vec::with_capacity(boards.len());
+
+        for (draw_index, &number) in input.draw_numbers.iter().enumerate() {
+            for (index, board) in boards.iter_mut().enumerate() {
+                if !board.is_won() && board.set(number) {
+                    let unmarked_sum = board.compute_score();
+                    wins.push(Win {
+                        draw_index,
+                        number,
+                        board: index,
+                        unmarked_sum,
+                        score: unmarked_sum * number as u32,
+                    });
+                }
+            }
+            if wins.len() == boards.len() {
+                break;
+            }
+        }
+
+        Timeline {
+            wins,
+            board_count: input.boards.len(),
+        }
+    }
+
+    pub fn first(&self) -> Option<&Win> {
+        self.wins.first()
+    }
+
+    /// Win of the last board, if all of them win
+    pub fn last_of_all(&self) -> Option<&Win> {
+        self.wins.get(self.board_count.checked_sub(1)?)
+    }
+
+    /// Win of a board, if it wins
+    pub fn win_of(&self, board: usize) -> Option<&Win> {
+        self.wins.iter().find(|win| win.board == board)
+    }
+
+    /// Indices of the boards which never win, increasing
+    pub fn never_won(&self) -> Vec<usize> {
+        let mut won = vec![false; self.board_count];
+        for win in &self.wins {
+            won[win.board] = true;
+        }
+        (0..self.board_count).filter(|&board| !won[board]).collect()
+    }
+
+    /// Index of the draw after which `count` boards have won, if that many ever do (at least one)
+    pub fn draw_with_wins(&self, count: usize) -> Option<usize> {
+        let win = self.wins.get(count.checked_sub(1)?)?;
+        Some(win.draw_index)
+    }
+}
+
+impl Display for Win {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "draw {} ({}): board {} wins with {} unmarked, score {}",
+            self.draw_index, self.number, self.board, self.unmarked_sum, self.score
+        )
+    }
+}
+
+impl Display for Timeline {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        for win in &self.wins {
+            writeln!(f, "{}", win)?;
+        }
+        let never_won = self.never_won();
+        if !never_won.is_empty() {
+            let boards = never_won
+                .iter()
+                .map(|board| board.to_string())
+                .collect::<Vec<_>>();
+            writeln!(f, "never won: {}", boards.join(", "))?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::input_parsing::parse_string;
+    use crate::models::{Board, Input};
+    use crate::rules::WinRule;
+    use crate::timeline::{Timeline, Win};
+
+    const EXAMPLE: &str = include_str!("../dataset/example.txt");
+
+    #[test]
+    fn timeline_example_case() {
+        let timeline = Timeline::new(&parse_string(EXAMPLE.to_string()).unwrap());
+
+        assert_eq!(
+            timeline.wins,
+            vec![
+                Win {
+                    draw_index: 11,
+                    number: 24,
+                    board: 2,
+                    unmarked_sum: 188,
+                    score: 4512,
+                },
+                Win {
+                    draw_index: 13,
+                    number: 16,
+                    board: 0,
+                    unmarked_sum: 137,
+                    score: 2192,
+                },
+                Win {
+                    draw_index: 14,
+                    number: 13,
+                    board: 1,
+                    unmarked_sum: 148,
+                    score: 1924,
+                },
+            ]
+        );
+        assert_eq!(timeline.first().map(|win| win.board), Some(2));
+        assert_eq!(timeline.last_of_all().map(|win| win.score), Some(1924));
+        assert_eq!(timeline.win_of(0).map(|win| win.number), Some(16));
+        assert!(timeline.never_won().is_empty());
+        assert_eq!(timeline.draw_with_wins(2), Some(13));
+        assert_eq!(timeline.draw_with_wins(4), None);
+        assert_eq!(
+            timeline.to_string().lines().next(),
+            Some("draw 11 (24): board 2 wins with 188 unmarked, score 4512")
+        );
+    }
+
+    #[test]
+    fn boards_never_winning() {
+        let input = Input {
+            draw_numbers: vec![1, 2, 3, 4],
+            boards: vec![
+                Board::new([1, 2, 3, 4]),
+                Board::new([5, 6, 7, 8]),
+                Board::new([3, 4, 9, 1]),
+            ],
+        }
+        .with_rules(&[WinRule::Blackout]);
+        let timeline = Timeline::new(&input);
+
+        assert_eq!(timeline.wins.len(), 1);
+        assert_eq!(timeline.win_of(0).map(|win| win.draw_index), Some(3));
+        assert_eq!(timeline.never_won(), vec![1, 2]);
+        assert_eq!(timeline.last_of_all(), None);
+        assert_eq!(timeline.draw_with_wins(0), None);
+        assert!(timeline.to_string().ends_with("never won: 1, 2\n"));
+    }
+}
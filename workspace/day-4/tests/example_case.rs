@@ -0,0 +1,29 @@
+use aoc_common::{Answer, Solution};
+use day_4::input_parsing::parse_string;
+use day_4::part_1::find_first_winning_board;
+use day_4::part_2::find_last_winning_board;
+use day_4::Day4;
+
+const EXAMPLE: &str = include_str!("../dataset/example.txt");
+
+#[test]
+fn part_1_example_case() {
+    let input = parse_string(EXAMPLE.to_string()).unwrap();
+
+    assert_eq!(find_first_winning_board(&input), 4512);
+}
+
+#[test]
+fn part_2_example_case() {
+    let input = parse_string(EXAMPLE.to_string()).unwrap();
+
+    assert_eq!(find_last_winning_board(&input), 1924);
+}
+
+#[test]
+fn solution_example_case() {
+    let input = Day4::parse(EXAMPLE.to_string()).unwrap();
+
+    assert_eq!(Day4::part_1(&input), Answer::Number(4512));
+    assert_eq!(Day4::part_2(&input), Answer::Number(1924));
+}
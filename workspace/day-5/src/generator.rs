@@ -0,0 +1,48 @@
+use aoc_common::Rng;
+use std::fmt::Write;
+
+/// Coordinates range from 0 to 999
+const MAP_SIZE: i64 = 1000;
+
+/// Generates `size` horizontal, vertical and diagonal lines of vents
+pub fn generate(size: usize, rng: &mut Rng) -> String {
+    let mut content = String::new();
+    for _ in 0..size {
+        let length = rng.range(1..=MAP_SIZE / 2);
+        let x = rng.range(0..=MAP_SIZE - 1 - length);
+        let y = rng.range(0..=MAP_SIZE - 1 - length);
+        let (mut a, mut b) = match rng.below(4) {
+            0 => ((x, y), (x + length, y)),
+            1 => ((x, y), (x, y + length)),
+            2 => ((x, y), (x + length, y + length)),
+            _ => ((x, y + length), (x + length, y)),
+        };
+        if rng.chance(0.5) {
+            (a, b) = (b, a);
+        }
+        writeln!(content, "{},{} -> {},{}", a.0, a.1, b.0, b.1).unwrap();
+    }
+
+    content
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::generator::generate;
+    use crate::Day5;
+    use aoc_common::{Rng, Solution};
+
+    #[test]
+    fn generate_valid_input() {
+        let input = Day5::parse(generate(100, &mut Rng::new(1))).unwrap();
+
+        assert_eq!(input.lines.len(), 100);
+        for line in &input.lines {
+            let (dx, dy) = (line.b.x - line.a.x, line.b.y - line.a.y);
+            assert!(dx == 0 || dy == 0 || dx.abs() == dy.abs());
+            assert!([line.a.x, line.a.y, line.b.x, line.b.y]
+                .iter()
+                .all(|coordinate| (0..1000).contains(coordinate)));
+        }
+    }
+}
@@ -1,43 +1,58 @@
 use crate::models::{Input, Line, Point};
-use std::error::Error;
-use std::fs;
+use crate::Day5;
+use aoc_common::{input_lines, read_lines, InputError, InputLine, ParseError, Solution};
+use std::io::BufRead;
 
-pub fn parse_file(file_path: String) -> Result<Input, Box<dyn Error>> {
-    let file_content = fs::read_to_string(file_path).expect("Error while reading the data file");
+pub fn parse_string(content: String) -> Result<Input, ParseError> {
+    let lines = input_lines(Day5::DAY, &content)
+        .filter(|line| !line.text.is_empty())
+        .map(|line| parse_line(&line))
+        .collect::<Result<_, _>>()?;
 
-    parse_string(file_content)
+    Ok(Input { lines })
 }
 
-fn parse_string(content: String) -> Result<Input, Box<dyn Error>> {
-    let lines = content
-        .lines()
-        .map(|string_line| {
-            let parts = string_line
-                .replace(" -> ", ",")
-                .split(',')
-                .map(|value| value.parse::<i32>().unwrap())
-                .collect::<Vec<i32>>();
-
-            Line {
-                a: Point {
-                    x: parts[0],
-                    y: parts[1],
-                },
-                b: Point {
-                    x: parts[2],
-                    y: parts[3],
-                },
-            }
-        })
-        .collect();
+/// Parses the lines of vents as they are read, without holding the whole input in memory
+pub fn parse_reader<R: BufRead>(reader: R) -> Result<Input, InputError> {
+    let mut lines = Vec::new();
+    read_lines(Day5::DAY, reader, |line| {
+        if !line.text.is_empty() {
+            lines.push(parse_line(&line)?);
+        }
+        Ok(())
+    })?;
 
     Ok(Input { lines })
 }
 
+fn parse_line(line: &InputLine) -> Result<Line, ParseError> {
+    let (a, b) = line
+        .text
+        .split_once(" -> ")
+        .ok_or_else(|| line.error_at_end("` -> `"))?;
+
+    Ok(Line {
+        a: parse_point(line, a)?,
+        b: parse_point(line, b)?,
+    })
+}
+
+fn parse_point(line: &InputLine, text: &str) -> Result<Point, ParseError> {
+    let (x, y) = text
+        .split_once(',')
+        .ok_or_else(|| line.error(text, "`x,y` coordinates"))?;
+
+    Ok(Point {
+        x: line.parse(x, "a number")?,
+        y: line.parse(y, "a number")?,
+    })
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::input_parsing::parse_string;
+    use crate::input_parsing::{parse_reader, parse_string};
     use crate::models::{Input, Line, Point};
+    use aoc_common::{InputError, ParseError};
 
     #[test]
     fn parse_example_case() {
@@ -104,4 +119,34 @@ mod tests {
             }
         );
     }
+
+    #[test]
+    fn parse_stream() {
+        let content = "0,9 -> 5,9\n8,0 -> 0,8\n\n9,4 -> 3,4";
+
+        assert_eq!(
+            parse_reader(content.as_bytes()).unwrap(),
+            parse_string(content.to_string()).unwrap()
+        );
+        assert!(matches!(
+            parse_reader("0,9 -> 5,9\n8,0 - 0,8\n".as_bytes()),
+            Err(InputError::Parse(error)) if error == ParseError::new(5, 2, 10, "` -> `")
+        ));
+    }
+
+    #[test]
+    fn parse_invalid_lines() {
+        assert_eq!(
+            parse_string("0,9 -> 5,9\n8,0 - 0,8\n".to_string()),
+            Err(ParseError::new(5, 2, 10, "` -> `"))
+        );
+        assert_eq!(
+            parse_string("0,9 -> 5;9\n".to_string()),
+            Err(ParseError::new(5, 1, 8, "`x,y` coordinates"))
+        );
+        assert_eq!(
+            parse_string("0,9 -> 5,a\n".to_string()),
+            Err(ParseError::new(5, 1, 10, "a number"))
+        );
+    }
 }
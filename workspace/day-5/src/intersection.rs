@@ -0,0 +1,198 @@
+use crate::models::{Line, Point};
+use std::cmp::Ordering;
+
+/// Common part of two lines of vents
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum Intersection {
+    /// The lines share a single point
+    Point(Point),
+    /// The lines are collinear and share the points of this line, oriented as the first line
+    Overlap(Line),
+}
+
+/// Coordinates widened so that products of differences cannot overflow
+fn coordinates(point: Point) -> (i128, i128) {
+    (point.x as i128, point.y as i128)
+}
+
+fn difference(a: (i128, i128), b: (i128, i128)) -> (i128, i128) {
+    (a.0 - b.0, a.1 - b.1)
+}
+
+fn cross(a: (i128, i128), b: (i128, i128)) -> i128 {
+    a.0 * b.1 - a.1 * b.0
+}
+
+fn dot(a: (i128, i128), b: (i128, i128)) -> i128 {
+    a.0 * b.0 + a.1 * b.1
+}
+
+impl Line {
+    /// Whether the point is on the line, between its ends
+    pub fn contains(&self, point: Point) -> bool {
+        let (a, b, p) = (coordinates(self.a), coordinates(self.b), coordinates(point));
+        let (direction, offset) = (difference(b, a), difference(p, a));
+
+        cross(direction, offset) == 0
+            && (0..=dot(direction, direction)).contains(&dot(direction, offset))
+            && (direction != (0, 0) || offset == (0, 0))
+    }
+
+    /// Points with integer coordinates the lines have in common, if any: a single point where
+    /// they cross or touch, or the part they share when they are collinear
+    pub fn intersection(&self, other: &Line) -> Option<Intersection> {
+        let (a, b) = (coordinates(self.a), coordinates(self.b));
+        let (c, d) = (coordinates(other.a), coordinates(other.b));
+        let (direction, other_direction) = (difference(b, a), difference(d, c));
+
+        if direction == (0, 0) {
+            return other
+                .contains(self.a)
+                .then_some(Intersection::Point(self.a));
+        }
+        if other_direction == (0, 0) {
+            return self
+                .contains(other.a)
+                .then_some(Intersection::Point(other.a));
+        }
+
+        let denominator = cross(direction, other_direction);
+        let offset = difference(c, a);
+        if denominator != 0 {
+            // Solve a + t * direction = c + u * other_direction, with t and u in [0, 1]
+            let (mut t, mut u) = (cross(offset, other_direction), cross(offset, direction));
+            let denominator = if denominator < 0 {
+                (t, u) = (-t, -u);
+                -denominator
+            } else {
+                denominator
+            };
+            if !(0..=denominator).contains(&t) || !(0..=denominator).contains(&u) {
+                return None;
+            }
+
+            let x = a.0 * denominator + t * direction.0;
+            let y = a.1 * denominator + t * direction.1;
+            if x % denominator != 0 || y % denominator != 0 {
+                return None;
+            }
+            let point = Point {
+                x: (x / denominator) as i32,
+                y: (y / denominator) as i32,
+            };
+            return Some(Intersection::Point(point));
+        }
+
+        if cross(offset, direction) != 0 {
+            // Parallel lines
+            return None;
+        }
+
+        // Collinear lines: positions of the ends along the first line, from 0 at `a`
+        let position = |p: (i128, i128)| dot(difference(p, a), direction);
+        let (mut start, mut end) = ((other.a, position(c)), (other.b, position(d)));
+        if start.1 > end.1 {
+            (start, end) = (end, start);
+        }
+        let start = if start.1 > 0 { start } else { (self.a, 0) };
+        let length = dot(direction, direction);
+        let end = if end.1 < length {
+            end
+        } else {
+            (self.b, length)
+        };
+
+        match start.1.cmp(&end.1) {
+            Ordering::Greater => None,
+            Ordering::Equal => Some(Intersection::Point(start.0)),
+            Ordering::Less => Some(Intersection::Overlap(Line {
+                a: start.0,
+                b: end.0,
+            })),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::intersection::Intersection;
+    use crate::models::{Line, Point};
+    use aoc_common::Rng;
+    use std::collections::HashSet;
+
+    #[test]
+    fn crossing_lines() {
+        assert_eq!(
+            Line::new((0, 0), (6, 3)).intersection(&Line::new((0, 3), (4, -1))),
+            Some(Intersection::Point(Point { x: 2, y: 1 }))
+        );
+        // Crossing between points with integer coordinates
+        assert_eq!(
+            Line::new((0, 0), (1, 1)).intersection(&Line::new((0, 1), (1, 0))),
+            None
+        );
+        // Lines too short to cross
+        assert_eq!(
+            Line::new((0, 0), (1, 0)).intersection(&Line::new((3, -1), (3, 1))),
+            None
+        );
+        // Touching ends
+        assert_eq!(
+            Line::new((0, 0), (3, 1)).intersection(&Line::new((3, 1), (5, 7))),
+            Some(Intersection::Point(Point { x: 3, y: 1 }))
+        );
+    }
+
+    #[test]
+    fn collinear_lines() {
+        assert_eq!(
+            Line::new((0, 0), (9, 3)).intersection(&Line::new((12, 4), (3, 1))),
+            Some(Intersection::Overlap(Line::new((3, 1), (9, 3))))
+        );
+        assert_eq!(
+            Line::new((3, 1), (9, 3)).intersection(&Line::new((-3, -1), (3, 1))),
+            Some(Intersection::Point(Point { x: 3, y: 1 }))
+        );
+        assert_eq!(
+            Line::new((0, 0), (3, 1)).intersection(&Line::new((6, 2), (9, 3))),
+            None
+        );
+        // Parallel lines
+        assert_eq!(
+            Line::new((0, 0), (3, 1)).intersection(&Line::new((0, 1), (3, 2))),
+            None
+        );
+        assert_eq!(
+            Line::new((2, 2), (2, 2)).intersection(&Line::new((0, 0), (4, 4))),
+            Some(Intersection::Point(Point { x: 2, y: 2 }))
+        );
+    }
+
+    #[test]
+    fn intersection_matches_lattice_points() {
+        let mut rng = Rng::new(6);
+        let mut random_line = || {
+            let mut coordinate = || rng.range(-4..=4) as i32;
+            Line::new((coordinate(), coordinate()), (coordinate(), coordinate()))
+        };
+
+        for _ in 0..2_000 {
+            let (first, second) = (random_line(), random_line());
+            let points = first.points().collect::<HashSet<_>>();
+            let expected = second
+                .points()
+                .filter(|point| points.contains(point))
+                .collect::<HashSet<_>>();
+
+            let intersection = match first.intersection(&second) {
+                None => HashSet::new(),
+                Some(Intersection::Point(point)) => HashSet::from([point]),
+                Some(Intersection::Overlap(overlap)) => {
+                    assert_eq!(overlap.step(), first.step());
+                    overlap.points().collect()
+                }
+            };
+            assert_eq!(intersection, expected, "{:?} and {:?}", first, second);
+        }
+    }
+}
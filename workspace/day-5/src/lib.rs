@@ -0,0 +1,50 @@
+use crate::input_parsing::{parse_reader, parse_string};
+use crate::models::Input;
+use aoc_common::{Answer, FrameSink, Generator, InputError, ParseError, Rng, Solution, Visualize};
+use std::io::{self, BufRead};
+
+pub mod generator;
+pub mod input_parsing;
+pub mod intersection;
+pub mod models;
+pub mod part_1;
+pub mod part_2;
+pub mod sweep;
+pub mod visualization;
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    const DAY: u8 = 5;
+    type Input = Input;
+
+    fn parse(content: String) -> Result<Input, ParseError> {
+        parse_string(content)
+    }
+
+    fn parse_reader<R: BufRead>(reader: R) -> Result<Input, InputError> {
+        parse_reader(reader)
+    }
+
+    fn part_1(input: &Input) -> Answer {
+        part_1::overlapping_points_count(input).into()
+    }
+
+    fn part_2(input: &Input) -> Answer {
+        part_2::overlapping_points_count(input).into()
+    }
+}
+
+impl Generator for Day5 {
+    const DEFAULT_SIZE: usize = 500;
+
+    fn generate(size: usize, rng: &mut Rng) -> String {
+        generator::generate(size, rng)
+    }
+}
+
+impl Visualize for Day5 {
+    fn visualize(input: &Input, frames: &mut dyn FrameSink) -> io::Result<()> {
+        visualization::visualize(input, frames)
+    }
+}
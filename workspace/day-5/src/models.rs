@@ -3,13 +3,23 @@ pub struct Input {
     pub lines: Vec<Line>,
 }
 
-#[derive(Debug, Eq, PartialEq)]
+/// Segment of vents between two points, of any slope. Only the points of the segment with integer
+/// coordinates hold vents.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
 pub struct Line {
     pub a: Point,
     pub b: Point,
 }
 
 impl Line {
+    /// Line from `(ax, ay)` to `(bx, by)`
+    pub fn new((ax, ay): (i32, i32), (bx, by): (i32, i32)) -> Self {
+        Line {
+            a: Point { x: ax, y: ay },
+            b: Point { x: bx, y: by },
+        }
+    }
+
     pub fn is_vertical(&self) -> bool {
         self.a.x == self.b.x
     }
@@ -17,10 +27,75 @@ impl Line {
     pub fn is_horizontal(&self) -> bool {
         self.a.y == self.b.y
     }
+
+    /// Smallest step from a point of the line with integer coordinates to the next one towards
+    /// `b`, `(0, 0)` for a single point line
+    pub fn step(&self) -> (i32, i32) {
+        let (dx, dy) = (self.b.x - self.a.x, self.b.y - self.a.y);
+        match gcd(dx, dy) {
+            0 => (0, 0),
+            divisor => (dx / divisor, dy / divisor),
+        }
+    }
+
+    /// Number of points of the line with integer coordinates
+    pub fn lattice_point_count(&self) -> usize {
+        let (dx, dy) = (self.b.x - self.a.x, self.b.y - self.a.y);
+        gcd(dx, dy) as usize + 1
+    }
+
+    /// Points of the line with integer coordinates, from `a` to `b`
+    pub fn points(&self) -> impl Iterator<Item = Point> {
+        let (a, (step_x, step_y)) = (self.a, self.step());
+        (0..self.lattice_point_count() as i32).map(move |i| Point {
+            x: a.x + i * step_x,
+            y: a.y + i * step_y,
+        })
+    }
 }
 
-#[derive(Debug, Eq, PartialEq, Hash)]
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
 pub struct Point {
     pub x: i32,
     pub y: i32,
 }
+
+/// Greatest common divisor of the absolute values
+fn gcd(a: i32, b: i32) -> i32 {
+    let (mut a, mut b) = (a.abs(), b.abs());
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::models::{Line, Point};
+
+    #[test]
+    fn lattice_points() {
+        let line = Line {
+            a: Point { x: 1, y: 7 },
+            b: Point { x: 7, y: 3 },
+        };
+
+        assert_eq!(line.step(), (3, -2));
+        assert_eq!(line.lattice_point_count(), 3);
+        assert_eq!(
+            line.points().collect::<Vec<_>>(),
+            vec![
+                Point { x: 1, y: 7 },
+                Point { x: 4, y: 5 },
+                Point { x: 7, y: 3 }
+            ]
+        );
+
+        let point = Line {
+            a: Point { x: 2, y: 2 },
+            b: Point { x: 2, y: 2 },
+        };
+        assert_eq!(point.step(), (0, 0));
+        assert_eq!(point.points().count(), 1);
+    }
+}
@@ -1,7 +1,19 @@
 use crate::models::{Input, Point};
+use crate::sweep;
 use std::collections::HashMap;
 
 pub fn overlapping_points_count(input: &Input) -> usize {
+    sweep::overlapping_points_count(
+        input
+            .lines
+            .iter()
+            .filter(|line| line.is_horizontal() || line.is_vertical()),
+    )
+}
+
+/// Counts the overlapping points by walking the lines point by point, as a reference for the
+/// sweep line
+pub fn rasterized_overlapping_points_count(input: &Input) -> usize {
     // Store all points that have a hit in the map in an hashmap, along with their number of crossing lines
     let mut points_in_map: HashMap<Point, usize> = HashMap::new();
 
@@ -27,16 +39,7 @@ pub fn overlapping_points_count(input: &Input) -> usize {
                     x: current_x,
                     y: current_y,
                 };
-                if points_in_map.contains_key(&point) {
-                    match points_in_map.get_mut(&point) {
-                        None => {}
-                        Some(count) => {
-                            *count += 1;
-                        }
-                    }
-                } else {
-                    points_in_map.insert(point, 1);
-                }
+                *points_in_map.entry(point).or_insert(0) += 1;
 
                 current_x += step_x;
                 current_y += step_y;
@@ -45,12 +48,10 @@ pub fn overlapping_points_count(input: &Input) -> usize {
     }
 
     // Compute the score
-    let total = points_in_map
-        .iter()
-        .map(|(_, count)| if *count > 1 { 1 } else { 0 })
-        .sum();
-
-    total
+    points_in_map
+        .values()
+        .map(|count| if *count > 1 { 1 } else { 0 })
+        .sum()
 }
 
 #[cfg(test)]
@@ -1,53 +1,29 @@
 use crate::models::{Input, Point};
+use crate::sweep;
 use std::collections::HashMap;
 
 pub fn overlapping_points_count(input: &Input) -> usize {
+    sweep::overlapping_points_count(&input.lines)
+}
+
+/// Counts the overlapping points by walking the lines point by point, as a reference for the
+/// sweep line
+pub fn rasterized_overlapping_points_count(input: &Input) -> usize {
     // Store all points that have a hit in the map in an hashmap, along with their number of crossing lines
     let mut points_in_map: HashMap<Point, usize> = HashMap::new();
 
-    // Process all lines
+    // Process all lines, of any slope, walking from a point with integer coordinates to the next
     for line in &input.lines {
-        // Compute steps to go from a to b
-        let mut step_x = line.b.x - line.a.x;
-        if step_x != 0 {
-            step_x = step_x / step_x.abs();
-        }
-        let mut step_y = line.b.y - line.a.y;
-        if step_y != 0 {
-            step_y = step_y / step_y.abs();
-        }
-
-        // Walk along the line and insert the points
-        let mut current_x = line.a.x;
-        let mut current_y = line.a.y;
-        while current_x - step_x != line.b.x || current_y - step_y != line.b.y {
-            let point = Point {
-                x: current_x,
-                y: current_y,
-            };
-            if points_in_map.contains_key(&point) {
-                match points_in_map.get_mut(&point) {
-                    None => {}
-                    Some(count) => {
-                        *count += 1;
-                    }
-                }
-            } else {
-                points_in_map.insert(point, 1);
-            }
-
-            current_x += step_x;
-            current_y += step_y;
+        for point in line.points() {
+            *points_in_map.entry(point).or_insert(0) += 1;
         }
     }
 
     // Compute the score
-    let total = points_in_map
-        .iter()
-        .map(|(_, count)| if *count > 1 { 1 } else { 0 })
-        .sum();
-
-    total
+    points_in_map
+        .values()
+        .map(|count| if *count > 1 { 1 } else { 0 })
+        .sum()
 }
 
 #[cfg(test)]
@@ -0,0 +1,292 @@
+use crate::models::Line;
+
+/// Direction of lines of vents, as the step between their points with integer coordinates. Its
+/// first non zero coordinate is positive, so that a direction and its opposite are the same.
+#[derive(Debug, Clone, Copy, Eq, Ord, PartialEq, PartialOrd)]
+struct Direction {
+    dx: i128,
+    dy: i128,
+}
+
+impl Direction {
+    fn new(line: &Line) -> Self {
+        let (dx, dy) = line.step();
+        let (dx, dy) = match (dx, dy) {
+            // Single points are given any direction
+            (0, 0) => (1, 0),
+            (dx, dy) if dx < 0 || (dx == 0 && dy < 0) => (-dx, -dy),
+            (dx, dy) => (dx, dy),
+        };
+
+        Direction {
+            dx: dx as i128,
+            dy: dy as i128,
+        }
+    }
+
+    /// Coefficients `(a, b)` of the equation `a * x + b * y = key` of the lines
+    fn coefficients(self) -> (i128, i128) {
+        (self.dy, -self.dx)
+    }
+
+    /// Position of a point along the lines, increasing by `spacing` from a point with integer
+    /// coordinates to the next one
+    fn position(self, (x, y): (i128, i128)) -> i128 {
+        x * self.dx + y * self.dy
+    }
+
+    fn spacing(self) -> i128 {
+        self.dx * self.dx + self.dy * self.dy
+    }
+}
+
+/// A line of vents, as the interval of positions it covers on its supporting line
+#[derive(Debug, Clone, Copy, Eq, Ord, PartialEq, PartialOrd)]
+struct Segment {
+    direction: Direction,
+    key: i128,
+    start: i128,
+    end: i128,
+}
+
+impl Segment {
+    fn new(line: &Line) -> Self {
+        let (a, b) = (
+            (line.a.x as i128, line.a.y as i128),
+            (line.b.x as i128, line.b.y as i128),
+        );
+        let direction = Direction::new(line);
+        let (a_position, b_position) = (direction.position(a), direction.position(b));
+        let (x, y) = direction.coefficients();
+
+        Segment {
+            direction,
+            key: x * a.0 + y * a.1,
+            start: a_position.min(b_position),
+            end: a_position.max(b_position),
+        }
+    }
+
+    /// Point where the supporting lines of the segments cross, if it has integer coordinates
+    fn crossing(&self, other: &Segment) -> Option<(i128, i128)> {
+        let (a1, b1) = self.direction.coefficients();
+        let (a2, b2) = other.direction.coefficients();
+        let determinant = a1 * b2 - a2 * b1;
+        if determinant == 0 {
+            return None;
+        }
+
+        let x = self.key * b2 - other.key * b1;
+        let y = a1 * other.key - a2 * self.key;
+        if x % determinant != 0 || y % determinant != 0 {
+            return None;
+        }
+        Some((x / determinant, y / determinant))
+    }
+
+    fn contains(&self, position: i128) -> bool {
+        (self.start..=self.end).contains(&position)
+    }
+}
+
+#[derive(Debug, Clone, Copy, Eq, Ord, PartialEq, PartialOrd)]
+enum Event {
+    /// A segment of the line starts
+    Start,
+    /// A segment of the line ended on the previous point
+    End,
+    /// A segment of another direction crosses the line, `first` if its direction comes first
+    Crossing { first: bool },
+}
+
+/// Counts the points with integer coordinates covered by at least two lines, without walking the
+/// lines point by point.
+///
+/// The segments are grouped by supporting line, and each supporting line is swept along: its
+/// segments are intervals, and a segment of another direction crosses it on a single point.
+/// Points where segments of the line overlap are counted by interval lengths, and crossing
+/// points one by one. A point covered by several directions is only counted on the line of the
+/// first of them. The memory used is proportional to the number of segments, whatever their
+/// coordinates, and the time quadratic in it.
+pub fn overlapping_points_count<'a>(lines: impl IntoIterator<Item = &'a Line>) -> usize {
+    let mut segments = lines.into_iter().map(Segment::new).collect::<Vec<_>>();
+    segments.sort_unstable();
+
+    segments
+        .chunk_by(|a, b| (a.direction, a.key) == (b.direction, b.key))
+        .map(|line_segments| count_on_line(line_segments, &segments))
+        .sum::<i128>() as usize
+}
+
+/// Counts the points of a supporting line, whose segments are given, covered by at least two
+/// segments and by no segment of a direction coming first
+fn count_on_line(line_segments: &[Segment], segments: &[Segment]) -> i128 {
+    let line = line_segments[0];
+    let end = line_segments
+        .iter()
+        .map(|segment| segment.end)
+        .max()
+        .unwrap();
+
+    let mut events = Vec::with_capacity(2 * line_segments.len());
+    for segment in line_segments {
+        events.push((segment.start, Event::Start));
+        events.push((segment.end + line.direction.spacing(), Event::End));
+    }
+    for segment in segments
+        .iter()
+        .filter(|segment| segment.direction != line.direction)
+    {
+        let Some(point) = line.crossing(segment) else {
+            continue;
+        };
+        let position = line.direction.position(point);
+        if segment.contains(segment.direction.position(point))
+            && (line.start..=end).contains(&position)
+        {
+            let first = segment.direction < line.direction;
+            events.push((position, Event::Crossing { first }));
+        }
+    }
+    events.sort_unstable();
+
+    let mut count = 0;
+    let mut coverage = 0;
+    let mut previous_position = line.start;
+    for position_events in events.chunk_by(|a, b| a.0 == b.0) {
+        let position = position_events[0].0;
+        if coverage >= 2 {
+            count += (position - previous_position) / line.direction.spacing();
+        }
+        previous_position = position;
+
+        let mut crossings = 0;
+        let mut crossed_first = false;
+        for &(_, event) in position_events {
+            match event {
+                Event::Start => coverage += 1,
+                Event::End => coverage -= 1,
+                Event::Crossing { first } => {
+                    crossings += 1;
+                    crossed_first |= first;
+                }
+            }
+        }
+
+        // A point the line covers twice is already counted with the overlaps, and belongs to
+        // the line of a direction coming first if any crosses it
+        if crossings > 0 {
+            match (coverage, crossed_first) {
+                (1, false) => count += 1,
+                (2.., true) => count -= 1,
+                _ => {}
+            }
+        }
+    }
+
+    count
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::models::{Input, Line};
+    use crate::sweep::overlapping_points_count;
+    use crate::{part_1, part_2};
+    use aoc_common::Rng;
+
+    #[test]
+    fn huge_coordinates() {
+        let lines = [
+            Line::new((0, 5_000_000), (2_000_000, 5_000_000)),
+            Line::new((3_000_000, 5_000_000), (1_000_000, 5_000_000)),
+            Line::new((1_500_000, 0), (1_500_000, 9_000_000)),
+            Line::new((0, 3_500_000), (4_000_000, 7_500_000)),
+            Line::new((2_500_000, 4_000_000), (2_500_000, 6_000_000)),
+        ];
+
+        // The overlap of the horizontal lines, covering the crossings at x = 1,500,000, and the
+        // crossings of the last line with the second one and with the diagonal
+        assert_eq!(overlapping_points_count(&lines), 1_000_001 + 2);
+    }
+
+    #[test]
+    fn crossings_of_several_directions() {
+        let lines = [
+            Line::new((0, 2), (4, 2)),
+            Line::new((2, 0), (2, 4)),
+            Line::new((0, 0), (4, 4)),
+            Line::new((0, 4), (4, 0)),
+            // Diagonals crossing between lattice points
+            Line::new((0, 1), (1, 0)),
+            Line::new((0, 0), (1, 1)),
+        ];
+
+        // The last diagonal overlaps the first one on 2 points
+        assert_eq!(overlapping_points_count(&lines), 3);
+        assert_eq!(overlapping_points_count(&lines[..4]), 1);
+    }
+
+    #[test]
+    fn sweep_matches_rasterization() {
+        let mut rng = Rng::new(5);
+        for _ in 0..200 {
+            let lines = (0..rng.range(0..=12))
+                .map(|_| {
+                    let length = rng.range(1..=6) as i32;
+                    let (x, y) = (rng.range(0..=6) as i32, rng.range(0..=6) as i32);
+                    let (a, b) = match rng.below(4) {
+                        0 => ((x, y), (x + length, y)),
+                        1 => ((x, y), (x, y + length)),
+                        2 => ((x, y), (x + length, y + length)),
+                        _ => ((x, y + length), (x + length, y)),
+                    };
+                    if rng.chance(0.5) {
+                        Line::new(a, b)
+                    } else {
+                        Line::new(b, a)
+                    }
+                })
+                .collect::<Vec<_>>();
+            let input = Input { lines };
+
+            assert_eq!(
+                part_1::overlapping_points_count(&input),
+                part_1::rasterized_overlapping_points_count(&input)
+            );
+            assert_eq!(
+                part_2::overlapping_points_count(&input),
+                part_2::rasterized_overlapping_points_count(&input)
+            );
+        }
+    }
+
+    #[test]
+    fn any_slope() {
+        let lines = [
+            Line::new((0, 0), (6, 3)),
+            Line::new((0, 3), (4, -1)),
+            Line::new((8, 4), (2, 1)),
+            // Crossing the first line between its points with integer coordinates
+            Line::new((1, 0), (1, 1)),
+        ];
+
+        // (2, 1) where the first two lines cross, and (2, 1), (4, 2), (6, 3) on the overlap
+        assert_eq!(overlapping_points_count(&lines), 3);
+
+        let mut rng = Rng::new(7);
+        for _ in 0..200 {
+            let lines = (0..rng.range(0..=10))
+                .map(|_| {
+                    let mut coordinate = || rng.range(-6..=6) as i32;
+                    Line::new((coordinate(), coordinate()), (coordinate(), coordinate()))
+                })
+                .collect::<Vec<_>>();
+            let input = Input { lines };
+
+            assert_eq!(
+                part_2::overlapping_points_count(&input),
+                part_2::rasterized_overlapping_points_count(&input)
+            );
+        }
+    }
+}
@@ -0,0 +1,54 @@
+use crate::models::Input;
+use aoc_common::{FrameSink, Grid};
+use std::io;
+
+/// Frames shown at most, the lines being drawn by batches on large inputs
+const MAX_FRAMES: usize = 100;
+
+/// Shows the vents being drawn, lines of any slope included. Points covered by a single line are
+/// gray, points where lines overlap are white.
+pub fn visualize(input: &Input, frames: &mut dyn FrameSink) -> io::Result<()> {
+    // The frames only cover the bounding box of the lines
+    let xs = || input.lines.iter().flat_map(|line| [line.a.x, line.b.x]);
+    let ys = || input.lines.iter().flat_map(|line| [line.a.y, line.b.y]);
+    let (x_min, y_min) = (xs().min().unwrap_or(0), ys().min().unwrap_or(0));
+    let width = xs().max().map_or(0, |x_max| (x_max - x_min + 1) as usize);
+    let height = ys().max().map_or(0, |y_max| (y_max - y_min + 1) as usize);
+
+    let mut frame = Grid::filled(width, height, 0u8);
+    let batch_size = input.lines.len().div_ceil(MAX_FRAMES).max(1);
+    for lines in input.lines.chunks(batch_size) {
+        for line in lines {
+            for point in line.points() {
+                let position = ((point.x - x_min) as usize, (point.y - y_min) as usize);
+                frame[position] = if frame[position] == 0 { 128 } else { 255 };
+            }
+        }
+        frames.frame(&frame)?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::input_parsing::parse_string;
+    use crate::visualization::visualize;
+
+    #[test]
+    fn visualize_example_case() {
+        let input = parse_string(include_str!("../dataset/example.txt").to_string()).unwrap();
+        let mut frames = Vec::new();
+        visualize(&input, &mut frames).unwrap();
+
+        let overlaps = |frame: usize| {
+            frames[frame]
+                .values()
+                .filter(|&&level| level == 255)
+                .count()
+        };
+        assert_eq!(frames.len(), 10);
+        assert_eq!((frames[0].width(), frames[0].height()), (10, 10));
+        assert_eq!((overlaps(0), overlaps(9)), (0, 12));
+    }
+}
@@ -0,0 +1,28 @@
+use aoc_common::{Answer, Solution};
+use day_5::input_parsing::parse_string;
+use day_5::Day5;
+use day_5::{part_1, part_2};
+
+const EXAMPLE: &str = include_str!("../dataset/example.txt");
+
+#[test]
+fn part_1_example_case() {
+    let input = parse_string(EXAMPLE.to_string()).unwrap();
+
+    assert_eq!(part_1::overlapping_points_count(&input), 5);
+}
+
+#[test]
+fn part_2_example_case() {
+    let input = parse_string(EXAMPLE.to_string()).unwrap();
+
+    assert_eq!(part_2::overlapping_points_count(&input), 12);
+}
+
+#[test]
+fn solution_example_case() {
+    let input = Day5::parse(EXAMPLE.to_string()).unwrap();
+
+    assert_eq!(Day5::part_1(&input), Answer::Number(5));
+    assert_eq!(Day5::part_2(&input), Answer::Number(12));
+}
@@ -0,0 +1,26 @@
+use aoc_common::Rng;
+
+/// Generates the timers of `size` lanternfish, from 1 to 5 like in the puzzle inputs
+pub fn generate(size: usize, rng: &mut Rng) -> String {
+    let timers = (0..size)
+        .map(|_| rng.range(1..=5).to_string())
+        .collect::<Vec<_>>();
+
+    timers.join(",") + "\n"
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::generator::generate;
+    use crate::Day6;
+    use aoc_common::{Rng, Solution};
+
+    #[test]
+    fn generate_valid_input() {
+        let input = Day6::parse(generate(300, &mut Rng::new(1))).unwrap();
+
+        assert_eq!(input.fish_per_timer.iter().sum::<u64>(), 300);
+        assert_eq!(input.fish_per_timer[0], 0);
+        assert!(input.fish_per_timer[6..].iter().all(|&count| count == 0));
+    }
+}
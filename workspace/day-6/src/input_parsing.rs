@@ -1,23 +1,18 @@
 use crate::models::Input;
-use std::error::Error;
-use std::fs;
+use crate::Day6;
+use aoc_common::{input_lines, ParseError, Solution};
 
-pub fn parse_file(file_path: String) -> Result<Input, Box<dyn Error>> {
-    let file_content = fs::read_to_string(file_path).expect("Error while reading the data file");
-
-    parse_string(file_content)
-}
-
-fn parse_string(content: String) -> Result<Input, Box<dyn Error>> {
-    let fish_timers = content
-        .trim()
-        .split(',')
-        .map(|timer| timer.parse::<usize>())
-        .collect::<Result<Vec<usize>, _>>()?;
+pub fn parse_string(content: String) -> Result<Input, ParseError> {
+    let line = input_lines(Day6::DAY, &content)
+        .next()
+        .ok_or_else(|| ParseError::new(Day6::DAY, 1, 1, "the fish timers"))?;
 
     let mut fish_per_timer = [0; 9];
-    for fish in fish_timers {
-        fish_per_timer[fish] += 1;
+    for timer in line.text.trim().split(',') {
+        match line.parse::<usize>(timer, "a timer between 0 and 8")? {
+            fish if fish < fish_per_timer.len() => fish_per_timer[fish] += 1,
+            _ => return Err(line.error(timer, "a timer between 0 and 8")),
+        }
     }
 
     Ok(Input { fish_per_timer })
@@ -27,6 +22,7 @@ fn parse_string(content: String) -> Result<Input, Box<dyn Error>> {
 mod tests {
     use crate::input_parsing::parse_string;
     use crate::models::Input;
+    use aoc_common::ParseError;
 
     #[test]
     fn parse_example_case() {
@@ -43,4 +39,16 @@ mod tests {
             }
         );
     }
+
+    #[test]
+    fn parse_invalid_timers() {
+        assert_eq!(
+            parse_string("3,4,9,1\n".to_string()),
+            Err(ParseError::new(6, 1, 5, "a timer between 0 and 8"))
+        );
+        assert_eq!(
+            parse_string("".to_string()),
+            Err(ParseError::new(6, 1, 1, "the fish timers"))
+        );
+    }
 }
@@ -0,0 +1,35 @@
+use crate::input_parsing::parse_string;
+use crate::models::Input;
+use aoc_common::{Answer, Generator, ParseError, Rng, Solution};
+
+pub mod generator;
+pub mod input_parsing;
+pub mod models;
+pub mod simulate;
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    const DAY: u8 = 6;
+    type Input = Input;
+
+    fn parse(content: String) -> Result<Input, ParseError> {
+        parse_string(content)
+    }
+
+    fn part_1(input: &Input) -> Answer {
+        simulate::simulate_lanternfish_population(input, 80).into()
+    }
+
+    fn part_2(input: &Input) -> Answer {
+        simulate::simulate_lanternfish_population(input, 256).into()
+    }
+}
+
+impl Generator for Day6 {
+    const DEFAULT_SIZE: usize = 300;
+
+    fn generate(size: usize, rng: &mut Rng) -> String {
+        generator::generate(size, rng)
+    }
+}
@@ -1,7 +1,7 @@
 use crate::models::Input;
 
 pub fn simulate_lanternfish_population(input: &Input, days: usize) -> u64 {
-    let mut fish_population = input.fish_per_timer.clone();
+    let mut fish_population = input.fish_per_timer;
     for _i in 0..days {
         let fishes_day_0 = fish_population[0];
         fish_population.copy_within(1..9, 0);
@@ -0,0 +1,29 @@
+use aoc_common::{Answer, Solution};
+use day_6::input_parsing::parse_string;
+use day_6::simulate::simulate_lanternfish_population;
+use day_6::Day6;
+
+const EXAMPLE: &str = include_str!("../dataset/example.txt");
+
+#[test]
+fn part_1_example_case() {
+    let input = parse_string(EXAMPLE.to_string()).unwrap();
+
+    assert_eq!(simulate_lanternfish_population(&input, 18), 26);
+    assert_eq!(simulate_lanternfish_population(&input, 80), 5934);
+}
+
+#[test]
+fn part_2_example_case() {
+    let input = parse_string(EXAMPLE.to_string()).unwrap();
+
+    assert_eq!(simulate_lanternfish_population(&input, 256), 26984457539);
+}
+
+#[test]
+fn solution_example_case() {
+    let input = Day6::parse(EXAMPLE.to_string()).unwrap();
+
+    assert_eq!(Day6::part_1(&input), Answer::Number(5934));
+    assert_eq!(Day6::part_2(&input), Answer::Number(26984457539));
+}
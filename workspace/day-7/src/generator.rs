@@ -0,0 +1,56 @@
+use aoc_common::Rng;
+
+/// Generates the positions of `size` crabs. As in the puzzle inputs, small positions are more
+/// frequent and the cheapest alignment lies below the number of crabs.
+pub fn generate(size: usize, rng: &mut Rng) -> String {
+    let max_position = 2 * size as i64;
+    let positions = (0..size)
+        .map(|_| {
+            let position = rng.range(0..=max_position).min(rng.range(0..=max_position));
+            position.to_string()
+        })
+        .collect::<Vec<_>>();
+
+    positions.join(",") + "\n"
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::generator::generate;
+    use crate::Day7;
+    use aoc_common::{Answer, Rng, Solution};
+
+    /// Cheapest alignment over every position
+    fn naive_cheapest_alignment(positions: &[usize], cost: impl Fn(usize) -> usize) -> usize {
+        let max_position = *positions.iter().max().unwrap();
+        (0..=max_position)
+            .map(|target| {
+                positions
+                    .iter()
+                    .map(|&position| cost(position.abs_diff(target)))
+                    .sum()
+            })
+            .min()
+            .unwrap()
+    }
+
+    #[test]
+    fn generate_valid_input() {
+        for seed in 0..5 {
+            let input = Day7::parse(generate(200, &mut Rng::new(seed))).unwrap();
+            let positions = &input.crab_positions;
+
+            assert_eq!(positions.len(), 200);
+            assert_eq!(
+                Day7::part_1(&input),
+                Answer::from(naive_cheapest_alignment(positions, |distance| distance))
+            );
+            assert_eq!(
+                Day7::part_2(&input),
+                Answer::from(naive_cheapest_alignment(positions, |distance| {
+                    distance * (distance + 1) / 2
+                }))
+            );
+        }
+    }
+}
@@ -1,18 +1,17 @@
 use crate::models::Input;
-use std::error::Error;
-use std::fs;
+use crate::Day7;
+use aoc_common::{input_lines, ParseError, Solution};
 
-pub fn parse_file(file_path: String) -> Result<Input, Box<dyn Error>> {
-    let file_content = fs::read_to_string(file_path).expect("Error while reading the data file");
+pub fn parse_string(content: String) -> Result<Input, ParseError> {
+    let line = input_lines(Day7::DAY, &content)
+        .next()
+        .ok_or_else(|| ParseError::new(Day7::DAY, 1, 1, "the crab positions"))?;
 
-    parse_string(file_content)
-}
-
-fn parse_string(content: String) -> Result<Input, Box<dyn Error>> {
-    let mut crab_positions = content
+    let mut crab_positions = line
+        .text
         .trim()
         .split(',')
-        .map(|position| position.parse::<usize>())
+        .map(|position| line.parse::<usize>(position, "a position"))
         .collect::<Result<Vec<usize>, _>>()?;
     crab_positions.sort();
 
@@ -23,6 +22,7 @@ fn parse_string(content: String) -> Result<Input, Box<dyn Error>> {
 mod tests {
     use crate::input_parsing::parse_string;
     use crate::models::Input;
+    use aoc_common::ParseError;
 
     #[test]
     fn parse_example_case() {
@@ -39,4 +39,12 @@ mod tests {
             }
         );
     }
+
+    #[test]
+    fn parse_invalid_positions() {
+        assert_eq!(
+            parse_string("16,1,-2\n".to_string()),
+            Err(ParseError::new(7, 1, 6, "a position"))
+        );
+    }
 }
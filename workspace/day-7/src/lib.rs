@@ -0,0 +1,36 @@
+use crate::input_parsing::parse_string;
+use crate::models::Input;
+use aoc_common::{Answer, Generator, ParseError, Rng, Solution};
+
+pub mod generator;
+pub mod input_parsing;
+pub mod models;
+pub mod part_1;
+pub mod part_2;
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    const DAY: u8 = 7;
+    type Input = Input;
+
+    fn parse(content: String) -> Result<Input, ParseError> {
+        parse_string(content)
+    }
+
+    fn part_1(input: &Input) -> Answer {
+        part_1::cheapest_alignment(input).into()
+    }
+
+    fn part_2(input: &Input) -> Answer {
+        part_2::cheapest_alignment(input).into()
+    }
+}
+
+impl Generator for Day7 {
+    const DEFAULT_SIZE: usize = 1000;
+
+    fn generate(size: usize, rng: &mut Rng) -> String {
+        generator::generate(size, rng)
+    }
+}
@@ -36,13 +36,7 @@ fn fuel_cost(input: &Input, position: i64) -> usize {
     input
         .crab_positions
         .iter()
-        .map(|&crab| {
-            if crab < position {
-                position - crab
-            } else {
-                crab - position
-            }
-        })
+        .map(|&crab| crab.abs_diff(position))
         .sum()
 }
 
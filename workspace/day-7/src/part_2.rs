@@ -37,11 +37,7 @@ fn fuel_cost(input: &Input, position: i64) -> usize {
         .crab_positions
         .iter()
         .map(|&crab| {
-            let diff = if crab < position {
-                position - crab
-            } else {
-                crab - position
-            };
+            let diff = crab.abs_diff(position);
             diff * (diff + 1) / 2
         })
         .sum()
@@ -0,0 +1,28 @@
+use aoc_common::{Answer, Solution};
+use day_7::input_parsing::parse_string;
+use day_7::Day7;
+use day_7::{part_1, part_2};
+
+const EXAMPLE: &str = include_str!("../dataset/example.txt");
+
+#[test]
+fn part_1_example_case() {
+    let input = parse_string(EXAMPLE.to_string()).unwrap();
+
+    assert_eq!(part_1::cheapest_alignment(&input), 37);
+}
+
+#[test]
+fn part_2_example_case() {
+    let input = parse_string(EXAMPLE.to_string()).unwrap();
+
+    assert_eq!(part_2::cheapest_alignment(&input), 168);
+}
+
+#[test]
+fn solution_example_case() {
+    let input = Day7::parse(EXAMPLE.to_string()).unwrap();
+
+    assert_eq!(Day7::part_1(&input), Answer::Number(37));
+    assert_eq!(Day7::part_2(&input), Answer::Number(168));
+}
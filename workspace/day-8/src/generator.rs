@@ -0,0 +1,63 @@
+use aoc_common::Rng;
+use std::fmt::Write;
+
+/// Segments of each digit, with the segments named as in the puzzle statement
+const DIGIT_SEGMENTS: [&str; 10] = [
+    "abcefg", "cf", "acdeg", "acdfg", "bcdf", "abdfg", "abdefg", "acf", "abcdefg", "abcdfg",
+];
+
+/// Generates `size` entries, each one with its own random wiring of the segments
+pub fn generate(size: usize, rng: &mut Rng) -> String {
+    let mut content = String::new();
+    for _ in 0..size {
+        let mut wiring = "abcdefg".chars().collect::<Vec<_>>();
+        rng.shuffle(&mut wiring);
+
+        let mut digits = (0..10).collect::<Vec<_>>();
+        rng.shuffle(&mut digits);
+        let patterns = digits
+            .iter()
+            .map(|&digit| pattern(digit, &wiring, rng))
+            .collect::<Vec<_>>();
+        let values = (0..4)
+            .map(|_| {
+                let digit = rng.below(10);
+                pattern(digit, &wiring, rng)
+            })
+            .collect::<Vec<_>>();
+
+        writeln!(content, "{} | {}", patterns.join(" "), values.join(" ")).unwrap();
+    }
+
+    content
+}
+
+/// Pattern lighting the digit through the wiring, its segments in any order
+fn pattern(digit: usize, wiring: &[char], rng: &mut Rng) -> String {
+    let mut segments = DIGIT_SEGMENTS[digit]
+        .bytes()
+        .map(|segment| wiring[(segment - b'a') as usize])
+        .collect::<Vec<_>>();
+    rng.shuffle(&mut segments);
+
+    segments.into_iter().collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::generator::generate;
+    use crate::Day8;
+    use aoc_common::{Answer, Rng, Solution};
+
+    #[test]
+    fn generate_valid_input() {
+        let input = Day8::parse(generate(50, &mut Rng::new(1))).unwrap();
+
+        assert_eq!(input.data.len(), 50);
+        // Every output value decodes to a number of 4 digits at most
+        let Answer::Number(sum) = Day8::part_2(&input) else {
+            panic!("expected a number");
+        };
+        assert!(sum < 50 * 10_000);
+    }
+}
@@ -1,43 +1,68 @@
 use crate::models::{Entry, Input};
+use crate::Day8;
+use aoc_common::{input_lines, read_lines, InputError, InputLine, ParseError, Solution};
 use itertools::Itertools;
-use std::error::Error;
-use std::fs;
+use std::io::BufRead;
 
-pub fn parse_file(file_path: String) -> Result<Input, Box<dyn Error>> {
-    let file_content = fs::read_to_string(file_path).expect("Error while reading the data file");
+pub fn parse_string(content: String) -> Result<Input, ParseError> {
+    let data = input_lines(Day8::DAY, &content)
+        .filter(|line| !line.text.is_empty())
+        .map(|line| parse_entry(&line))
+        .collect::<Result<_, _>>()?;
 
-    parse_string(file_content)
+    Ok(Input { data })
 }
 
-pub fn parse_string(content: String) -> Result<Input, Box<dyn Error>> {
-    let data = content
-        .lines()
-        .map(|line| {
-            let parts = line.split('|').collect::<Vec<&str>>();
-
-            let patterns = parts[0]
-                .trim()
-                .split_ascii_whitespace()
-                .map(|str| str.chars().sorted().collect::<String>())
-                .collect::<Vec<String>>();
-
-            let values = parts[1]
-                .trim()
-                .split_ascii_whitespace()
-                .map(|str| str.chars().sorted().collect::<String>())
-                .collect::<Vec<String>>();
-
-            Entry::new(patterns, values)
-        })
-        .collect();
+/// Parses the entries as they are read, without holding the whole input in memory
+pub fn parse_reader<R: BufRead>(reader: R) -> Result<Input, InputError> {
+    let mut data = Vec::new();
+    read_lines(Day8::DAY, reader, |line| {
+        if !line.text.is_empty() {
+            data.push(parse_entry(&line)?);
+        }
+        Ok(())
+    })?;
 
     Ok(Input { data })
 }
 
+fn parse_entry(line: &InputLine) -> Result<Entry, ParseError> {
+    let (patterns, values) = line
+        .text
+        .split_once('|')
+        .ok_or_else(|| line.error_at_end("`|`"))?;
+
+    let patterns = parse_patterns(line, patterns, 10)?;
+    let values = parse_patterns(line, values, 4)?;
+
+    Ok(Entry::new(patterns, values))
+}
+
+/// Parses exactly `count` patterns of segments, sorting the segments of each one
+fn parse_patterns(line: &InputLine, text: &str, count: usize) -> Result<Vec<String>, ParseError> {
+    let patterns = text
+        .split_ascii_whitespace()
+        .map(|pattern| {
+            let invalid_segment = pattern.find(|c| !('a'..='g').contains(&c));
+            match invalid_segment {
+                Some(offset) => Err(line.error(&pattern[offset..], "a segment from `a` to `g`")),
+                None => Ok(pattern.chars().sorted().collect::<String>()),
+            }
+        })
+        .collect::<Result<Vec<String>, _>>()?;
+
+    if patterns.len() != count {
+        return Err(line.error(text, format!("{} patterns", count)));
+    }
+
+    Ok(patterns)
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::input_parsing::parse_string;
+    use crate::input_parsing::{parse_reader, parse_string};
     use crate::models::{Entry, Input};
+    use aoc_common::{InputError, ParseError};
 
     #[test]
     fn parse_example_case() {
@@ -74,4 +99,45 @@ mod tests {
             }
         );
     }
+
+    #[test]
+    fn parse_stream() {
+        let content =
+            "be cfbegad cbdgef fgaecd cgeb fdcge agebfd fecdb fabcd edb | fdgacbe cefdb cefbgd gcbe
+edbfga begcd cbg gc gcadebf fbgde acbgfd abcde gfcbed gfec | fcgedb cgb dgebacf gc
+";
+
+        assert_eq!(
+            parse_reader(content.as_bytes()).unwrap(),
+            parse_string(content.to_string()).unwrap()
+        );
+        assert!(matches!(
+            parse_reader("be cfbegad cbdgef fgaecd cgeb fdcge agebfd fecdb fabcd edb\n".as_bytes()),
+            Err(InputError::Parse(error)) if error == ParseError::new(8, 1, 59, "`|`")
+        ));
+    }
+
+    #[test]
+    fn parse_invalid_entries() {
+        assert_eq!(
+            parse_string(
+                "be cfbegad cbdgef fgaecd cgeb fdcge agebfd fecdb fabcd edb\n".to_string()
+            ),
+            Err(ParseError::new(8, 1, 59, "`|`"))
+        );
+        assert_eq!(
+            parse_string(
+                "be cfbegad cbdgef fgaecd cgeb fdcge agebfd fecdb fabcd edb | fdgacbe cefdb cefbgd"
+                    .to_string()
+            ),
+            Err(ParseError::new(8, 1, 61, "4 patterns"))
+        );
+        assert_eq!(
+            parse_string(
+                "be cfbegad cbdgef fgaecd cgeb fdcge agebfd fecdb fabcd edb | fdgacbe cefdb cxfbgd gcbe"
+                    .to_string()
+            ),
+            Err(ParseError::new(8, 1, 77, "a segment from `a` to `g`"))
+        );
+    }
 }
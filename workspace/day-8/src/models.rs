@@ -49,12 +49,9 @@ impl Entry {
             }
         }
 
-        let segment_b =
-            ('a' as u8 + segment_count.iter().position(|&n| n == 6).unwrap() as u8) as char;
-        let segment_e =
-            ('a' as u8 + segment_count.iter().position(|&n| n == 4).unwrap() as u8) as char;
-        let segment_f =
-            ('a' as u8 + segment_count.iter().position(|&n| n == 9).unwrap() as u8) as char;
+        let segment_b = (b'a' + segment_count.iter().position(|&n| n == 6).unwrap() as u8) as char;
+        let segment_e = (b'a' + segment_count.iter().position(|&n| n == 4).unwrap() as u8) as char;
+        let segment_f = (b'a' + segment_count.iter().position(|&n| n == 9).unwrap() as u8) as char;
 
         // Find segment c
         let mut segment_c = ' ';
@@ -168,6 +165,6 @@ impl Entry {
 
     pub fn pattern_to_digit(&self, pattern: String) -> usize {
         let position = self.patterns.iter().position(|p| *p == pattern).unwrap();
-        return self.found_patterns[position];
+        self.found_patterns[position]
     }
 }
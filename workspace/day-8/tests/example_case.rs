@@ -0,0 +1,29 @@
+use aoc_common::{Answer, Solution};
+use day_8::input_parsing::parse_string;
+use day_8::part_1::count_1_4_7_8;
+use day_8::part_2::sum_output;
+use day_8::Day8;
+
+const EXAMPLE: &str = include_str!("../dataset/example.txt");
+
+#[test]
+fn part_1_example_case() {
+    let input = parse_string(EXAMPLE.to_string()).unwrap();
+
+    assert_eq!(count_1_4_7_8(&input), 26);
+}
+
+#[test]
+fn part_2_example_case() {
+    let input = parse_string(EXAMPLE.to_string()).unwrap();
+
+    assert_eq!(sum_output(&input), 61229);
+}
+
+#[test]
+fn solution_example_case() {
+    let input = Day8::parse(EXAMPLE.to_string()).unwrap();
+
+    assert_eq!(Day8::part_1(&input), Answer::Number(26));
+    assert_eq!(Day8::part_2(&input), Answer::Number(61229));
+}
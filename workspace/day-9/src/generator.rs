@@ -0,0 +1,77 @@
+use crate::Day9;
+use aoc_common::{Grid, Rng, Solution};
+use std::collections::VecDeque;
+
+/// Smallest map leaving room for the three largest basins
+const MIN_SIZE: usize = 5;
+
+/// Generates a `size` × `size` height map of basins bordered by 9s, the height growing with the
+/// distance from the low point of the basin. Maps with less than 3 low points are generated again.
+pub fn generate(size: usize, rng: &mut Rng) -> String {
+    let size = size.max(MIN_SIZE);
+    loop {
+        let content = basins(size, rng);
+        if Day9::parse(content.clone())
+            .unwrap()
+            .map
+            .flow_points()
+            .len()
+            >= 3
+        {
+            return content;
+        }
+    }
+}
+
+fn basins(size: usize, rng: &mut Rng) -> String {
+    // Grow the basins from their low points, breadth first
+    let mut basin = Grid::filled(size, size, usize::MAX);
+    let mut distance = Grid::filled(size, size, 0);
+    let mut queue = VecDeque::new();
+    for index in 0..(size * size / 40).max(4) {
+        let cell = rng.below(size * size);
+        let position = (cell % size, cell / size);
+        if basin[position] == usize::MAX {
+            basin[position] = index;
+            queue.push_back(position);
+        }
+    }
+    while let Some(position) = queue.pop_front() {
+        for neighbour in distance.neighbours_4(position) {
+            if basin[neighbour] == usize::MAX {
+                basin[neighbour] = basin[position];
+                distance[neighbour] = distance[position] + 1;
+                queue.push_back(neighbour);
+            }
+        }
+    }
+
+    let mut heights = distance.map(|&distance| distance.min(8) as u32);
+    for position in basin.positions() {
+        if basin
+            .neighbours_4(position)
+            .any(|neighbour| basin[neighbour] != basin[position])
+        {
+            heights[position] = 9;
+        }
+    }
+
+    heights.render(|&height| char::from_digit(height, 10).unwrap())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::generator::generate;
+    use crate::Day9;
+    use aoc_common::{Rng, Solution};
+
+    #[test]
+    fn generate_valid_input() {
+        for seed in 0..10 {
+            let input = Day9::parse(generate(20, &mut Rng::new(seed))).unwrap();
+
+            assert!(input.map.flow_points().len() >= 3);
+            Day9::part_2(&input);
+        }
+    }
+}
@@ -1,25 +1,12 @@
 use crate::models::{HeightMap, Input};
-use std::error::Error;
-use std::fs;
+use crate::Day9;
+use aoc_common::{input_lines, Grid, ParseError, Solution};
 
-pub fn parse_file(file_path: String) -> Result<Input, Box<dyn Error>> {
-    let file_content = fs::read_to_string(file_path).expect("Error while reading the data file");
-
-    parse_string(file_content)
-}
-
-pub fn parse_string(content: String) -> Result<Input, Box<dyn Error>> {
-    let data = content
-        .lines()
-        .map(|line| {
-            line.chars()
-                .map(|c| c as u8 - '0' as u8)
-                .collect::<Vec<u8>>()
-        })
-        .collect::<Vec<Vec<u8>>>();
+pub fn parse_string(content: String) -> Result<Input, ParseError> {
+    let lines = input_lines(Day9::DAY, &content).filter(|line| !line.text.is_empty());
 
     Ok(Input {
-        map: HeightMap::new(data),
+        map: HeightMap::new(Grid::parse_digits(lines, "heights")?),
     })
 }
 
@@ -27,6 +14,8 @@ pub fn parse_string(content: String) -> Result<Input, Box<dyn Error>> {
 mod tests {
     use crate::input_parsing::parse_string;
     use crate::models::{HeightMap, Input};
+    use aoc_common::Grid;
+    use aoc_common::ParseError;
 
     #[test]
     fn parse_example_case() {
@@ -43,14 +32,26 @@ mod tests {
         assert_eq!(
             input,
             Input {
-                map: HeightMap::new(vec![
+                map: HeightMap::new(Grid::from_rows(vec![
                     vec![2, 1, 9, 9, 9, 4, 3, 2, 1, 0],
                     vec![3, 9, 8, 7, 8, 9, 4, 9, 2, 1],
                     vec![9, 8, 5, 6, 7, 8, 9, 8, 9, 2],
                     vec![8, 7, 6, 7, 8, 9, 6, 7, 8, 9],
                     vec![9, 8, 9, 9, 9, 6, 5, 6, 7, 8],
-                ])
+                ]))
             }
         );
     }
+
+    #[test]
+    fn parse_invalid_heights() {
+        assert_eq!(
+            parse_string("2199943210\n39878949x1\n".to_string()),
+            Err(ParseError::new(9, 2, 9, "a digit"))
+        );
+        assert_eq!(
+            parse_string("2199943210\n398789492\n".to_string()),
+            Err(ParseError::new(9, 2, 10, "10 heights"))
+        );
+    }
 }
@@ -0,0 +1,34 @@
+use crate::input_parsing::parse_string;
+use crate::models::Input;
+use aoc_common::{Answer, Generator, ParseError, Rng, Solution};
+
+pub mod generator;
+pub mod input_parsing;
+pub mod models;
+
+pub struct Day9;
+
+impl Solution for Day9 {
+    const DAY: u8 = 9;
+    type Input = Input;
+
+    fn parse(content: String) -> Result<Input, ParseError> {
+        parse_string(content)
+    }
+
+    fn part_1(input: &Input) -> Answer {
+        input.map.risk_level().into()
+    }
+
+    fn part_2(input: &Input) -> Answer {
+        input.map.find_basins().into()
+    }
+}
+
+impl Generator for Day9 {
+    const DEFAULT_SIZE: usize = 100;
+
+    fn generate(size: usize, rng: &mut Rng) -> String {
+        generator::generate(size, rng)
+    }
+}
@@ -1,3 +1,5 @@
+use aoc_common::Grid;
+
 #[derive(Debug, Eq, PartialEq)]
 pub struct Input {
     pub map: HeightMap,
@@ -5,50 +7,31 @@ pub struct Input {
 
 #[derive(Debug, Eq, PartialEq)]
 pub struct HeightMap {
-    data: Vec<u8>,
-    width: usize,
-    height: usize,
+    heights: Grid<u8>,
 }
 
 impl HeightMap {
-    pub fn new(data: Vec<Vec<u8>>) -> HeightMap {
-        let width = data[0].len();
-        let height = data.len();
-        let data = data.into_iter().flatten().collect();
-
-        HeightMap {
-            data,
-            width,
-            height,
-        }
+    pub fn new(heights: Grid<u8>) -> HeightMap {
+        HeightMap { heights }
     }
 
+    /// Positions lower than all their neighbours
     pub fn flow_points(&self) -> Vec<(usize, usize)> {
-        {
-            let mut flow_points = Vec::new();
-
-            for y in 0..self.height {
-                for x in 0..self.width {
-                    let value = self.data[y * self.width + x];
-
-                    if (x == 0 || self.data[y * self.width + x - 1] > value)
-                        && (x + 1 >= self.width || self.data[y * self.width + x + 1] > value)
-                        && (y == 0 || self.data[(y - 1) * self.width + x] > value)
-                        && (y + 1 >= self.height || self.data[(y + 1) * self.width + x] > value)
-                    {
-                        flow_points.push((x, y));
-                    }
-                }
-            }
-
-            flow_points
-        }
+        self.heights
+            .iter()
+            .filter(|&(position, height)| {
+                self.heights
+                    .neighbours_4(position)
+                    .all(|neighbour| self.heights[neighbour] > *height)
+            })
+            .map(|(position, _)| position)
+            .collect()
     }
 
     pub fn risk_level(&self) -> usize {
         self.flow_points()
             .iter()
-            .map(|(x, y)| self.data[y * self.width + x] as usize + 1)
+            .map(|&position| self.heights[position] as usize + 1)
             .sum()
     }
 
@@ -56,7 +39,7 @@ impl HeightMap {
         let mut basin_sizes = self
             .flow_points()
             .iter()
-            .map(|(x, y)| self.basin_size(*x, *y))
+            .map(|&position| self.basin_size(position))
             .collect::<Vec<usize>>();
 
         basin_sizes.sort();
@@ -65,30 +48,23 @@ impl HeightMap {
         basin_sizes[0] * basin_sizes[1] * basin_sizes[2]
     }
 
-    fn basin_size(&self, x: usize, y: usize) -> usize {
-        let ref mut basin_map = vec![false; self.width * self.height];
+    fn basin_size(&self, position: (usize, usize)) -> usize {
+        let mut basin_map = Grid::filled(self.heights.width(), self.heights.height(), false);
 
-        self.recursive_basin_size(basin_map, x, y)
+        self.recursive_basin_size(&mut basin_map, position)
     }
 
-    fn recursive_basin_size(&self, basin_map: &mut Vec<bool>, x: usize, y: usize) -> usize {
+    fn recursive_basin_size(&self, basin_map: &mut Grid<bool>, position: (usize, usize)) -> usize {
         let mut count = 0;
 
-        if self.data[y * self.width + x] < 9 {
-            basin_map[y * self.width + x] = true;
+        if self.heights[position] < 9 {
+            basin_map[position] = true;
             count += 1;
 
-            if x > 0 && !basin_map[y * self.width + x - 1] {
-                count += self.recursive_basin_size(basin_map, x - 1, y);
-            }
-            if x + 1 < self.width && !basin_map[y * self.width + x + 1] {
-                count += self.recursive_basin_size(basin_map, x + 1, y);
-            }
-            if y > 0 && !basin_map[(y - 1) * self.width + x] {
-                count += self.recursive_basin_size(basin_map, x, y - 1);
-            }
-            if y + 1 < self.height && !basin_map[(y + 1) * self.width + x] {
-                count += self.recursive_basin_size(basin_map, x, y + 1);
+            for neighbour in self.heights.neighbours_4(position) {
+                if !basin_map[neighbour] {
+                    count += self.recursive_basin_size(basin_map, neighbour);
+                }
             }
         }
 
@@ -99,17 +75,18 @@ impl HeightMap {
 #[cfg(test)]
 mod tests {
     use crate::models::{HeightMap, Input};
+    use aoc_common::Grid;
 
     #[test]
     fn count_low_points() {
         let input = Input {
-            map: HeightMap::new(vec![
+            map: HeightMap::new(Grid::from_rows(vec![
                 vec![2, 1, 9, 9, 9, 4, 3, 2, 1, 0],
                 vec![3, 9, 8, 7, 8, 9, 4, 9, 2, 1],
                 vec![9, 8, 5, 6, 7, 8, 9, 8, 9, 2],
                 vec![8, 7, 6, 7, 8, 9, 6, 7, 8, 9],
                 vec![9, 8, 9, 9, 9, 6, 5, 6, 7, 8],
-            ]),
+            ])),
         };
 
         assert_eq!(15, input.map.risk_level());
@@ -118,13 +95,13 @@ mod tests {
     #[test]
     fn find_basins() {
         let input = Input {
-            map: HeightMap::new(vec![
+            map: HeightMap::new(Grid::from_rows(vec![
                 vec![2, 1, 9, 9, 9, 4, 3, 2, 1, 0],
                 vec![3, 9, 8, 7, 8, 9, 4, 9, 2, 1],
                 vec![9, 8, 5, 6, 7, 8, 9, 8, 9, 2],
                 vec![8, 7, 6, 7, 8, 9, 6, 7, 8, 9],
                 vec![9, 8, 9, 9, 9, 6, 5, 6, 7, 8],
-            ]),
+            ])),
         };
 
         assert_eq!(1134, input.map.find_basins());
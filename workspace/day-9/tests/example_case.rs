@@ -0,0 +1,27 @@
+use aoc_common::{Answer, Solution};
+use day_9::input_parsing::parse_string;
+use day_9::Day9;
+
+const EXAMPLE: &str = include_str!("../dataset/example.txt");
+
+#[test]
+fn part_1_example_case() {
+    let input = parse_string(EXAMPLE.to_string()).unwrap();
+
+    assert_eq!(input.map.risk_level(), 15);
+}
+
+#[test]
+fn part_2_example_case() {
+    let input = parse_string(EXAMPLE.to_string()).unwrap();
+
+    assert_eq!(input.map.find_basins(), 1134);
+}
+
+#[test]
+fn solution_example_case() {
+    let input = Day9::parse(EXAMPLE.to_string()).unwrap();
+
+    assert_eq!(Day9::part_1(&input), Answer::Number(15));
+    assert_eq!(Day9::part_2(&input), Answer::Number(1134));
+}